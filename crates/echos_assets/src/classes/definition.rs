@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+use brtk::random::{Dice, Random};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{SpecialAttackData, StatsData};
+
+/// Dice rolled for each stat when a class doesn't name its own
+const DEFAULT_STAT_DICE: Dice = Dice { count: 3, sides: 6, modifier: 0 };
+
+/// A character class offered at character creation, loaded from `*.class.ron` files.
+///
+/// Whatever a class leaves out is taken from the player's entity definition.
+#[derive(Asset, Serialize, Deserialize, Debug, Clone, PartialEq, TypePath)]
+pub struct ClassDefinition {
+    /// Name shown on the creation screen
+    pub name: String,
+    /// A line about how the class plays
+    pub description: String,
+    /// Starting stats, before any reroll
+    pub stats: StatsData,
+    /// Dice rolled for each stat when the player rerolls (defaults to 3d6)
+    pub stat_dice: Option<String>,
+    /// Starting maximum health
+    pub max_health: Option<i32>,
+    /// Items carried from the start (item_id, quantity pairs)
+    #[serde(default)]
+    pub starting_items: Vec<(String, u32)>,
+    /// Items worn or wielded from the start
+    #[serde(default)]
+    pub equipment: Vec<String>,
+    /// Special attacks the class knows
+    #[serde(default)]
+    pub abilities: Vec<SpecialAttackData>,
+    /// How far the character's torches reach
+    pub light_radius: Option<u32>,
+}
+
+impl ClassDefinition {
+    pub fn new(name: impl Into<String>, description: impl Into<String>, stats: StatsData) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            stats,
+            stat_dice: None,
+            max_health: None,
+            starting_items: Vec::new(),
+            equipment: Vec::new(),
+            abilities: Vec::new(),
+            light_radius: None,
+        }
+    }
+
+    pub fn with_stat_dice(mut self, dice: impl Into<String>) -> Self {
+        self.stat_dice = Some(dice.into());
+        self
+    }
+
+    pub fn with_max_health(mut self, max_health: i32) -> Self {
+        self.max_health = Some(max_health);
+        self
+    }
+
+    pub fn with_starting_item(mut self, item_id: impl Into<String>, quantity: u32) -> Self {
+        self.starting_items.push((item_id.into(), quantity));
+        self
+    }
+
+    pub fn with_equipment(mut self, item_id: impl Into<String>) -> Self {
+        self.equipment.push(item_id.into());
+        self
+    }
+
+    pub fn with_ability(mut self, ability: SpecialAttackData) -> Self {
+        self.abilities.push(ability);
+        self
+    }
+
+    pub fn with_light_radius(mut self, radius: u32) -> Self {
+        self.light_radius = Some(radius);
+        self
+    }
+
+    /// Dice rolled for each stat on a reroll (defaults to 3d6)
+    pub fn stat_dice(&self) -> Dice {
+        self.stat_dice.as_deref().and_then(|dice| dice.parse().ok()).unwrap_or(DEFAULT_STAT_DICE)
+    }
+
+    /// Roll a fresh set of stats with the class's stat dice
+    pub fn roll_stats(&self, random: &mut Random) -> StatsData {
+        let dice = self.stat_dice();
+        StatsData {
+            strength: random.roll(dice),
+            defense: random.roll(dice),
+            intelligence: random.roll(dice),
+            agility: random.roll(dice),
+            vitality: random.roll(dice),
+            luck: random.roll(dice),
+        }
+    }
+
+    /// Validate the class
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if self.name.trim().is_empty() {
+            errors.push("Class name cannot be empty".to_string());
+        }
+        if let Some(dice) = &self.stat_dice
+            && dice.parse::<Dice>().is_err()
+        {
+            errors.push(format!("Stat dice '{dice}' is not valid dice notation"));
+        }
+        if let Some(max_health) = self.max_health
+            && max_health <= 0
+        {
+            errors.push("Maximum health must be positive".to_string());
+        }
+        for (item_id, quantity) in &self.starting_items {
+            if *quantity == 0 {
+                errors.push(format!("Starting item '{item_id}' has no quantity"));
+            }
+        }
+        for item_id in &self.equipment {
+            if item_id.trim().is_empty() {
+                errors.push("Equipment item id cannot be empty".to_string());
+            }
+        }
+        if self.light_radius == Some(0) {
+            errors.push("Light radius must be at least 1".to_string());
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roll_stats_stays_within_dice() {
+        let class =
+            ClassDefinition::new("Rogue", "Quick and lucky", StatsData::rogue()).with_stat_dice("2d4+2");
+        let mut random = Random::new(11);
+
+        for _ in 0..20 {
+            let stats = class.roll_stats(&mut random);
+            for stat in
+                [stats.strength, stats.defense, stats.intelligence, stats.agility, stats.vitality, stats.luck]
+            {
+                assert!((4..=10).contains(&stat));
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_dice_and_health() {
+        let class = ClassDefinition::new("Mage", "Frail", StatsData::mage())
+            .with_stat_dice("lots")
+            .with_max_health(0);
+
+        assert_eq!(class.validate().unwrap_err().len(), 2);
+        assert_eq!(ClassDefinition::new("Mage", "Frail", StatsData::mage()).stat_dice(), DEFAULT_STAT_DICE);
+    }
+
+    #[test]
+    fn test_validate_rejects_blank_equipment() {
+        let class =
+            ClassDefinition::new("Warrior", "Sturdy", StatsData::warrior()).with_equipment("longsword");
+        assert!(class.validate().is_ok());
+
+        assert_eq!(class.with_equipment(" ").validate().unwrap_err().len(), 1);
+    }
+}
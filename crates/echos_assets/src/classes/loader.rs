@@ -0,0 +1,21 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_asset_loader::prelude::*;
+
+use super::definition::ClassDefinition;
+
+/// Resource holding the character classes offered at character creation
+#[derive(AssetCollection, Resource, Debug)]
+pub struct ClassDefinitions {
+    /// Every class file in the classes folder, keyed by path
+    #[asset(path = "classes", collection(typed, mapped))]
+    pub classes: HashMap<String, Handle<ClassDefinition>>,
+}
+
+impl ClassDefinitions {
+    /// Every loaded class, sorted by name so the creation screen lists them in a stable order
+    pub fn loaded<'a>(&self, assets: &'a Assets<ClassDefinition>) -> Vec<&'a ClassDefinition> {
+        let mut classes: Vec<_> = self.classes.values().filter_map(|handle| assets.get(handle)).collect();
+        classes.sort_by(|a, b| a.name.cmp(&b.name));
+        classes
+    }
+}
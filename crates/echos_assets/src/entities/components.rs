@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use brtk::random::Dice;
 use serde::{Deserialize, Serialize};
 
 /// Different types of AI behavior patterns
@@ -16,6 +17,53 @@ impl Default for AIBehaviorType {
     fn default() -> Self { Self::Neutral }
 }
 
+impl AIBehaviorType {
+    /// Faction for entities that don't name one in their definition
+    pub fn default_faction(&self) -> &'static str {
+        match self {
+            Self::Hostile => "monsters",
+            Self::Passive => "critters",
+            Self::Neutral => "wanderers",
+        }
+    }
+}
+
+/// How an entity moves through the world, used for terrain costs and pathfinding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize, Default)]
+pub enum MovementType {
+    /// Ground movement, blocked by chasms and deep water
+    #[default]
+    Walking,
+    /// Flies over chasms and water
+    Flying,
+    /// Moves freely through water, clumsy on land obstacles
+    Swimming,
+    /// Tunnels through rubble and loose ore
+    Burrowing,
+}
+
+impl MovementType {
+    /// Numeric id used by the pathfinding provider and path cache
+    pub fn as_u8(self) -> u8 {
+        match self {
+            Self::Walking => 0,
+            Self::Flying => 1,
+            Self::Swimming => 2,
+            Self::Burrowing => 3,
+        }
+    }
+
+    /// Convert a pathfinding movement id back into a movement type (unknown ids walk)
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Flying,
+            2 => Self::Swimming,
+            3 => Self::Burrowing,
+            _ => Self::Walking,
+        }
+    }
+}
+
 /// Data representation of TurnActor component for serialization
 #[derive(Serialize, Deserialize, Debug, Clone, Reflect)]
 pub struct TurnActorData {
@@ -163,3 +211,212 @@ pub struct DescriptionData {
 impl DescriptionData {
     pub fn new(text: impl Into<String>) -> Self { Self { text: text.into() } }
 }
+
+/// A special attack an entity can use on top of its plain melee strike
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub struct SpecialAttackData {
+    /// Name shown in the combat log
+    pub name: String,
+    /// Which tiles the attack hits
+    pub shape: AttackShape,
+    /// Multiplier on normal melee damage (defaults to 1.0)
+    pub damage_multiplier: Option<f32>,
+    /// Turns after use before the attack is ready again
+    pub cooldown: u32,
+    /// Fewest foes the attack has to catch before the AI bothers with it (defaults to 1)
+    pub min_targets: Option<u32>,
+}
+
+/// The area a special attack covers
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum AttackShape {
+    /// Every tile around the attacker
+    Swipe,
+    /// A rush of up to `length` tiles toward the target, hitting the first thing in the way
+    Charge { length: u32 },
+    /// Everything within `radius` of the attacker that the blast can reach
+    Slam { radius: u32 },
+    /// A cone `length` tiles long toward the target
+    Breath { length: u32 },
+}
+
+impl SpecialAttackData {
+    pub fn new(name: impl Into<String>, shape: AttackShape, cooldown: u32) -> Self {
+        Self { name: name.into(), shape, damage_multiplier: None, cooldown, min_targets: None }
+    }
+
+    pub fn with_damage_multiplier(mut self, multiplier: f32) -> Self {
+        self.damage_multiplier = Some(multiplier);
+        self
+    }
+
+    pub fn with_min_targets(mut self, min_targets: u32) -> Self {
+        self.min_targets = Some(min_targets);
+        self
+    }
+
+    /// Get the damage multiplier (defaults to 1.0 if not specified)
+    pub fn damage_multiplier(&self) -> f32 { self.damage_multiplier.unwrap_or(1.0) }
+
+    /// Get the fewest foes worth using the attack on (defaults to 1 if not specified)
+    pub fn min_targets(&self) -> u32 { self.min_targets.unwrap_or(1) }
+
+    /// Check the attack for values that can't work
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if self.name.trim().is_empty() {
+            errors.push("Special attack name cannot be empty".to_string());
+        }
+        if self.damage_multiplier() <= 0.0 {
+            errors.push(format!("'{}' damage multiplier must be positive", self.name));
+        }
+        if self.min_targets() == 0 {
+            errors.push(format!("'{}' must need at least one target", self.name));
+        }
+
+        match self.shape {
+            AttackShape::Charge { length: 0 }
+            | AttackShape::Slam { radius: 0 }
+            | AttackShape::Breath { length: 0 } => {
+                errors.push(format!("'{}' must reach at least one tile", self.name));
+            }
+            _ => {}
+        }
+
+        errors
+    }
+}
+
+/// What an entity drops when it dies, on top of whatever it was carrying
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub struct LootTableData {
+    /// How many times the table is rolled, in dice notation like "1d3" (defaults to once)
+    pub rolls: Option<String>,
+    /// Weight of a roll coming up empty (defaults to 0)
+    pub nothing_weight: Option<f32>,
+    pub entries: Vec<LootEntryData>,
+}
+
+/// One kind of item a loot table can drop
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub struct LootEntryData {
+    /// Item id of the drop
+    pub item: String,
+    /// Chance relative to the table's other entries
+    pub weight: f32,
+    /// How many drop, in dice notation like "2d4+1" (defaults to one)
+    pub quantity: Option<String>,
+    /// Weight gained for every level past the first, so rare drops grow common deeper down
+    pub weight_per_depth: Option<f32>,
+    /// Shallowest depth the entry drops at (defaults to 1)
+    pub min_depth: Option<u32>,
+}
+
+impl LootTableData {
+    pub fn new() -> Self { Self { rolls: None, nothing_weight: None, entries: Vec::new() } }
+
+    pub fn with_rolls(mut self, rolls: impl Into<String>) -> Self {
+        self.rolls = Some(rolls.into());
+        self
+    }
+
+    pub fn with_nothing_weight(mut self, weight: f32) -> Self {
+        self.nothing_weight = Some(weight);
+        self
+    }
+
+    pub fn with_entry(mut self, entry: LootEntryData) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Get how many times to roll (defaults to once)
+    pub fn rolls(&self) -> Dice { parse_dice(self.rolls.as_deref()) }
+
+    /// Get the weight of rolling nothing (defaults to 0)
+    pub fn nothing_weight(&self) -> f32 { self.nothing_weight.unwrap_or(0.0) }
+
+    /// Check the table for values that can't work
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if let Some(rolls) = &self.rolls
+            && rolls.parse::<Dice>().is_err()
+        {
+            errors.push(format!("Loot rolls '{rolls}' is not valid dice notation"));
+        }
+        if self.nothing_weight() < 0.0 {
+            errors.push("Loot nothing weight cannot be negative".to_string());
+        }
+
+        for entry in &self.entries {
+            if entry.item.trim().is_empty() {
+                errors.push("Loot entry item cannot be empty".to_string());
+            }
+            if entry.weight < 0.0 || entry.weight_per_depth() < 0.0 {
+                errors.push(format!("Loot entry '{}' weights cannot be negative", entry.item));
+            }
+            if let Some(quantity) = &entry.quantity
+                && quantity.parse::<Dice>().is_err()
+            {
+                errors.push(format!(
+                    "Loot entry '{}' quantity '{quantity}' is not valid dice notation",
+                    entry.item
+                ));
+            }
+            if entry.min_depth == Some(0) {
+                errors.push(format!("Loot entry '{}' minimum depth must be at least 1", entry.item));
+            }
+        }
+
+        errors
+    }
+}
+
+impl Default for LootTableData {
+    fn default() -> Self { Self::new() }
+}
+
+impl LootEntryData {
+    pub fn new(item: impl Into<String>, weight: f32) -> Self {
+        Self { item: item.into(), weight, quantity: None, weight_per_depth: None, min_depth: None }
+    }
+
+    pub fn with_quantity(mut self, quantity: impl Into<String>) -> Self {
+        self.quantity = Some(quantity.into());
+        self
+    }
+
+    pub fn with_weight_per_depth(mut self, weight: f32) -> Self {
+        self.weight_per_depth = Some(weight);
+        self
+    }
+
+    pub fn with_min_depth(mut self, depth: u32) -> Self {
+        self.min_depth = Some(depth);
+        self
+    }
+
+    /// Get how many drop (defaults to one)
+    pub fn quantity(&self) -> Dice { parse_dice(self.quantity.as_deref()) }
+
+    /// Get the weight gained per level of depth (defaults to 0)
+    pub fn weight_per_depth(&self) -> f32 { self.weight_per_depth.unwrap_or(0.0) }
+
+    /// Get the shallowest depth the entry drops at (defaults to 1)
+    pub fn min_depth(&self) -> u32 { self.min_depth.unwrap_or(1) }
+
+    /// The entry's weight at a depth; nothing before its minimum depth
+    pub fn weight_at(&self, depth: u32) -> f32 {
+        if depth < self.min_depth() {
+            return 0.0;
+        }
+        self.weight + self.weight_per_depth() * depth.saturating_sub(1) as f32
+    }
+}
+
+/// Parse optional dice notation, falling back to exactly one
+fn parse_dice(notation: Option<&str>) -> Dice {
+    notation.and_then(|notation| notation.parse().ok()).unwrap_or(Dice::new(1, 1, 0))
+}
@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use super::components::*;
+use super::{components::*, thinker::ThinkerData};
 
 /// Main entity definition loaded from RON files
 #[derive(Asset, Serialize, Deserialize, Debug, Clone, TypePath)]
@@ -34,6 +34,19 @@ pub struct EntityComponents {
 
     // AI-specific components
     pub ai_behavior_type: Option<AIBehaviorType>,
+    pub faction: Option<String>,
+    pub defends_allies: Option<bool>,
+    pub thinker: Option<ThinkerData>,
+    pub preferred_range: Option<u8>,
+    pub special_attacks: Option<Vec<SpecialAttackData>>,
+
+    // Drops
+    pub loot: Option<LootTableData>,
+    /// Experience its killer earns
+    pub experience: Option<u32>,
+
+    // Movement
+    pub movement_type: Option<MovementType>,
 
     // Spawning and gameplay properties
     pub spawn_weight: Option<f32>,
@@ -61,9 +74,57 @@ impl EntityDefinition {
     /// Get spawn weight for random selection
     pub fn spawn_weight(&self) -> f32 { self.components.spawn_weight.unwrap_or(1.0) }
 
+    /// Get the experience its killer earns (defaults to 10)
+    pub fn experience(&self) -> u32 { self.components.experience.unwrap_or(10) }
+
+    /// Get the dungeon depths this entity spawns at (defaults to every depth)
+    pub fn level_range(&self) -> (u32, u32) { self.components.level_range.unwrap_or((1, u32::MAX)) }
+
+    /// Check if this entity belongs at a depth
+    pub fn spawns_at(&self, depth: u32) -> bool {
+        let (min, max) = self.level_range();
+        (min..=max).contains(&depth)
+    }
+
     /// Get AI behavior type (defaults to Neutral if not specified)
     pub fn ai_behavior_type(&self) -> AIBehaviorType { self.components.ai_behavior_type.unwrap_or_default() }
 
+    /// Get faction id (defaults to "player" for the player, otherwise by AI behavior type)
+    pub fn faction(&self) -> &str {
+        match &self.components.faction {
+            Some(faction) => faction,
+            None if self.is_player() => "player",
+            None => self.ai_behavior_type().default_faction(),
+        }
+    }
+
+    /// Get the AI's thinker (defaults to the stock thinker for its behavior type, reaching for
+    /// special attacks first when the entity has any)
+    pub fn thinker(&self) -> ThinkerData {
+        match &self.components.thinker {
+            Some(thinker) => thinker.clone(),
+            None if self.special_attacks().is_empty() => ThinkerData::for_behavior(self.ai_behavior_type()),
+            None => ThinkerData::for_behavior(self.ai_behavior_type()).with_special_attacks(),
+        }
+    }
+
+    /// Get special attacks (defaults to none)
+    pub fn special_attacks(&self) -> &[SpecialAttackData] {
+        self.components.special_attacks.as_deref().unwrap_or_default()
+    }
+
+    /// Get the loot table rolled on death, if any
+    pub fn loot(&self) -> Option<&LootTableData> { self.components.loot.as_ref() }
+
+    /// Get preferred fighting distance from a target (defaults to 1, toe to toe)
+    pub fn preferred_range(&self) -> u8 { self.components.preferred_range.unwrap_or(1) }
+
+    /// Check if this AI joins fights when it sees an ally attacked
+    pub fn defends_allies(&self) -> bool { self.components.defends_allies.unwrap_or(false) }
+
+    /// Get movement type (defaults to Walking if not specified)
+    pub fn movement_type(&self) -> MovementType { self.components.movement_type.unwrap_or_default() }
+
     /// Validate the entity definition for correctness
     pub fn validate(&self) -> Result<(), Vec<String>> {
         let mut errors = Vec::new();
@@ -147,6 +208,31 @@ impl EntityDefinition {
             }
         }
 
+        // Validate faction
+        if self.components.faction.as_ref().is_some_and(|faction| faction.trim().is_empty()) {
+            errors.push("Faction cannot be empty".to_string());
+        }
+
+        // Validate thinker
+        if let Some(thinker) = &self.components.thinker {
+            errors.extend(thinker.validate().into_iter().map(|error| format!("Thinker: {error}")));
+        }
+
+        // Validate preferred range
+        if self.components.preferred_range == Some(0) {
+            errors.push("Preferred range must be at least 1".to_string());
+        }
+
+        // Validate special attacks
+        for attack in self.special_attacks() {
+            errors.extend(attack.validate());
+        }
+
+        // Validate loot table
+        if let Some(loot) = self.loot() {
+            errors.extend(loot.validate());
+        }
+
         // Validate spawn weight
         if let Some(weight) = self.components.spawn_weight
             && weight < 0.0
@@ -224,6 +310,54 @@ impl EntityComponents {
         self
     }
 
+    /// Set faction
+    pub fn with_faction(mut self, faction: impl Into<String>) -> Self {
+        self.faction = Some(faction.into());
+        self
+    }
+
+    /// Set whether this AI defends its allies
+    pub fn with_defends_allies(mut self, defends_allies: bool) -> Self {
+        self.defends_allies = Some(defends_allies);
+        self
+    }
+
+    /// Set thinker
+    pub fn with_thinker(mut self, thinker: ThinkerData) -> Self {
+        self.thinker = Some(thinker);
+        self
+    }
+
+    /// Set preferred fighting range
+    pub fn with_preferred_range(mut self, range: u8) -> Self {
+        self.preferred_range = Some(range);
+        self
+    }
+
+    /// Add a special attack
+    pub fn with_special_attack(mut self, attack: SpecialAttackData) -> Self {
+        self.special_attacks.get_or_insert_with(Vec::new).push(attack);
+        self
+    }
+
+    /// Set the loot table rolled on death
+    pub fn with_loot(mut self, loot: LootTableData) -> Self {
+        self.loot = Some(loot);
+        self
+    }
+
+    /// Set the experience its killer earns
+    pub fn with_experience(mut self, experience: u32) -> Self {
+        self.experience = Some(experience);
+        self
+    }
+
+    /// Set movement type
+    pub fn with_movement_type(mut self, movement_type: MovementType) -> Self {
+        self.movement_type = Some(movement_type);
+        self
+    }
+
     /// Set spawn weight
     pub fn with_spawn_weight(mut self, weight: f32) -> Self {
         self.spawn_weight = Some(weight);
@@ -77,6 +77,17 @@ impl EntityDefinitions {
     /// Get all enemy entity definitions
     pub fn get_enemies(&self) -> Vec<&Handle<EntityDefinition>> { self.enemies.values().collect() }
 
+    /// Get every loaded enemy definition along with its simple name
+    pub fn loaded_enemies<'a>(
+        &'a self,
+        assets: &'a Assets<EntityDefinition>,
+    ) -> impl Iterator<Item = (&'a str, &'a EntityDefinition)> + 'a {
+        self.enemies.iter().filter_map(|(path, handle)| {
+            let name = extract_name_from_path(path).unwrap_or(path);
+            assets.get(handle).map(|definition| (name, definition))
+        })
+    }
+
     /// Get a random enemy definition handle
     pub fn get_random_enemy(&self) -> Option<&Handle<EntityDefinition>> {
         let enemies = self.get_enemies();
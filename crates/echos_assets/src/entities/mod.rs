@@ -1,10 +1,12 @@
 pub mod components;
 pub mod definition;
 pub mod loader;
+pub mod thinker;
 
 pub use components::*;
 pub use definition::*;
 pub use loader::*;
+pub use thinker::*;
 
 use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
@@ -17,10 +19,15 @@ pub(super) fn plugin(app: &mut App) {
         .register_type::<components::FieldOfViewData>()
         .register_type::<components::TileSpriteData>()
         .register_type::<components::AIBehaviorType>()
+        .register_type::<components::MovementType>()
         .register_type::<components::HealthData>()
         .register_type::<components::StatsData>()
         .register_type::<components::InventoryData>()
-        .register_type::<components::DescriptionData>();
+        .register_type::<components::DescriptionData>()
+        .register_type::<components::SpecialAttackData>()
+        .register_type::<components::LootTableData>()
+        .register_type::<components::LootEntryData>()
+        .register_type::<thinker::ThinkerData>();
 
     // Add validation system that runs after assets are loaded
     app.add_systems(Update, loader::validate_entity_definitions);
@@ -0,0 +1,247 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::components::AIBehaviorType;
+
+/// Data description of an AI's big-brain thinker: how it picks, what it weighs and what it does
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub struct ThinkerData {
+    /// Label shown in big-brain debug output
+    pub label: Option<String>,
+    /// How the winning choice is picked
+    pub picker: PickerData,
+    /// Scored choices, in priority order
+    pub choices: Vec<ChoiceData>,
+    /// What to do when no choice wins
+    pub otherwise: Option<ActionData>,
+}
+
+/// Strategy for picking between scored choices
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum PickerData {
+    /// First choice, in listed order, whose score reaches the threshold
+    FirstToScore { threshold: f32 },
+    /// Highest scoring choice whose score reaches the threshold
+    HighestToScore { threshold: f32 },
+    /// Highest scoring choice, whatever its score
+    Highest,
+}
+
+impl Default for PickerData {
+    fn default() -> Self { Self::FirstToScore { threshold: 0.5 } }
+}
+
+/// A scorer paired with the action it triggers
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub struct ChoiceData {
+    pub scorer: ScorerData,
+    /// Multiplier on the scorer's output, between 0 and 1 (defaults to 1)
+    pub weight: Option<f32>,
+    pub action: ActionData,
+}
+
+impl ChoiceData {
+    pub fn new(scorer: ScorerData, action: ActionData) -> Self { Self { scorer, weight: None, action } }
+
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Get the weight (defaults to 1.0 if not specified)
+    pub fn weight(&self) -> f32 { self.weight.unwrap_or(1.0) }
+}
+
+/// What a choice measures
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum ScorerData {
+    /// Wants to close in on a hostile target it knows about (hostile behavior only)
+    Chase,
+    /// Wants to get away from a visible threat. Passive AIs always do; with a health threshold
+    /// any AI does once its health fraction falls to it.
+    Flee { health_threshold: Option<f32> },
+    /// Wants to wander when nothing else is going on
+    Wander,
+    /// Scores 1 once the AI's health fraction falls to the threshold
+    LowHealth { threshold: f32 },
+    /// Wants to use a special attack that is ready and would catch enough foes
+    SpecialAttack,
+    /// Wants to feed on a corpse in view while it has no target
+    Scavenge,
+    /// Always the same score
+    Fixed(f32),
+}
+
+/// What a choice does
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub enum ActionData {
+    /// Chase the target, then attack it once adjacent
+    ChaseAndAttack,
+    /// Chase the target without attacking
+    Chase,
+    /// Attack an adjacent target
+    Attack,
+    /// Run from the target
+    Flee,
+    /// Wander about; `radius` keeps area and patrol wanderers near where they spawned
+    Wander { wander_type: WanderKind, radius: Option<u32> },
+    /// Unleash the special attack the scorer lined up
+    SpecialAttack,
+    /// Walk to the nearest corpse in view and eat it
+    Scavenge,
+    /// Stand still
+    Idle,
+}
+
+/// How a wandering AI picks where to go
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum WanderKind {
+    /// Random nearby spots
+    #[default]
+    Random,
+    /// Spots within a radius of home
+    Area,
+    /// A route of patrol points
+    Patrol,
+    /// Distant spots, preferring ones far away
+    Explore,
+}
+
+impl ActionData {
+    /// Plain random wandering
+    pub fn wander() -> Self { Self::Wander { wander_type: WanderKind::Random, radius: None } }
+}
+
+impl ThinkerData {
+    pub fn new(picker: PickerData) -> Self {
+        Self { label: None, picker, choices: Vec::new(), otherwise: None }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_choice(mut self, choice: ChoiceData) -> Self {
+        self.choices.push(choice);
+        self
+    }
+
+    pub fn otherwise(mut self, action: ActionData) -> Self {
+        self.otherwise = Some(action);
+        self
+    }
+
+    /// Whether any of the thinker's actions walks a patrol route
+    pub fn patrols(&self) -> bool {
+        self.choices
+            .iter()
+            .map(|choice| &choice.action)
+            .chain(&self.otherwise)
+            .any(|action| matches!(action, ActionData::Wander { wander_type: WanderKind::Patrol, .. }))
+    }
+
+    /// Put special attacks ahead of every other choice
+    pub fn with_special_attacks(mut self) -> Self {
+        self.choices.insert(0, ChoiceData::new(ScorerData::SpecialAttack, ActionData::SpecialAttack));
+        self
+    }
+
+    /// The stock thinker for an AI behavior type, used when a definition doesn't describe one
+    pub fn for_behavior(behavior_type: AIBehaviorType) -> Self {
+        match behavior_type {
+            AIBehaviorType::Hostile => Self::new(PickerData::FirstToScore { threshold: 0.7 })
+                .with_label("Hostile")
+                .with_choice(ChoiceData::new(ScorerData::Chase, ActionData::ChaseAndAttack))
+                .otherwise(ActionData::wander()),
+            // Passive AIs prioritize survival, reacting to danger at a lower threshold
+            AIBehaviorType::Passive => Self::new(PickerData::FirstToScore { threshold: 0.5 })
+                .with_label("Passive")
+                .with_choice(ChoiceData::new(ScorerData::Flee { health_threshold: None }, ActionData::Flee))
+                .with_choice(ChoiceData::new(ScorerData::Wander, ActionData::wander()))
+                .otherwise(ActionData::Idle),
+            // Neutral AIs are content to wander, fighting back only once provoked
+            AIBehaviorType::Neutral => Self::new(PickerData::FirstToScore { threshold: 0.3 })
+                .with_label("Neutral")
+                .with_choice(ChoiceData::new(ScorerData::Chase, ActionData::ChaseAndAttack))
+                .with_choice(ChoiceData::new(ScorerData::Wander, ActionData::wander()))
+                .otherwise(ActionData::Idle),
+        }
+    }
+
+    /// Check the thinker for values big-brain can't work with
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let in_unit_range = |value: f32| (0.0..=1.0).contains(&value);
+
+        if self.choices.is_empty() && self.otherwise.is_none() {
+            errors.push("Thinker has no choices and no fallback action".to_string());
+        }
+
+        match self.picker {
+            PickerData::FirstToScore { threshold } | PickerData::HighestToScore { threshold }
+                if !in_unit_range(threshold) =>
+            {
+                errors.push(format!("Picker threshold {threshold} must be between 0 and 1"));
+            }
+            _ => {}
+        }
+
+        for (index, choice) in self.choices.iter().enumerate() {
+            if !in_unit_range(choice.weight()) {
+                errors.push(format!("Choice {index} weight {} must be between 0 and 1", choice.weight()));
+            }
+
+            match choice.scorer {
+                ScorerData::Flee { health_threshold: Some(value) }
+                | ScorerData::LowHealth { threshold: value }
+                | ScorerData::Fixed(value)
+                    if !in_unit_range(value) =>
+                {
+                    errors.push(format!("Choice {index} scorer value {value} must be between 0 and 1"));
+                }
+                _ => {}
+            }
+
+            if let ActionData::Wander { wander_type: WanderKind::Area, radius: None } = choice.action {
+                errors.push(format!("Choice {index} area wander needs a radius"));
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stock_thinkers_are_valid() {
+        for behavior_type in [AIBehaviorType::Hostile, AIBehaviorType::Passive, AIBehaviorType::Neutral] {
+            assert!(ThinkerData::for_behavior(behavior_type).validate().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_special_attacks_come_first() {
+        let thinker = ThinkerData::for_behavior(AIBehaviorType::Hostile).with_special_attacks();
+
+        assert_eq!(thinker.choices[0].scorer, ScorerData::SpecialAttack);
+        assert_eq!(thinker.choices.len(), 2);
+        assert!(thinker.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validation_catches_bad_values() {
+        let thinker = ThinkerData::new(PickerData::HighestToScore { threshold: 1.5 })
+            .with_choice(ChoiceData::new(ScorerData::Fixed(0.2), ActionData::Idle).with_weight(2.0))
+            .with_choice(ChoiceData::new(
+                ScorerData::Wander,
+                ActionData::Wander { wander_type: WanderKind::Area, radius: None },
+            ));
+
+        assert_eq!(thinker.validate().len(), 3);
+        assert_eq!(ThinkerData::new(PickerData::Highest).validate().len(), 1);
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// How one faction regards another
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default)]
+pub enum Stance {
+    /// Attack on sight (or flee, for timid creatures)
+    Hostile,
+    /// Leave each other alone
+    #[default]
+    Neutral,
+    /// Never attack, and come to each other's aid
+    Friendly,
+}
+
+/// A stance between two factions. Relations are symmetric.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub struct FactionRelation {
+    pub a: String,
+    pub b: String,
+    pub stance: Stance,
+}
+
+impl FactionRelation {
+    pub fn new(a: impl Into<String>, b: impl Into<String>, stance: Stance) -> Self {
+        Self { a: a.into(), b: b.into(), stance }
+    }
+
+    /// Whether this relation is between the two given factions, in either order
+    pub fn is_between(&self, a: &str, b: &str) -> bool {
+        (self.a == a && self.b == b) || (self.a == b && self.b == a)
+    }
+}
+
+/// Faction list and relationship matrix, loaded from `*.factions.ron` files
+#[derive(Asset, Serialize, Deserialize, Debug, Clone, TypePath, Default)]
+pub struct FactionTable {
+    /// Every faction id entity definitions may refer to
+    pub factions: Vec<String>,
+    /// Explicit stances between pairs of factions
+    #[serde(default)]
+    pub relations: Vec<FactionRelation>,
+    /// Stance between different factions with no explicit relation
+    #[serde(default)]
+    pub default_stance: Stance,
+}
+
+impl FactionTable {
+    /// Stance between two factions. Members of the same faction are friendly unless a relation
+    /// says otherwise.
+    pub fn stance(&self, a: &str, b: &str) -> Stance {
+        match self.relations.iter().find(|relation| relation.is_between(a, b)) {
+            Some(relation) => relation.stance,
+            None if a == b => Stance::Friendly,
+            None => self.default_stance,
+        }
+    }
+
+    /// Validate faction names and relations
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        let mut seen = HashSet::new();
+
+        for faction in &self.factions {
+            if faction.trim().is_empty() {
+                errors.push("Faction name cannot be empty".to_string());
+            }
+            if !seen.insert(faction.as_str()) {
+                errors.push(format!("Duplicate faction '{faction}'"));
+            }
+        }
+
+        for (index, relation) in self.relations.iter().enumerate() {
+            for faction in [&relation.a, &relation.b] {
+                if !seen.contains(faction.as_str()) {
+                    errors.push(format!("Relation references unknown faction '{faction}'"));
+                }
+            }
+
+            if self.relations[..index].iter().any(|earlier| earlier.is_between(&relation.a, &relation.b)) {
+                errors.push(format!("Duplicate relation between '{}' and '{}'", relation.a, relation.b));
+            }
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> FactionTable {
+        FactionTable {
+            factions: vec!["player".into(), "guards".into(), "berserkers".into()],
+            relations: vec![
+                FactionRelation::new("guards", "player", Stance::Hostile),
+                FactionRelation::new("berserkers", "berserkers", Stance::Hostile),
+            ],
+            default_stance: Stance::Neutral,
+        }
+    }
+
+    #[test]
+    fn test_stance_lookup() {
+        let table = table();
+
+        assert_eq!(table.stance("player", "guards"), Stance::Hostile);
+        assert_eq!(table.stance("guards", "player"), Stance::Hostile);
+        assert_eq!(table.stance("guards", "guards"), Stance::Friendly);
+        assert_eq!(table.stance("berserkers", "berserkers"), Stance::Hostile);
+        assert_eq!(table.stance("berserkers", "guards"), Stance::Neutral);
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_factions() {
+        let mut table = table();
+        table.relations.push(FactionRelation::new("guards", "goblins", Stance::Hostile));
+
+        let errors = table.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("goblins")));
+    }
+}
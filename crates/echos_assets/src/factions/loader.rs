@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+use super::definition::FactionTable;
+
+/// Resource holding the loaded faction table
+#[derive(AssetCollection, Resource, Debug)]
+pub struct FactionDefinitions {
+    #[asset(path = "factions/default.factions.ron")]
+    pub table: Handle<FactionTable>,
+}
@@ -0,0 +1,14 @@
+pub mod definition;
+pub mod loader;
+
+pub use definition::*;
+pub use loader::*;
+
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(RonAssetPlugin::<FactionTable>::new(&["factions.ron"]));
+
+    app.register_type::<definition::Stance>().register_type::<definition::FactionRelation>();
+}
@@ -1,9 +1,25 @@
 use bevy::prelude::*;
 
+pub mod classes;
 pub mod entities;
+pub mod factions;
+pub mod progression;
+pub mod scaling;
+pub mod survival;
+pub mod terrain;
 
 pub struct EchosAssetsPlugin;
 
 impl Plugin for EchosAssetsPlugin {
-    fn build(&self, app: &mut App) { app.add_plugins((entities::plugin,)); }
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            classes::plugin,
+            entities::plugin,
+            factions::plugin,
+            progression::plugin,
+            scaling::plugin,
+            survival::plugin,
+            terrain::plugin,
+        ));
+    }
 }
@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::scaling::ScalingCurve;
+
+/// Experience needed to reach level 2 when nothing else is given
+const DEFAULT_BASE_EXPERIENCE: u32 = 50;
+/// Stat points handed out per level when nothing else is given
+const DEFAULT_STAT_POINTS: u32 = 2;
+
+/// How characters level up, loaded from `*.progression.ron` files
+#[derive(Asset, Serialize, Deserialize, Debug, Clone, PartialEq, TypePath)]
+pub struct ProgressionTable {
+    /// Experience needed to go from level 1 to level 2
+    pub base_experience: Option<u32>,
+    /// How the experience needed for each further level grows with the level
+    #[serde(default)]
+    pub experience_curve: ScalingCurve,
+    /// Stat points to spend on every level gained
+    pub stat_points_per_level: Option<u32>,
+    /// Highest level a character can reach (defaults to no limit)
+    pub max_level: Option<u32>,
+}
+
+impl Default for ProgressionTable {
+    fn default() -> Self {
+        Self {
+            base_experience: None,
+            experience_curve: ScalingCurve::Flat,
+            stat_points_per_level: None,
+            max_level: None,
+        }
+    }
+}
+
+impl ProgressionTable {
+    pub fn new(base_experience: u32, experience_curve: ScalingCurve) -> Self {
+        Self { base_experience: Some(base_experience), experience_curve, ..Default::default() }
+    }
+
+    pub fn with_stat_points_per_level(mut self, points: u32) -> Self {
+        self.stat_points_per_level = Some(points);
+        self
+    }
+
+    pub fn with_max_level(mut self, level: u32) -> Self {
+        self.max_level = Some(level);
+        self
+    }
+
+    /// Experience needed to go from level 1 to level 2 (defaults to 50)
+    pub fn base_experience(&self) -> u32 { self.base_experience.unwrap_or(DEFAULT_BASE_EXPERIENCE) }
+
+    /// Stat points to spend per level gained (defaults to 2)
+    pub fn stat_points_per_level(&self) -> u32 { self.stat_points_per_level.unwrap_or(DEFAULT_STAT_POINTS) }
+
+    /// Highest reachable level (defaults to no limit)
+    pub fn max_level(&self) -> u32 { self.max_level.unwrap_or(u32::MAX) }
+
+    /// Experience needed to go from `level` to the next one
+    pub fn experience_to_advance(&self, level: u32) -> u64 {
+        (self.base_experience() as f32 * self.experience_curve.multiplier(level)).round().max(1.0) as u64
+    }
+
+    /// Total experience at which a character reaches `level`
+    pub fn threshold(&self, level: u32) -> u64 {
+        (1..level).map(|level| self.experience_to_advance(level)).sum()
+    }
+
+    /// The level a character with `experience` in total has reached
+    pub fn level_for(&self, experience: u64) -> u32 {
+        let mut level = 1;
+        let mut needed = 0;
+
+        while level < self.max_level() {
+            needed += self.experience_to_advance(level);
+            if experience < needed {
+                break;
+            }
+            level += 1;
+        }
+
+        level
+    }
+
+    /// Validate the table
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if self.base_experience == Some(0) {
+            errors.push("Base experience must be positive".to_string());
+        }
+        if self.max_level == Some(0) {
+            errors.push("Maximum level must be at least 1".to_string());
+        }
+        errors.extend(self.experience_curve.validate("Experience"));
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thresholds_follow_curve() {
+        let table = ProgressionTable::new(100, ScalingCurve::Linear(0.5));
+
+        assert_eq!(table.threshold(1), 0);
+        assert_eq!(table.threshold(2), 100);
+        assert_eq!(table.threshold(3), 250);
+        assert_eq!(table.threshold(4), 450);
+    }
+
+    #[test]
+    fn test_level_for_experience() {
+        let table = ProgressionTable::new(100, ScalingCurve::Linear(0.5)).with_max_level(3);
+
+        assert_eq!(table.level_for(0), 1);
+        assert_eq!(table.level_for(99), 1);
+        assert_eq!(table.level_for(100), 2);
+        assert_eq!(table.level_for(249), 2);
+        assert_eq!(table.level_for(10_000), 3);
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+use super::definition::ProgressionTable;
+
+/// Resource holding the loaded character progression table
+#[derive(AssetCollection, Resource, Debug)]
+pub struct ProgressionDefinitions {
+    #[asset(path = "progression/default.progression.ron")]
+    pub table: Handle<ProgressionTable>,
+}
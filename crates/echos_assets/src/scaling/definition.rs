@@ -0,0 +1,382 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{EntityDefinition, ScorerData, SpecialAttackData};
+
+/// How a multiplier grows with depth. Depth 1 always gets the base value.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect, Default)]
+pub enum ScalingCurve {
+    /// Never changes
+    #[default]
+    Flat,
+    /// Adds a fixed fraction of the base value per level
+    Linear(f32),
+    /// Compounds by a fraction per level
+    Exponential(f32),
+    /// Multipliers for depth 1, 2, 3 and so on; deeper levels keep the last one
+    Steps(Vec<f32>),
+}
+
+impl ScalingCurve {
+    /// Multiplier at a depth
+    pub fn multiplier(&self, depth: u32) -> f32 {
+        let levels = depth.saturating_sub(1);
+        match self {
+            Self::Flat => 1.0,
+            Self::Linear(per_level) => 1.0 + per_level * levels as f32,
+            Self::Exponential(rate) => (1.0 + rate).powi(levels as i32),
+            Self::Steps(steps) => steps.get(levels as usize).or(steps.last()).copied().unwrap_or(1.0),
+        }
+    }
+
+    pub(crate) fn validate(&self, label: &str) -> Vec<String> {
+        match self {
+            Self::Flat => Vec::new(),
+            Self::Linear(per_level) if *per_level < 0.0 => {
+                vec![format!("{label} scaling cannot shrink linearly, it would go negative")]
+            }
+            Self::Exponential(rate) if *rate <= -1.0 => {
+                vec![format!("{label} scaling rate must be greater than -1")]
+            }
+            Self::Steps(steps) if steps.is_empty() => {
+                vec![format!("{label} scaling needs at least one step")]
+            }
+            Self::Steps(steps) if steps.iter().any(|step| *step <= 0.0) => {
+                vec![format!("{label} scaling steps must be positive")]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Multipliers every enemy gets as it spawns deeper down
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect, Default)]
+pub struct DepthScaling {
+    #[serde(default)]
+    pub health: ScalingCurve,
+    #[serde(default)]
+    pub stats: ScalingCurve,
+    #[serde(default)]
+    pub speed: ScalingCurve,
+    /// Experience earned for the kill
+    #[serde(default)]
+    pub experience: ScalingCurve,
+}
+
+/// A tougher take on any enemy, such as an elite or a champion
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub struct EliteVariant {
+    /// Put in front of the enemy's name
+    pub prefix: String,
+    /// Chance an enemy spawning deep enough becomes this variant
+    pub chance: f32,
+    pub min_depth: Option<u32>,
+    pub health_multiplier: Option<f32>,
+    pub stats_multiplier: Option<f32>,
+    pub speed_multiplier: Option<f32>,
+    pub experience_multiplier: Option<f32>,
+    /// Sprite tint (RGBA) so the variant stands out
+    pub tint: Option<(f32, f32, f32, f32)>,
+    /// Extra special attacks on top of the enemy's own
+    pub special_attacks: Option<Vec<SpecialAttackData>>,
+}
+
+impl EliteVariant {
+    pub fn new(prefix: impl Into<String>, chance: f32) -> Self {
+        Self {
+            prefix: prefix.into(),
+            chance,
+            min_depth: None,
+            health_multiplier: None,
+            stats_multiplier: None,
+            speed_multiplier: None,
+            experience_multiplier: None,
+            tint: None,
+            special_attacks: None,
+        }
+    }
+
+    pub fn with_min_depth(mut self, depth: u32) -> Self {
+        self.min_depth = Some(depth);
+        self
+    }
+
+    pub fn with_multipliers(mut self, health: f32, stats: f32, speed: f32) -> Self {
+        self.health_multiplier = Some(health);
+        self.stats_multiplier = Some(stats);
+        self.speed_multiplier = Some(speed);
+        self
+    }
+
+    pub fn with_experience_multiplier(mut self, multiplier: f32) -> Self {
+        self.experience_multiplier = Some(multiplier);
+        self
+    }
+
+    pub fn with_tint(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.tint = Some((r, g, b, a));
+        self
+    }
+
+    pub fn with_special_attack(mut self, attack: SpecialAttackData) -> Self {
+        self.special_attacks.get_or_insert_with(Vec::new).push(attack);
+        self
+    }
+
+    /// Shallowest depth the variant turns up at (defaults to 1)
+    pub fn min_depth(&self) -> u32 { self.min_depth.unwrap_or(1) }
+
+    /// Health multiplier (defaults to 1.0)
+    pub fn health_multiplier(&self) -> f32 { self.health_multiplier.unwrap_or(1.0) }
+
+    /// Stats multiplier (defaults to 1.0)
+    pub fn stats_multiplier(&self) -> f32 { self.stats_multiplier.unwrap_or(1.0) }
+
+    /// Speed multiplier (defaults to 1.0)
+    pub fn speed_multiplier(&self) -> f32 { self.speed_multiplier.unwrap_or(1.0) }
+
+    /// Experience multiplier (defaults to 1.0)
+    pub fn experience_multiplier(&self) -> f32 { self.experience_multiplier.unwrap_or(1.0) }
+
+    /// Bonus special attacks (defaults to none)
+    pub fn special_attacks(&self) -> &[SpecialAttackData] {
+        self.special_attacks.as_deref().unwrap_or_default()
+    }
+
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let label = &self.prefix;
+
+        if self.prefix.trim().is_empty() {
+            errors.push("Variant prefix cannot be empty".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.chance) {
+            errors.push(format!("{label}: chance must be between 0 and 1"));
+        }
+        if self.min_depth == Some(0) {
+            errors.push(format!("{label}: minimum depth must be at least 1"));
+        }
+        for multiplier in
+            [self.health_multiplier, self.stats_multiplier, self.speed_multiplier, self.experience_multiplier]
+                .into_iter()
+                .flatten()
+        {
+            if multiplier <= 0.0 {
+                errors.push(format!("{label}: multipliers must be positive"));
+            }
+        }
+        for attack in self.special_attacks() {
+            errors.extend(attack.validate().into_iter().map(|error| format!("{label}: {error}")));
+        }
+
+        errors
+    }
+}
+
+/// Depth scaling and elite variants, loaded from `*.scaling.ron` files
+#[derive(Asset, Serialize, Deserialize, Debug, Clone, TypePath, Default)]
+pub struct ScalingTable {
+    #[serde(default)]
+    pub depth: DepthScaling,
+    /// Rolled in order; an enemy becomes the first variant it rolls
+    #[serde(default)]
+    pub variants: Vec<EliteVariant>,
+}
+
+impl ScalingTable {
+    /// Roll which variant, if any, an enemy spawning at a depth becomes
+    pub fn roll_variant(&self, depth: u32, rng: &mut fastrand::Rng) -> Option<&EliteVariant> {
+        self.variants
+            .iter()
+            .filter(|variant| depth >= variant.min_depth())
+            .find(|variant| rng.f32() < variant.chance)
+    }
+
+    /// A copy of a definition scaled for a depth and dressed up as a variant.
+    ///
+    /// Only components the definition has are scaled; spawn defaults for missing ones stay as they
+    /// are.
+    pub fn scale(
+        &self,
+        definition: &EntityDefinition,
+        depth: u32,
+        variant: Option<&EliteVariant>,
+    ) -> EntityDefinition {
+        let mut scaled = definition.clone();
+        let components = &mut scaled.components;
+
+        let mut health = self.depth.health.multiplier(depth);
+        let mut stats = self.depth.stats.multiplier(depth);
+        let mut speed = self.depth.speed.multiplier(depth);
+        let mut experience = self.depth.experience.multiplier(depth);
+
+        if let Some(variant) = variant {
+            health *= variant.health_multiplier();
+            stats *= variant.stats_multiplier();
+            speed *= variant.speed_multiplier();
+            experience *= variant.experience_multiplier();
+
+            scaled.name = format!("{} {}", variant.prefix, scaled.name);
+
+            if let (Some(tint), Some(sprite)) = (variant.tint, components.tile_sprite.as_mut()) {
+                sprite.tint = Some(tint);
+            }
+
+            if !variant.special_attacks().is_empty() {
+                components
+                    .special_attacks
+                    .get_or_insert_with(Vec::new)
+                    .extend_from_slice(variant.special_attacks());
+
+                // A hand-written thinker has to be told about the new attacks
+                if let Some(thinker) = components.thinker.take() {
+                    let uses_attacks =
+                        thinker.choices.iter().any(|choice| choice.scorer == ScorerData::SpecialAttack);
+                    components.thinker =
+                        Some(if uses_attacks { thinker } else { thinker.with_special_attacks() });
+                }
+            }
+        }
+
+        if let Some(data) = components.health.as_mut() {
+            data.max = scale_value(data.max, health);
+            data.current = scale_value(data.current, health);
+        }
+
+        if let Some(data) = components.stats.as_mut() {
+            for stat in [
+                &mut data.strength,
+                &mut data.defense,
+                &mut data.intelligence,
+                &mut data.agility,
+                &mut data.vitality,
+                &mut data.luck,
+            ] {
+                *stat = scale_value(*stat, stats);
+            }
+        }
+
+        if let Some(data) = components.turn_actor.as_mut() {
+            data.speed = (data.speed as f32 * speed).round().max(1.0) as u32;
+        }
+
+        // Tougher enemies are worth more, even those leaning on the default
+        components.experience = Some(scale_value(definition.experience() as i32, experience) as u32);
+
+        scaled
+    }
+
+    /// Validate curves and variants
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        errors.extend(self.depth.health.validate("Health"));
+        errors.extend(self.depth.stats.validate("Stats"));
+        errors.extend(self.depth.speed.validate("Speed"));
+        errors.extend(self.depth.experience.validate("Experience"));
+
+        for variant in &self.variants {
+            errors.extend(variant.validate());
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+/// Scale a positive value, never letting it drop to zero
+fn scale_value(value: i32, multiplier: f32) -> i32 {
+    if value <= 0 { value } else { ((value as f32 * multiplier).round() as i32).max(1) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{
+        AttackShape, EntityComponents, HealthData, StatsData, TileSpriteData, TurnActorData,
+    };
+
+    fn guard() -> EntityDefinition {
+        EntityDefinition::new("Guard", "").with_components(
+            EntityComponents::new()
+                .as_ai()
+                .with_turn_actor(TurnActorData::new(800))
+                .with_health(HealthData::new(50))
+                .with_stats(StatsData::balanced())
+                .with_tile_sprite(TileSpriteData::new((1, 2))),
+        )
+    }
+
+    #[test]
+    fn test_curves() {
+        assert_eq!(ScalingCurve::Flat.multiplier(9), 1.0);
+        assert_eq!(ScalingCurve::Linear(0.1).multiplier(1), 1.0);
+        assert!((ScalingCurve::Linear(0.1).multiplier(6) - 1.5).abs() < 1e-5);
+        assert!((ScalingCurve::Exponential(0.5).multiplier(3) - 2.25).abs() < 1e-5);
+
+        let steps = ScalingCurve::Steps(vec![1.0, 1.2, 1.5]);
+        assert_eq!(steps.multiplier(2), 1.2);
+        assert_eq!(steps.multiplier(10), 1.5);
+    }
+
+    #[test]
+    fn test_scale_by_depth() {
+        let table = ScalingTable {
+            depth: DepthScaling {
+                health: ScalingCurve::Linear(0.1),
+                stats: ScalingCurve::Linear(0.05),
+                speed: ScalingCurve::Flat,
+                experience: ScalingCurve::Linear(0.2),
+            },
+            variants: Vec::new(),
+        };
+
+        let scaled = table.scale(&guard(), 11, None);
+        let health = scaled.components.health.unwrap();
+        assert_eq!((health.current, health.max), (100, 100));
+        assert_eq!(scaled.components.stats.unwrap().strength, 15);
+        assert_eq!(scaled.components.turn_actor.unwrap().speed, 800);
+        assert_eq!(scaled.experience(), 30);
+        assert_eq!(scaled.name, "Guard");
+    }
+
+    #[test]
+    fn test_variant_dresses_up_enemy() {
+        let champion = EliteVariant::new("Champion", 1.0)
+            .with_multipliers(2.0, 1.5, 1.0)
+            .with_tint(1.0, 0.8, 0.2, 1.0)
+            .with_special_attack(SpecialAttackData::new("Whirlwind", AttackShape::Swipe, 4));
+        let table = ScalingTable { variants: vec![champion], ..Default::default() };
+
+        let variant = table.roll_variant(1, &mut fastrand::Rng::with_seed(1));
+        let scaled = table.scale(&guard(), 1, variant);
+
+        assert_eq!(scaled.name, "Champion Guard");
+        assert_eq!(scaled.components.health.unwrap().max, 100);
+        assert_eq!(scaled.components.stats.unwrap().defense, 15);
+        assert_eq!(scaled.components.tile_sprite.unwrap().tint, Some((1.0, 0.8, 0.2, 1.0)));
+        assert_eq!(scaled.special_attacks().len(), 1);
+    }
+
+    #[test]
+    fn test_variants_wait_for_their_depth() {
+        let table = ScalingTable {
+            variants: vec![EliteVariant::new("Elite", 1.0).with_min_depth(4)],
+            ..Default::default()
+        };
+        let mut rng = fastrand::Rng::with_seed(5);
+
+        assert!(table.roll_variant(3, &mut rng).is_none());
+        assert_eq!(table.roll_variant(4, &mut rng).map(|variant| variant.prefix.as_str()), Some("Elite"));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_data() {
+        let table = ScalingTable {
+            depth: DepthScaling { health: ScalingCurve::Steps(vec![1.0, 0.0]), ..Default::default() },
+            variants: vec![EliteVariant::new("", 1.5)],
+        };
+
+        let errors = table.validate().unwrap_err();
+        assert_eq!(errors.len(), 3);
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+use super::definition::ScalingTable;
+
+/// Resource holding the loaded monster scaling table
+#[derive(AssetCollection, Resource, Debug)]
+pub struct ScalingDefinitions {
+    #[asset(path = "scaling/default.scaling.ron")]
+    pub table: Handle<ScalingTable>,
+}
@@ -0,0 +1,16 @@
+pub mod definition;
+pub mod loader;
+
+pub use definition::*;
+pub use loader::*;
+
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(RonAssetPlugin::<ScalingTable>::new(&["scaling.ron"]));
+
+    app.register_type::<definition::ScalingCurve>()
+        .register_type::<definition::DepthScaling>()
+        .register_type::<definition::EliteVariant>();
+}
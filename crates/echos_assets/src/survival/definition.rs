@@ -0,0 +1,223 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+
+/// Satiety a character can hold when nothing else is given
+const DEFAULT_MAX_SATIETY: u32 = 2000;
+/// Satiety lost per standard turn when nothing else is given
+const DEFAULT_DRAIN_PER_TURN: f32 = 1.0;
+/// Satiety a ration restores when nothing else is given
+const DEFAULT_RATION_SATIETY: u32 = 800;
+
+/// How forgiving the game is
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// The next difficulty along, wrapping back to the easiest
+    pub fn next(self) -> Self {
+        match self {
+            Self::Easy => Self::Normal,
+            Self::Normal => Self::Hard,
+            Self::Hard => Self::Easy,
+        }
+    }
+}
+
+/// How hungry a character is, from well fed to collapsing
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect, Default,
+)]
+pub enum HungerStage {
+    #[default]
+    Satiated,
+    Hungry,
+    Weak,
+    Fainting,
+}
+
+impl HungerStage {
+    /// Name for the HUD (empty when satiated)
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Satiated => "",
+            Self::Hungry => "Hungry",
+            Self::Weak => "Weak",
+            Self::Fainting => "Fainting",
+        }
+    }
+}
+
+/// What a hunger stage does to a character
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub struct HungerStageData {
+    pub stage: HungerStage,
+    /// The stage starts once satiety drops below this fraction of the maximum
+    pub below: f32,
+    /// Stat changes while in this stage, by stat name
+    #[serde(default)]
+    pub stat_modifiers: HashMap<String, i32>,
+    /// Damage taken per standard turn while in this stage
+    pub damage_per_turn: Option<f32>,
+}
+
+impl HungerStageData {
+    pub fn new(stage: HungerStage, below: f32) -> Self {
+        Self { stage, below, stat_modifiers: HashMap::new(), damage_per_turn: None }
+    }
+
+    pub fn with_stat_modifier(mut self, stat: impl Into<String>, amount: i32) -> Self {
+        self.stat_modifiers.insert(stat.into(), amount);
+        self
+    }
+
+    pub fn with_damage_per_turn(mut self, damage: f32) -> Self {
+        self.damage_per_turn = Some(damage);
+        self
+    }
+}
+
+/// The food clock, loaded from `*.hunger.ron` files
+#[derive(Asset, Serialize, Deserialize, Debug, Clone, PartialEq, TypePath, Default)]
+pub struct HungerSettings {
+    /// Most satiety a character can hold (defaults to 2000)
+    pub max_satiety: Option<u32>,
+    /// Satiety lost per standard turn of elapsed time (defaults to 1.0)
+    pub drain_per_turn: Option<f32>,
+    /// How much faster hunger sets in during each kind of action, by action name
+    #[serde(default)]
+    pub exertion: HashMap<String, f32>,
+    /// Satiety a ration restores (defaults to 800)
+    pub ration_satiety: Option<u32>,
+    /// The stages of hunger past satiated
+    #[serde(default)]
+    pub stages: Vec<HungerStageData>,
+    /// Difficulties played without a food clock
+    #[serde(default)]
+    pub disabled_on: Vec<Difficulty>,
+}
+
+impl HungerSettings {
+    pub fn with_max_satiety(mut self, max_satiety: u32) -> Self {
+        self.max_satiety = Some(max_satiety);
+        self
+    }
+
+    pub fn with_drain_per_turn(mut self, drain: f32) -> Self {
+        self.drain_per_turn = Some(drain);
+        self
+    }
+
+    pub fn with_exertion(mut self, action: impl Into<String>, multiplier: f32) -> Self {
+        self.exertion.insert(action.into(), multiplier);
+        self
+    }
+
+    pub fn with_stage(mut self, stage: HungerStageData) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    pub fn disabled_on(mut self, difficulty: Difficulty) -> Self {
+        self.disabled_on.push(difficulty);
+        self
+    }
+
+    /// Most satiety a character can hold (defaults to 2000)
+    pub fn max_satiety(&self) -> f32 { self.max_satiety.unwrap_or(DEFAULT_MAX_SATIETY) as f32 }
+
+    /// Satiety lost per standard turn (defaults to 1.0)
+    pub fn drain_per_turn(&self) -> f32 { self.drain_per_turn.unwrap_or(DEFAULT_DRAIN_PER_TURN) }
+
+    /// Satiety a ration restores (defaults to 800)
+    pub fn ration_satiety(&self) -> f32 { self.ration_satiety.unwrap_or(DEFAULT_RATION_SATIETY) as f32 }
+
+    /// Whether the food clock runs at all on `difficulty`
+    pub fn is_enabled(&self, difficulty: Difficulty) -> bool { !self.disabled_on.contains(&difficulty) }
+
+    /// Satiety lost over `turns` standard turns spent on an action named `action`
+    pub fn drain(&self, action: &str, turns: f32) -> f32 {
+        self.drain_per_turn() * turns * self.exertion.get(action).copied().unwrap_or(1.0)
+    }
+
+    /// The deepest stage reached with `satiety` left, if any
+    pub fn stage_for(&self, satiety: f32) -> Option<&HungerStageData> {
+        let fraction = satiety / self.max_satiety().max(1.0);
+        self.stages.iter().filter(|stage| fraction < stage.below).max_by_key(|stage| stage.stage)
+    }
+
+    /// Validate the settings
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if self.max_satiety == Some(0) {
+            errors.push("Maximum satiety must be positive".to_string());
+        }
+        if self.drain_per_turn.is_some_and(|drain| drain < 0.0) {
+            errors.push("Drain per turn cannot be negative".to_string());
+        }
+        for (action, multiplier) in &self.exertion {
+            if *multiplier < 0.0 {
+                errors.push(format!("Exertion for '{action}' cannot be negative"));
+            }
+        }
+        for stage in &self.stages {
+            if stage.stage == HungerStage::Satiated {
+                errors.push("Satiated is not a stage of hunger".to_string());
+            }
+            if !(0.0..=1.0).contains(&stage.below) {
+                errors.push(format!("{:?} threshold must be between 0.0 and 1.0", stage.stage));
+            }
+            if stage.damage_per_turn.is_some_and(|damage| damage < 0.0) {
+                errors.push(format!("{:?} damage cannot be negative", stage.stage));
+            }
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> HungerSettings {
+        HungerSettings::default()
+            .with_max_satiety(1000)
+            .with_exertion("dig", 3.0)
+            .with_stage(HungerStageData::new(HungerStage::Hungry, 0.3))
+            .with_stage(HungerStageData::new(HungerStage::Weak, 0.1).with_stat_modifier("strength", -2))
+            .with_stage(HungerStageData::new(HungerStage::Fainting, 0.02).with_damage_per_turn(1.0))
+    }
+
+    #[test]
+    fn test_stage_for_picks_deepest_stage() {
+        let settings = settings();
+
+        assert_eq!(settings.stage_for(1000.0), None);
+        assert_eq!(settings.stage_for(250.0).map(|stage| stage.stage), Some(HungerStage::Hungry));
+        assert_eq!(settings.stage_for(50.0).map(|stage| stage.stage), Some(HungerStage::Weak));
+        assert_eq!(settings.stage_for(0.0).map(|stage| stage.stage), Some(HungerStage::Fainting));
+    }
+
+    #[test]
+    fn test_drain_scales_with_exertion() {
+        let settings = settings();
+
+        assert_eq!(settings.drain("move", 2.0), 2.0);
+        assert_eq!(settings.drain("dig", 2.0), 6.0);
+    }
+
+    #[test]
+    fn test_disabled_difficulties() {
+        let settings = settings().disabled_on(Difficulty::Easy);
+
+        assert!(!settings.is_enabled(Difficulty::Easy));
+        assert!(settings.is_enabled(Difficulty::Hard));
+        assert!(settings.validate().is_ok());
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+use super::definition::HungerSettings;
+
+/// Resource holding the loaded survival rules
+#[derive(AssetCollection, Resource, Debug)]
+pub struct SurvivalDefinitions {
+    #[asset(path = "survival/default.hunger.ron")]
+    pub hunger: Handle<HungerSettings>,
+}
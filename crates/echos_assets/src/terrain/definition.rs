@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::entities::MovementType;
+
+/// Light emitted by a terrain tile (glowing ore, lava, fungus, ...)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct TerrainLightData {
+    /// Light color as linear RGB
+    pub color: (f32, f32, f32),
+    /// Maximum range of the light in tiles
+    pub range: u32,
+    /// How quickly the light falls off with distance
+    pub falloff: f32,
+}
+
+/// Data definition for a single terrain type
+#[derive(Serialize, Deserialize, Debug, Clone, Reflect)]
+pub struct TerrainDefinition {
+    /// Unique identifier used by code and map generation (e.g. "floor", "iron_ore")
+    pub name: String,
+    /// ASCII glyph used for text output and debugging
+    pub glyph: char,
+    /// Index of the tile in the terrain atlas
+    pub atlas_index: u32,
+    /// Whether walkers can enter this tile
+    pub walkable: bool,
+    /// Whether this tile blocks vision and light
+    pub opaque: bool,
+    /// Movement cost multiplier for walkers (1 = open floor)
+    #[serde(default = "default_move_cost")]
+    pub move_cost: u32,
+    /// Per movement type cost overrides, `None` meaning impassable for that movement type
+    #[serde(default)]
+    pub movement_overrides: HashMap<MovementType, Option<u32>>,
+    /// Optional light emitted by the tile
+    #[serde(default)]
+    pub light: Option<TerrainLightData>,
+    /// Whether this tile can be dug out
+    #[serde(default)]
+    pub mineable: bool,
+    /// Whether this tile is an ore vein
+    #[serde(default)]
+    pub ore: bool,
+    /// Human-readable description
+    pub description: String,
+}
+
+fn default_move_cost() -> u32 { 1 }
+
+impl TerrainDefinition {
+    /// Create a new terrain definition with floor-like defaults
+    pub fn new(
+        name: impl Into<String>,
+        glyph: char,
+        atlas_index: u32,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            glyph,
+            atlas_index,
+            walkable: true,
+            opaque: false,
+            move_cost: 1,
+            movement_overrides: HashMap::new(),
+            light: None,
+            mineable: false,
+            ore: false,
+            description: description.into(),
+        }
+    }
+
+    /// Mark the tile as solid (not walkable and opaque)
+    pub fn solid(mut self) -> Self {
+        self.walkable = false;
+        self.opaque = true;
+        self
+    }
+
+    /// Mark the tile as not walkable
+    pub fn blocking(mut self) -> Self {
+        self.walkable = false;
+        self
+    }
+
+    /// Set the walking movement cost
+    pub fn with_move_cost(mut self, cost: u32) -> Self {
+        self.move_cost = cost;
+        self
+    }
+
+    /// Override the cost for a movement type (`None` = impassable)
+    pub fn with_override(mut self, movement_type: MovementType, cost: Option<u32>) -> Self {
+        self.movement_overrides.insert(movement_type, cost);
+        self
+    }
+
+    /// Set the light emitted by this tile
+    pub fn with_light(mut self, light: TerrainLightData) -> Self {
+        self.light = Some(light);
+        self
+    }
+
+    /// Mark the tile as mineable (and optionally as ore)
+    pub fn with_mineable(mut self, ore: bool) -> Self {
+        self.mineable = true;
+        self.ore = ore;
+        self
+    }
+
+    /// Movement cost for a movement type, `None` if it can't enter the tile
+    pub fn movement_cost(&self, movement_type: MovementType) -> Option<u32> {
+        match self.movement_overrides.get(&movement_type) {
+            Some(cost) => *cost,
+            None if self.walkable => Some(self.move_cost),
+            None => None,
+        }
+    }
+
+    /// Validate the terrain definition
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if self.name.trim().is_empty() {
+            errors.push("Terrain name cannot be empty".to_string());
+        }
+
+        if self.move_cost == 0 {
+            errors.push(format!("Terrain '{}' move cost must be positive", self.name));
+        }
+
+        if self.movement_overrides.values().any(|cost| *cost == Some(0)) {
+            errors.push(format!("Terrain '{}' movement overrides must be positive", self.name));
+        }
+
+        if let Some(light) = &self.light
+            && (light.range == 0 || light.falloff <= 0.0)
+        {
+            errors.push(format!("Terrain '{}' light needs a positive range and falloff", self.name));
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+/// Table of all terrain types, loaded from `*.terrain.ron` files
+#[derive(Asset, Serialize, Deserialize, Debug, Clone, TypePath, Default)]
+pub struct TerrainTable {
+    pub terrain: Vec<TerrainDefinition>,
+}
+
+impl TerrainTable {
+    /// Look up a definition by name
+    pub fn get(&self, name: &str) -> Option<&TerrainDefinition> {
+        self.terrain.iter().find(|definition| definition.name == name)
+    }
+
+    /// Validate every definition and check for duplicate names
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        let mut seen = HashSet::new();
+
+        for definition in &self.terrain {
+            if let Err(mut definition_errors) = definition.validate() {
+                errors.append(&mut definition_errors);
+            }
+
+            if !seen.insert(definition.name.as_str()) {
+                errors.push(format!("Duplicate terrain name '{}'", definition.name));
+            }
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_movement_cost_overrides() {
+        let chasm = TerrainDefinition::new("chasm", ' ', 0, "A chasm")
+            .blocking()
+            .with_override(MovementType::Flying, Some(1));
+
+        assert_eq!(chasm.movement_cost(MovementType::Walking), None);
+        assert_eq!(chasm.movement_cost(MovementType::Flying), Some(1));
+
+        let rubble = TerrainDefinition::new("rubble", ':', 517, "Rubble").with_move_cost(2);
+        assert_eq!(rubble.movement_cost(MovementType::Walking), Some(2));
+        assert_eq!(rubble.movement_cost(MovementType::Swimming), Some(2));
+    }
+
+    #[test]
+    fn test_table_rejects_duplicates() {
+        let table = TerrainTable {
+            terrain: vec![
+                TerrainDefinition::new("floor", '.', 515, "Floor"),
+                TerrainDefinition::new("floor", '.', 515, "Floor again"),
+            ],
+        };
+
+        let errors = table.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("Duplicate")));
+    }
+}
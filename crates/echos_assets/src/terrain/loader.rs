@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+use super::definition::TerrainTable;
+
+/// Resource holding the loaded terrain table
+#[derive(AssetCollection, Resource, Debug)]
+pub struct TerrainDefinitions {
+    #[asset(path = "terrain/default.terrain.ron")]
+    pub table: Handle<TerrainTable>,
+}
@@ -0,0 +1,14 @@
+pub mod definition;
+pub mod loader;
+
+pub use definition::*;
+pub use loader::*;
+
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(RonAssetPlugin::<TerrainTable>::new(&["terrain.ron"]));
+
+    app.register_type::<definition::TerrainDefinition>().register_type::<definition::TerrainLightData>();
+}
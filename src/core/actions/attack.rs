@@ -3,14 +3,17 @@ use fastrand::Rng;
 
 use crate::{
     core::{
-        components::{Health, Position, Stats},
-        events::{CombatEvent, DamageDealtEvent, EntityDeathEvent},
+        components::{Awareness, Encumbrance, Health, Position, StatModifiers, Stats},
+        events::{CombatEvent, DamageDealtEvent, EntityDeathEvent, NoiseEvent, NoiseKind},
         resources::CurrentMap,
         types::{ActionType, GameAction, GameError},
     },
     debug_combat,
 };
 
+/// Damage multiplier for hitting a target that never saw the attack coming
+pub const SNEAK_ATTACK_MULTIPLIER: f32 = 2.0;
+
 #[derive(Clone, Debug)]
 pub struct AttackAction {
     entity: Entity,
@@ -19,38 +22,6 @@ pub struct AttackAction {
 
 impl AttackAction {
     pub fn new(entity: Entity, target_position: Position) -> Self { Self { entity, target_position } }
-
-    fn calculate_damage(&self, attacker_stats: &Stats, defender_stats: &Stats) -> i32 {
-        let base_damage = 10; // Base weapon damage
-        let strength_bonus = attacker_stats.melee_damage_bonus();
-        let defense_reduction = defender_stats.damage_reduction();
-
-        let raw_damage = base_damage + strength_bonus;
-        let final_damage = (raw_damage - defense_reduction).max(1); // Minimum 1 damage
-
-        // Apply critical hit chance
-        let mut rng = Rng::new();
-        let crit_chance = attacker_stats.critical_chance();
-        let is_critical = rng.f32() * 100.0 <= crit_chance;
-
-        if is_critical {
-            debug_combat!("Critical hit!");
-            ((final_damage as f32) * 1.5).round() as i32
-        } else {
-            final_damage
-        }
-    }
-
-    fn calculate_accuracy(&self, attacker_stats: &Stats, defender_stats: &Stats) -> bool {
-        let base_accuracy = 85.0; // 85% base hit chance
-        let accuracy_bonus = attacker_stats.accuracy_bonus() as f32 * 2.0;
-        let evasion_penalty = defender_stats.evasion_bonus() as f32 * 2.0;
-
-        let final_accuracy = (base_accuracy + accuracy_bonus - evasion_penalty).clamp(5.0, 95.0);
-
-        let mut rng = Rng::new();
-        rng.f32() * 100.0 <= final_accuracy
-    }
 }
 
 impl GameAction for AttackAction {
@@ -59,85 +30,153 @@ impl GameAction for AttackAction {
     fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
         debug_combat!("Entity {:?} attacks position {:?}", self.entity, self.target_position);
 
-        // Get the current map to find the target
-        let target_entity = {
-            let map = world.resource::<CurrentMap>();
-            map.get_actor(self.target_position)
-        };
-
-        let target_entity = match target_entity {
-            Some(entity) => entity,
-            None => {
-                debug_combat!("No target at position {:?}", self.target_position);
-                return Ok(self.duration());
-            }
-        };
-
-        // Get attacker stats
-        let attacker_stats = world.get::<Stats>(self.entity).cloned().ok_or(GameError::MissingComponent {
-            entity: self.entity,
-            component: std::any::type_name::<Stats>(),
-        })?;
-
-        let defender_stats =
-            world.get::<Stats>(target_entity).cloned().ok_or(GameError::MissingComponent {
-                entity: target_entity,
-                component: std::any::type_name::<Stats>(),
-            })?;
-
-        // Calculate hit chance
-        if !self.calculate_accuracy(&attacker_stats, &defender_stats) {
-            debug_combat!("Attack missed!");
-            world.send_event(CombatEvent::AttackMissed { attacker: self.entity, target: target_entity });
-            return Ok(self.duration());
+        strike(world, self.entity, self.target_position, 1.0)?;
+        Ok(self.duration())
+    }
+
+    fn duration(&self) -> u64 { self.action_type().get_base_time_to_perform() }
+}
+
+/// Resolve one melee strike by `attacker` against whoever stands on `target_position`.
+///
+/// Damage is scaled by `damage_multiplier` before sneak attack and criticals apply. Striking an
+/// empty tile does nothing. Returns whether anyone was there to strike.
+pub fn strike(
+    world: &mut World,
+    attacker: Entity,
+    target_position: Position,
+    damage_multiplier: f32,
+) -> Result<bool, GameError> {
+    // Get the current map to find the target
+    let target_entity = {
+        let map = world.resource::<CurrentMap>();
+        map.get_actor(target_position)
+    };
+
+    let target_entity = match target_entity {
+        Some(entity) => entity,
+        None => {
+            debug_combat!("No target at position {:?}", target_position);
+            return Ok(false);
         }
+    };
 
-        // Calculate damage
-        let damage = self.calculate_damage(&attacker_stats, &defender_stats);
-        debug_combat!("Attack hits for {} damage!", damage);
-
-        // Apply damage to target and collect result
-        let (actual_damage, target_died) = {
-            let mut target_health = world.get_mut::<Health>(target_entity);
-            if let Some(ref mut health) = target_health {
-                let actual_damage = health.take_damage(damage);
-                let target_died = health.is_dead();
-                (actual_damage, target_died)
-            } else {
-                debug_combat!("Target has no health component!");
-                return Err(GameError::MissingComponent {
-                    entity: target_entity,
-                    component: std::any::type_name::<Health>(),
-                });
-            }
-        };
-
-        // Send events after releasing the mutable borrow
-        world.send_event(DamageDealtEvent {
-            attacker: self.entity,
-            target: target_entity,
-            damage: actual_damage,
-            position: self.target_position,
-        });
+    // Stats as they stand right now, buffs and debuffs included
+    let attacker_stats = effective_stats(world, attacker)?;
+    let defender_stats = effective_stats(world, target_entity)?;
 
-        world.send_event(CombatEvent::AttackHit {
-            attacker: self.entity,
-            target: target_entity,
-            damage: actual_damage,
-        });
+    // Hit or miss, a fight is loud
+    world.send_event(NoiseEvent::new(attacker, target_position, NoiseKind::Combat));
+
+    // Being attacked leaves no doubt; remember whether the target was caught off guard first
+    let target_unaware = match world.get_mut::<Awareness>(target_entity) {
+        Some(mut awareness) => {
+            let unaware = awareness.is_unaware();
+            awareness.alarm();
+            unaware
+        }
+        None => false,
+    };
+
+    // Calculate hit chance; a weighed-down defender is slower to get out of the way
+    let defender_encumbrance = world.get::<Encumbrance>(target_entity).copied().unwrap_or_default();
+    if !calculate_accuracy(&attacker_stats, &defender_stats, defender_encumbrance) {
+        debug_combat!("Attack missed!");
+        world.send_event(CombatEvent::AttackMissed { attacker, target: target_entity });
+        return Ok(true);
+    }
 
-        // Check if target died
-        if target_died {
-            debug_combat!("Target died!");
-            world.send_event(EntityDeathEvent {
+    // Calculate damage
+    let mut damage = calculate_damage(&attacker_stats, &defender_stats, damage_multiplier);
+    if target_unaware {
+        debug_combat!("Sneak attack!");
+        damage = ((damage as f32) * SNEAK_ATTACK_MULTIPLIER).round() as i32;
+    }
+    debug_combat!("Attack hits for {} damage!", damage);
+
+    // Apply damage to target and collect result
+    let (actual_damage, target_died) = {
+        let mut target_health = world.get_mut::<Health>(target_entity);
+        if let Some(ref mut health) = target_health {
+            let actual_damage = health.take_damage(damage);
+            let target_died = health.is_dead();
+            (actual_damage, target_died)
+        } else {
+            debug_combat!("Target has no health component!");
+            return Err(GameError::MissingComponent {
                 entity: target_entity,
-                position: self.target_position,
-                killer: Some(self.entity),
+                component: std::any::type_name::<Health>(),
             });
         }
+    };
+
+    // Send events after releasing the mutable borrow
+    world.send_event(DamageDealtEvent {
+        attacker,
+        target: target_entity,
+        damage: actual_damage,
+        position: target_position,
+    });
+
+    world.send_event(CombatEvent::AttackHit { attacker, target: target_entity, damage: actual_damage });
+
+    // Check if target died
+    if target_died {
+        debug_combat!("Target died!");
+        world.send_event(EntityDeathEvent {
+            entity: target_entity,
+            position: target_position,
+            killer: Some(attacker),
+        });
+    }
 
-        Ok(self.duration())
+    Ok(true)
+}
+
+/// An entity's stats with its temporary modifiers applied
+fn effective_stats(world: &World, entity: Entity) -> Result<Stats, GameError> {
+    let stats = world
+        .get::<Stats>(entity)
+        .ok_or(GameError::MissingComponent { entity, component: std::any::type_name::<Stats>() })?;
+
+    Ok(world
+        .get::<StatModifiers>(entity)
+        .map_or_else(|| stats.clone(), |modifiers| stats.with_modifiers(modifiers)))
+}
+
+fn calculate_damage(attacker_stats: &Stats, defender_stats: &Stats, damage_multiplier: f32) -> i32 {
+    let base_damage = 10; // Base weapon damage
+    let strength_bonus = attacker_stats.melee_damage_bonus();
+    let defense_reduction = defender_stats.damage_reduction();
+
+    let raw_damage = ((base_damage + strength_bonus) as f32 * damage_multiplier).round() as i32;
+    let final_damage = (raw_damage - defense_reduction).max(1); // Minimum 1 damage
+
+    // Apply critical hit chance
+    let mut rng = Rng::new();
+    let crit_chance = attacker_stats.critical_chance();
+    let is_critical = rng.f32() * 100.0 <= crit_chance;
+
+    if is_critical {
+        debug_combat!("Critical hit!");
+        ((final_damage as f32) * 1.5).round() as i32
+    } else {
+        final_damage
     }
+}
 
-    fn duration(&self) -> u64 { self.action_type().get_base_time_to_perform() }
+fn calculate_accuracy(
+    attacker_stats: &Stats,
+    defender_stats: &Stats,
+    defender_encumbrance: Encumbrance,
+) -> bool {
+    let base_accuracy = 85.0; // 85% base hit chance
+    let accuracy_bonus = attacker_stats.accuracy_bonus() as f32 * 2.0;
+    let evasion_penalty =
+        defender_stats.evasion_bonus() as f32 * 2.0 - defender_encumbrance.evasion_penalty();
+
+    let final_accuracy = (base_accuracy + accuracy_bonus - evasion_penalty).clamp(5.0, 95.0);
+
+    let mut rng = Rng::new();
+    rng.f32() * 100.0 <= final_accuracy
 }
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use brtk::random::Random;
+
+use crate::{
+    core::{
+        components::{Corpse, Health, LootTable, Position},
+        systems::combat::drop_items,
+        types::{ActionType, GameAction, GameError},
+    },
+    gameplay::world::generation::GenConfig,
+};
+
+/// Share of the eater's maximum health a meal of carrion restores
+pub const SCAVENGE_HEAL_FRACTION: f32 = 0.25;
+
+/// The nearest corpse on or next to `origin` that passes `filter`
+fn corpse_within_reach(
+    world: &mut World,
+    origin: Position,
+    filter: impl Fn(&Corpse) -> bool,
+) -> Option<Entity> {
+    let mut corpse_query = world.query::<(Entity, &Position, &Corpse)>();
+    corpse_query
+        .iter(world)
+        .filter(|(_, position, corpse)| position.pathfinding_distance(&origin) <= 1.0 && filter(corpse))
+        .min_by_key(|(_, position, _)| position.distance_squared(&origin))
+        .map(|(entity, ..)| entity)
+}
+
+/// Roll an unsearched corpse's loot and scatter it around the corpse
+fn spill_corpse_loot(world: &mut World, corpse: Entity) {
+    let depth = world.resource::<GenConfig>().depth as u32;
+    let Some(position) = world.get::<Position>(corpse).copied() else {
+        return;
+    };
+    let loot_table = world.get::<LootTable>(corpse).cloned();
+
+    let drops = world.resource_scope(|world, mut random: Mut<Random>| {
+        world
+            .get_mut::<Corpse>(corpse)
+            .map(|mut corpse| corpse.search(loot_table.as_ref(), depth, &mut random))
+            .unwrap_or_default()
+    });
+
+    drop_items(world, position, drops);
+}
+
+/// Search a corpse on or next to the actor, scattering its loot around it
+#[derive(Clone, Debug)]
+pub struct SearchAction {
+    entity: Entity,
+}
+
+impl SearchAction {
+    pub fn new(entity: Entity) -> Self { Self { entity } }
+}
+
+impl GameAction for SearchAction {
+    fn action_type(&self) -> ActionType { ActionType::Search }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let origin =
+            world.get::<Position>(self.entity).copied().ok_or(GameError::EntityNotFound(self.entity))?;
+
+        let Some(corpse) = corpse_within_reach(world, origin, |corpse| !corpse.searched) else {
+            log::info!("There is nothing here to search.");
+            return Err(GameError::NoValidTargets);
+        };
+
+        spill_corpse_loot(world, corpse);
+        if let Some(name) = world.get::<Name>(corpse) {
+            log::info!("Entity {} searched the {}", self.entity, name);
+        }
+
+        Ok(self.duration())
+    }
+}
+
+/// Eat a corpse on or next to the actor, healing a little. Any loot it still held spills out.
+#[derive(Clone, Debug)]
+pub struct ScavengeAction {
+    entity: Entity,
+}
+
+impl ScavengeAction {
+    pub fn new(entity: Entity) -> Self { Self { entity } }
+}
+
+impl GameAction for ScavengeAction {
+    fn action_type(&self) -> ActionType { ActionType::Scavenge }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let origin =
+            world.get::<Position>(self.entity).copied().ok_or(GameError::EntityNotFound(self.entity))?;
+
+        let corpse = corpse_within_reach(world, origin, |_| true).ok_or(GameError::NoValidTargets)?;
+
+        spill_corpse_loot(world, corpse);
+        if let Some(name) = world.get::<Name>(corpse) {
+            log::info!("Entity {} devoured the {}", self.entity, name);
+        }
+        world.despawn(corpse);
+
+        if let Some(mut health) = world.get_mut::<Health>(self.entity) {
+            let meal = ((health.max as f32 * SCAVENGE_HEAL_FRACTION).round() as i32).max(1);
+            health.heal(meal);
+        }
+
+        Ok(self.duration())
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::tiles::TileTextureIndex;
+
+use crate::{
+    core::{
+        components::{Description, Position},
+        events::{NoiseEvent, NoiseKind},
+        pathfinding,
+        resources::CurrentMap,
+        types::{ActionType, GameAction, GameError},
+    },
+    gameplay::world::components::TerrainType,
+};
+
+/// Dig out a mineable tile next to the digger, leaving open floor behind
+#[derive(Clone, Debug)]
+pub struct DigAction {
+    entity: Entity,
+    target_position: Position,
+}
+
+impl DigAction {
+    pub fn new(entity: Entity, target_position: Position) -> Self { Self { entity, target_position } }
+}
+
+impl GameAction for DigAction {
+    fn action_type(&self) -> ActionType { ActionType::Dig(self.target_position) }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let origin =
+            world.get::<Position>(self.entity).copied().ok_or(GameError::EntityNotFound(self.entity))?;
+
+        let distance = origin.pathfinding_distance(&self.target_position);
+        if distance > 1.0 {
+            return Err(GameError::out_of_range(distance, 1.0));
+        }
+
+        let tile_entity = {
+            let mut current_map = world.resource_mut::<CurrentMap>();
+            let terrain = current_map
+                .get_terrain(self.target_position)
+                .ok_or(GameError::OutOfBounds(self.target_position))?;
+
+            if !terrain.is_mineable() {
+                return Err(GameError::InvalidTarget);
+            }
+
+            current_map.set_terrain(self.target_position, TerrainType::FLOOR);
+            current_map.get_tile_entity(self.target_position)
+        };
+
+        // Keep the rendered tile in step with the map
+        if let Some(tile_entity) = tile_entity {
+            world.entity_mut(tile_entity).insert((
+                TerrainType::FLOOR,
+                TileTextureIndex(TerrainType::FLOOR.texture_index()),
+                Description::new(TerrainType::FLOOR.description()),
+            ));
+        }
+
+        // The layout changed, so cached paths are stale
+        pathfinding::utils::clear_pathfinding_cache();
+
+        world.send_event(NoiseEvent::new(self.entity, self.target_position, NoiseKind::Digging));
+        log::info!("Entity {} dug out {:?}", self.entity, self.target_position);
+
+        Ok(self.duration())
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::{
+        components::{Description, Position},
+        events::{NoiseEvent, NoiseKind},
+        resources::CurrentMap,
+        types::{ActionType, GameAction, GameError},
+    },
+    gameplay::world::{components::Feature, systems::feature_sprite},
+};
+
+/// Open or close a door next to the actor
+#[derive(Clone, Debug)]
+pub struct ToggleDoorAction {
+    entity: Entity,
+    target_position: Position,
+}
+
+impl ToggleDoorAction {
+    pub fn new(entity: Entity, target_position: Position) -> Self { Self { entity, target_position } }
+}
+
+impl GameAction for ToggleDoorAction {
+    fn action_type(&self) -> ActionType { ActionType::ToggleDoor(self.target_position) }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let origin =
+            world.get::<Position>(self.entity).copied().ok_or(GameError::EntityNotFound(self.entity))?;
+
+        let distance = origin.pathfinding_distance(&self.target_position);
+        if distance > 1.0 {
+            return Err(GameError::out_of_range(distance, 1.0));
+        }
+
+        let (door, occupied) = {
+            let current_map = world.resource::<CurrentMap>();
+            let door = current_map.get_feature(self.target_position).ok_or(GameError::InvalidTarget)?;
+            (door, current_map.get_actor(self.target_position).is_some())
+        };
+
+        let Some(Feature::Door { open }) = world.get::<Feature>(door).copied() else {
+            return Err(GameError::InvalidTarget);
+        };
+
+        // Something standing in the doorway keeps it from closing
+        if open && occupied {
+            return Err(GameError::ActionBlocked);
+        }
+
+        let toggled = Feature::Door { open: !open };
+        // Drop the old sprite so it is rebuilt from the new tile sprite
+        world
+            .entity_mut(door)
+            .insert((toggled, Description::new(toggled.description()), feature_sprite(toggled)))
+            .remove::<Sprite>();
+
+        world.send_event(NoiseEvent::new(self.entity, self.target_position, NoiseKind::Door));
+        log::info!(
+            "Entity {} {} the door at {:?}",
+            self.entity,
+            if open { "closed" } else { "opened" },
+            self.target_position
+        );
+
+        Ok(self.duration())
+    }
+}
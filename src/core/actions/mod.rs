@@ -9,3 +9,33 @@ pub use teleport::*;
 
 mod attack;
 pub use attack::*;
+
+mod dig;
+pub use dig::*;
+
+mod door;
+pub use door::*;
+
+mod ping;
+pub use ping::*;
+
+mod special_attack;
+pub use special_attack::*;
+
+mod corpse;
+pub use corpse::*;
+
+mod pick_up;
+pub use pick_up::*;
+
+mod use_item;
+pub use use_item::*;
+
+mod torch;
+pub use torch::*;
+
+mod stairs;
+pub use stairs::*;
+
+mod shoot;
+pub use shoot::*;
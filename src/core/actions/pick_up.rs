@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::core::{
+    actions::hold_torch,
+    components::{Equipment, FloorItem, Fuel, Inventory, InventoryError, Position},
+    resources::CurrentMap,
+    types::{ActionType, GameAction, GameError},
+};
+
+/// Pick up whatever lies on the actor's tile.
+///
+/// Anything that would take the actor past its carry capacity is left where it is, unless the
+/// actor chose to overload itself. A torch left burning is taken back in hand, if the actor's hand
+/// is free.
+#[derive(Clone, Debug)]
+pub struct PickUpAction {
+    entity: Entity,
+    allow_overload: bool,
+}
+
+impl PickUpAction {
+    pub fn new(entity: Entity, allow_overload: bool) -> Self { Self { entity, allow_overload } }
+}
+
+impl GameAction for PickUpAction {
+    fn action_type(&self) -> ActionType { ActionType::PickUp { allow_overload: self.allow_overload } }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let origin =
+            world.get::<Position>(self.entity).copied().ok_or(GameError::EntityNotFound(self.entity))?;
+
+        let floor_items = world.resource::<CurrentMap>().get_items(origin).to_vec();
+        if floor_items.is_empty() {
+            log::info!("There is nothing here to pick up.");
+            return Err(GameError::NoValidTargets);
+        }
+
+        let mut picked_up = false;
+        for floor_entity in floor_items {
+            let Some(item) = world.get::<FloorItem>(floor_entity).map(|floor_item| floor_item.0.clone())
+            else {
+                continue;
+            };
+
+            if let Some(fuel) = world.get::<Fuel>(floor_entity).copied() {
+                if world.get::<Fuel>(self.entity).is_some() {
+                    log::info!("You are already holding a lit torch.");
+                    continue;
+                }
+                world.despawn(floor_entity);
+                hold_torch(&mut world.entity_mut(self.entity), item, fuel);
+                log::info!("You take up the burning torch.");
+                picked_up = true;
+                continue;
+            }
+
+            // What the actor has equipped weighs on it too
+            let equipment = world.get::<Equipment>(self.entity).cloned();
+            let mut inventory =
+                world.get_mut::<Inventory>(self.entity).ok_or(GameError::MissingComponent {
+                    entity: self.entity,
+                    component: std::any::type_name::<Inventory>(),
+                })?;
+
+            match inventory.add_item_with_overload(item.clone(), equipment.as_ref(), self.allow_overload) {
+                Ok(0) => {
+                    log::info!("Picked up {} x{}", item.name, item.quantity);
+                    world.despawn(floor_entity);
+                    picked_up = true;
+                }
+                Ok(remaining) if remaining < item.quantity => {
+                    log::info!(
+                        "Picked up {} x{}, but had no room for the rest",
+                        item.name,
+                        item.quantity - remaining
+                    );
+                    if let Some(mut floor_item) = world.get_mut::<FloorItem>(floor_entity) {
+                        floor_item.0.quantity = remaining;
+                    }
+                    picked_up = true;
+                }
+                Ok(_) | Err(InventoryError::InventoryFull) => {
+                    log::info!("There's no room to carry the {}.", item.name);
+                }
+                Err(InventoryError::ExceedsWeightLimit) => {
+                    log::info!("The {} is too heavy to carry. Press Shift+G to take it anyway.", item.name);
+                }
+                Err(e) => log::warn!("Failed to pick up {}: {e}", item.name),
+            }
+        }
+
+        if !picked_up {
+            return Err(GameError::ActionBlocked);
+        }
+
+        Ok(self.duration())
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::core::{
+    components::{PlayerTag, Position},
+    events::{NoiseEvent, NoiseKind},
+    resources::{CurrentMap, FovMap},
+    types::{ActionType, GameAction, GameError},
+};
+
+/// How many steps the echolocation wave travels
+pub const PING_RADIUS: u32 = 12;
+/// How many turns the outlines linger before fading
+pub const PING_ECHO_TURNS: u8 = 5;
+/// How many turns an actor's silhouette lingers; actors move on, so it goes stale sooner
+pub const PING_SILHOUETTE_TURNS: u8 = 2;
+
+/// Send out an echolocation ping.
+///
+/// For the player this outlines nearby walls and actors, even around corners. Anyone can ping, and
+/// it is loud: every AI within earshot learns where it came from.
+#[derive(Clone, Debug)]
+pub struct PingAction {
+    entity: Entity,
+}
+
+impl PingAction {
+    pub fn new(entity: Entity) -> Self { Self { entity } }
+}
+
+impl GameAction for PingAction {
+    fn action_type(&self) -> ActionType { ActionType::Ping }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let origin =
+            world.get::<Position>(self.entity).copied().ok_or(GameError::EntityNotFound(self.entity))?;
+
+        // Only the player's pings feed what is drawn on screen
+        if world.get::<PlayerTag>(self.entity).is_some() {
+            world.resource_scope(|world, mut fov_map: Mut<FovMap>| {
+                let echoed = fov_map.echolocate(
+                    world.resource::<CurrentMap>(),
+                    origin,
+                    PING_RADIUS,
+                    PING_ECHO_TURNS,
+                    PING_SILHOUETTE_TURNS,
+                );
+                log::info!("Ping from {:?} echoed off {} tiles", origin, echoed.len());
+            });
+        }
+
+        world.send_event(NoiseEvent::new(self.entity, origin, NoiseKind::Ping));
+
+        Ok(self.duration())
+    }
+}
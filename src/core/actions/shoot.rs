@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::{
+        actions::strike,
+        components::Position,
+        resources::{CurrentMap, FovMap},
+        types::{ActionType, GameAction, GameError},
+    },
+    debug_combat,
+};
+
+/// Strike a target in sight from up to `range` tiles away
+#[derive(Clone, Debug)]
+pub struct ShootAction {
+    entity: Entity,
+    target_position: Position,
+    range: u8,
+}
+
+impl ShootAction {
+    pub fn new(entity: Entity, target_position: Position, range: u8) -> Self {
+        Self { entity, target_position, range }
+    }
+}
+
+impl GameAction for ShootAction {
+    fn action_type(&self) -> ActionType {
+        ActionType::Shoot { target: self.target_position, range: self.range }
+    }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let origin = *world.get::<Position>(self.entity).ok_or(GameError::EntityNotFound(self.entity))?;
+
+        let distance = origin.fov_range_distance(&self.target_position);
+        if distance > self.range as f32 {
+            return Err(GameError::out_of_range(distance, self.range as f32));
+        }
+
+        if !FovMap::can_see_entity(origin, self.range, self.target_position, world.resource::<CurrentMap>()) {
+            debug_combat!("Entity {:?} has no line of fire to {:?}", self.entity, self.target_position);
+            return Err(GameError::InvalidTarget);
+        }
+
+        debug_combat!("Entity {:?} shoots at {:?}", self.entity, self.target_position);
+        if !strike(world, self.entity, self.target_position, 1.0)? {
+            return Err(GameError::InvalidTarget);
+        }
+
+        Ok(self.duration())
+    }
+}
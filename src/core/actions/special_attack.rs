@@ -0,0 +1,300 @@
+use bevy::prelude::*;
+use brtk::{
+    grid_shapes::{Line, Shape},
+    prelude::Direction,
+};
+use echos_assets::entities::AttackShape;
+
+use crate::{
+    core::{
+        actions::strike,
+        components::{Faction, Movement, PlayerTag, Position, SpecialAttacks},
+        events::{NoiseEvent, NoiseKind},
+        resources::{CurrentMap, FovMap, Map},
+        types::{ActionType, GameAction, GameError},
+    },
+    debug_combat,
+    gameplay::factions::FactionRegistry,
+    prelude::assets::MovementType,
+};
+
+/// Cosine of the widest angle from its aim that a breath still reaches (about 35 degrees)
+const BREATH_SPREAD: f32 = 0.82;
+
+/// Where a special attack lands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackArea {
+    /// Tiles struck, nearest first
+    pub tiles: Vec<Position>,
+    /// Where the attacker ends up; only a charge moves it
+    pub landing: Position,
+}
+
+#[derive(Clone, Debug)]
+pub struct SpecialAttackAction {
+    entity: Entity,
+    index: u8,
+    target_position: Position,
+}
+
+impl SpecialAttackAction {
+    pub fn new(entity: Entity, index: u8, target_position: Position) -> Self {
+        Self { entity, index, target_position }
+    }
+}
+
+impl GameAction for SpecialAttackAction {
+    fn action_type(&self) -> ActionType {
+        ActionType::SpecialAttack { index: self.index, target: self.target_position }
+    }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let origin = *world.get::<Position>(self.entity).ok_or(GameError::EntityNotFound(self.entity))?;
+
+        let attack = {
+            let special_attacks =
+                world.get::<SpecialAttacks>(self.entity).ok_or(GameError::MissingComponent {
+                    entity: self.entity,
+                    component: std::any::type_name::<SpecialAttacks>(),
+                })?;
+
+            let attack = special_attacks
+                .get(self.index)
+                .cloned()
+                .ok_or_else(|| GameError::InvalidWeapon(format!("special attack {}", self.index)))?;
+
+            let remaining = special_attacks.remaining(self.index);
+            if remaining > 0 {
+                return Err(GameError::on_cooldown(remaining));
+            }
+            attack
+        };
+
+        let movement_type = Movement::type_of(world.get::<Movement>(self.entity));
+        let area = attack_area(
+            attack.shape,
+            origin,
+            self.target_position,
+            world.resource::<CurrentMap>(),
+            self.entity,
+            movement_type,
+        );
+        debug_combat!("Entity {:?} uses {} on {:?}", self.entity, attack.name, area.tiles);
+
+        if let Some(mut special_attacks) = world.get_mut::<SpecialAttacks>(self.entity) {
+            special_attacks.start_cooldown(self.index);
+            special_attacks.planned = None;
+        }
+
+        // Re-inserting Position runs its hooks, which move the actor on the map
+        if area.landing != origin {
+            world.entity_mut(self.entity).insert(area.landing);
+        }
+
+        world.send_event(NoiseEvent::new(self.entity, area.landing, NoiseKind::Combat));
+
+        // Sweeping blows pass over allies caught in the area
+        let attacker_faction = faction_of(world, self.entity);
+        let tiles: Vec<Position> = area
+            .tiles
+            .into_iter()
+            .filter(|tile| {
+                world.resource::<CurrentMap>().get_actor(*tile).is_none_or(|actor| {
+                    !world
+                        .resource::<FactionRegistry>()
+                        .is_friendly(attacker_faction, faction_of(world, actor))
+                })
+            })
+            .collect();
+
+        // One target that can't be struck doesn't spare the rest of the area
+        for tile in tiles {
+            if let Err(e) = strike(world, self.entity, tile, attack.damage_multiplier()) {
+                warn!("{} failed to strike {:?}: {}", attack.name, tile, e);
+            }
+        }
+
+        Ok(self.duration())
+    }
+}
+
+/// Faction an actor fights for
+fn faction_of(world: &World, entity: Entity) -> Faction {
+    Faction::of(world.get::<Faction>(entity), world.get::<PlayerTag>(entity).is_some())
+}
+
+/// The tiles a special attack from `origin` aimed at `target` would strike.
+///
+/// Walls stop everything: a charge halts in front of them, and slams and breaths only reach
+/// tiles in sight of the attacker.
+pub fn attack_area(
+    shape: AttackShape,
+    origin: Position,
+    target: Position,
+    map: &Map,
+    attacker: Entity,
+    movement_type: MovementType,
+) -> AttackArea {
+    let mut tiles: Vec<Position> = match shape {
+        AttackShape::Swipe => {
+            Direction::iter_cardinal_ordinal().map(|direction| origin + direction.coord()).collect()
+        }
+        AttackShape::Charge { length } => {
+            return charge_area(origin, target, length, map, attacker, movement_type);
+        }
+        AttackShape::Slam { radius } => {
+            let in_sight = FovMap::compute_temporary_fov(origin, radius as u8, map);
+            in_sight
+                .get_visible_set()
+                .iter()
+                .map(|&tile| Position::from(tile))
+                .filter(|tile| within_reach(origin, *tile, radius))
+                .collect()
+        }
+        AttackShape::Breath { length } => {
+            let in_sight = FovMap::compute_temporary_fov(origin, length as u8, map);
+            in_sight
+                .get_visible_set()
+                .iter()
+                .map(|&tile| Position::from(tile))
+                .filter(|tile| within_reach(origin, *tile, length) && in_cone(origin, target, *tile))
+                .collect()
+        }
+    };
+
+    tiles.retain(|tile| *tile != origin && map.in_bounds(*tile));
+    // Nearest first, in a fixed order whatever order the tiles were found in
+    tiles.sort_by_key(|tile| (tile.distance_squared(&origin), tile.y(), tile.x()));
+    AttackArea { tiles, landing: origin }
+}
+
+/// Rush along a line toward the target, striking the first actor in the way
+fn charge_area(
+    origin: Position,
+    target: Position,
+    length: u32,
+    map: &Map,
+    attacker: Entity,
+    movement_type: MovementType,
+) -> AttackArea {
+    let (dx, dy) = (target.x() - origin.x(), target.y() - origin.y());
+    let reach = dx.abs().max(dy.abs());
+    let mut area = AttackArea { tiles: Vec::new(), landing: origin };
+    if reach == 0 {
+        return area;
+    }
+
+    // Aim through the target, out to the full length of the charge
+    let length = length as i32;
+    let end = (
+        origin.x() + (dx as f32 * length as f32 / reach as f32).round() as i32,
+        origin.y() + (dy as f32 * length as f32 / reach as f32).round() as i32,
+    );
+
+    for tile in Line::new((origin.x(), origin.y()), end).positions().skip(1).map(Position::from) {
+        if map.get_actor(tile).is_some_and(|actor| actor != attacker) {
+            area.tiles.push(tile);
+            break;
+        }
+        if !map.is_passable(tile, movement_type) {
+            break;
+        }
+        area.landing = tile;
+    }
+
+    area
+}
+
+/// Whether `tile` lies within `reach` tiles of `origin`, give or take half a tile so the edge of
+/// the area isn't jagged
+fn within_reach(origin: Position, tile: Position, reach: u32) -> bool {
+    let reach = reach as i32;
+    tile.distance_squared(&origin) <= reach * reach + reach
+}
+
+/// Whether `tile` lies within the cone from `origin` toward `target`
+fn in_cone(origin: Position, target: Position, tile: Position) -> bool {
+    let aim =
+        Vec2::new((target.x() - origin.x()) as f32, (target.y() - origin.y()) as f32).normalize_or_zero();
+    let offset =
+        Vec2::new((tile.x() - origin.x()) as f32, (tile.y() - origin.y()) as f32).normalize_or_zero();
+    aim.dot(offset) >= BREATH_SPREAD
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::world::components::TerrainType;
+
+    fn open_map() -> Map { Map::new_with_terrain((20, 20), TerrainType::FLOOR) }
+
+    #[test]
+    fn test_swipe_covers_every_neighbour() {
+        let origin = Position::new(5, 5);
+        let area = attack_area(
+            AttackShape::Swipe,
+            origin,
+            Position::new(6, 5),
+            &open_map(),
+            Entity::PLACEHOLDER,
+            MovementType::Walking,
+        );
+
+        assert_eq!(area.tiles.len(), 8);
+        assert_eq!(area.landing, origin);
+    }
+
+    #[test]
+    fn test_charge_stops_at_walls() {
+        let mut map = open_map();
+        map.set_terrain(Position::new(8, 5), TerrainType::WALL);
+
+        let area = attack_area(
+            AttackShape::Charge { length: 6 },
+            Position::new(5, 5),
+            Position::new(6, 5),
+            &map,
+            Entity::PLACEHOLDER,
+            MovementType::Walking,
+        );
+
+        assert!(area.tiles.is_empty());
+        assert_eq!(area.landing, Position::new(7, 5));
+    }
+
+    #[test]
+    fn test_slam_reaches_a_round_area() {
+        let origin = Position::new(5, 5);
+        let area = attack_area(
+            AttackShape::Slam { radius: 2 },
+            origin,
+            Position::new(6, 5),
+            &open_map(),
+            Entity::PLACEHOLDER,
+            MovementType::Walking,
+        );
+
+        assert!(area.tiles.contains(&Position::new(7, 5)));
+        assert!(area.tiles.contains(&Position::new(6, 6)));
+        assert!(!area.tiles.contains(&Position::new(7, 7)));
+        assert!(!area.tiles.contains(&origin));
+    }
+
+    #[test]
+    fn test_breath_fans_out_toward_the_target() {
+        let origin = Position::new(5, 5);
+        let area = attack_area(
+            AttackShape::Breath { length: 4 },
+            origin,
+            Position::new(9, 5),
+            &open_map(),
+            Entity::PLACEHOLDER,
+            MovementType::Walking,
+        );
+
+        assert!(area.tiles.contains(&Position::new(6, 5)));
+        assert!(area.tiles.contains(&Position::new(8, 6)));
+        assert!(!area.tiles.contains(&Position::new(4, 5)));
+        assert!(!area.tiles.contains(&Position::new(5, 7)));
+    }
+}
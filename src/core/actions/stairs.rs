@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::{
+        components::Position,
+        events::DescendEvent,
+        resources::CurrentMap,
+        types::{ActionType, GameAction, GameError},
+    },
+    gameplay::world::components::TerrainType,
+};
+
+/// Take the stairs down to the next level
+#[derive(Clone, Debug)]
+pub struct DescendAction {
+    entity: Entity,
+}
+
+impl DescendAction {
+    pub fn new(entity: Entity) -> Self { Self { entity } }
+}
+
+impl GameAction for DescendAction {
+    fn action_type(&self) -> ActionType { ActionType::Descend }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let position =
+            world.get::<Position>(self.entity).copied().ok_or(GameError::EntityNotFound(self.entity))?;
+
+        if world.resource::<CurrentMap>().get_terrain(position) != Some(TerrainType::STAIRS_DOWN) {
+            log::info!("There are no stairs down here.");
+            return Err(GameError::InvalidTarget);
+        }
+
+        // The next level is built once the turn is over
+        world.send_event(DescendEvent { entity: self.entity });
+        log::info!("You take the stairs down.");
+
+        Ok(self.duration())
+    }
+}
@@ -1,12 +1,9 @@
 use bevy::{ecs::system::SystemState, prelude::*};
 
-use crate::{
-    core::{
-        components::Position,
-        resources::CurrentMap,
-        types::{ActionType, GameAction, GameError},
-    },
-    gameplay::world::components::TerrainType,
+use crate::core::{
+    components::{Movement, Position},
+    resources::CurrentMap,
+    types::{ActionType, GameAction, GameError},
 };
 
 #[derive(Clone, Debug)]
@@ -23,36 +20,44 @@ impl GameAction for TeleportAction {
     fn action_type(&self) -> ActionType { ActionType::Teleport(self.target_position) }
 
     fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
-        let mut state: SystemState<(ResMut<CurrentMap>, Query<&mut Position>)> = SystemState::new(world);
-        let (current_map, mut q_position) = state.get_mut(world);
+        let mut state: SystemState<(Res<CurrentMap>, Query<(&Position, Option<&Movement>)>)> =
+            SystemState::new(world);
+        let (current_map, q_position) = state.get(world);
 
         // Get the entity's current position
-        if let Ok(mut current_pos) = q_position.get_mut(self.entity) {
-            let Some(terrain_type) = current_map.get_terrain(self.target_position) else {
-                log::error!("Failed to get terrain type for target position: {:?}", self.target_position);
-                return Err(GameError::MissingComponent { entity: self.entity, component: "TerrainType" });
-            };
-
-            match terrain_type {
-                TerrainType::Wall => {
-                    log::error!("Target position is blocked by wall");
-                    return Err(GameError::MovementBlocked {
-                        from: *current_pos,
-                        to: self.target_position,
-                        reason: "Target position blocked by wall".to_string(),
-                    });
-                }
-                _ => {
-                    *current_pos = self.target_position;
-                    log::info!("Entity {} teleported to {:?}", self.entity, self.target_position);
-                }
-            }
-        } else {
+        let Ok((current_pos, movement_type)) =
+            q_position.get(self.entity).map(|(pos, movement)| (*pos, Movement::type_of(movement)))
+        else {
             return Err(GameError::EntityNotFound(self.entity));
+        };
+
+        let Some(terrain_type) = current_map.get_terrain(self.target_position) else {
+            log::error!("Failed to get terrain type for target position: {:?}", self.target_position);
+            return Err(GameError::MissingComponent { entity: self.entity, component: "TerrainType" });
+        };
+
+        // Landing is only allowed where this mover could stand (no walkers in chasms or deep water)
+        if !current_map.is_passable(self.target_position, movement_type) {
+            log::debug!("{} in the way of {:?} teleport", terrain_type.description(), movement_type);
+            return Err(GameError::MovementBlocked {
+                from: current_pos,
+                to: self.target_position,
+                reason: format!("{} in the way", terrain_type.description()),
+            });
         }
 
-        // Apply the system state changes
-        state.apply(world);
+        if current_map.get_actor(self.target_position).is_some_and(|occupant| occupant != self.entity) {
+            return Err(GameError::MovementBlocked {
+                from: current_pos,
+                to: self.target_position,
+                reason: "Target position occupied".to_string(),
+            });
+        }
+
+        // Re-inserting Position runs its hooks, which move the actor on the map
+        world.entity_mut(self.entity).insert(self.target_position);
+        log::info!("Entity {} teleported to {:?}", self.entity, self.target_position);
+
         Ok(self.duration())
     }
 }
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+use crate::core::{
+    components::{
+        Equipment, Fuel, Inventory, InventoryItem, LightRadius, Position, TORCH, TORCH_RANGE, light::Light,
+    },
+    systems::combat::floor_item,
+    types::{ActionType, GameAction, GameError},
+};
+
+/// Light a torch from an entity's inventory and take it in hand
+pub fn light_torch(entity: &mut EntityWorldMut) -> Result<(), GameError> {
+    if entity.contains::<Fuel>() {
+        log::info!("You are already holding a lit torch.");
+        return Err(GameError::ActionBlocked);
+    }
+
+    let id = entity.id();
+    let mut inventory = entity
+        .get_mut::<Inventory>()
+        .ok_or(GameError::MissingComponent { entity: id, component: std::any::type_name::<Inventory>() })?;
+    let Some(torch) =
+        inventory.find_item(TORCH).and_then(|slot| inventory.remove_item(slot, 1).ok().flatten())
+    else {
+        log::info!("You have no torches left to light.");
+        return Err(GameError::InsufficientResources);
+    };
+
+    let range = entity.get::<LightRadius>().map_or(TORCH_RANGE, |radius| radius.0);
+    hold_torch(entity, torch, Fuel::torch(range));
+    Ok(())
+}
+
+/// Take a burning torch in hand, with the fuel it has left
+pub fn hold_torch(entity: &mut EntityWorldMut, torch: InventoryItem, fuel: Fuel) {
+    match entity.get_mut::<Equipment>() {
+        Some(mut equipment) => equipment.equip(torch),
+        None => {
+            entity.insert(Equipment { items: vec![torch] });
+        }
+    }
+    entity.insert((Light::torch(fuel.range()), fuel));
+}
+
+/// Light a fresh torch from the inventory
+#[derive(Clone, Debug)]
+pub struct LightTorchAction {
+    entity: Entity,
+}
+
+impl LightTorchAction {
+    pub fn new(entity: Entity) -> Self { Self { entity } }
+}
+
+impl GameAction for LightTorchAction {
+    fn action_type(&self) -> ActionType { ActionType::LightTorch }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let mut entity =
+            world.get_entity_mut(self.entity).map_err(|_| GameError::EntityNotFound(self.entity))?;
+        light_torch(&mut entity)?;
+        log::info!("You light a torch.");
+
+        Ok(self.duration())
+    }
+}
+
+/// Set the torch in hand down, still burning, to light up the room around it
+#[derive(Clone, Debug)]
+pub struct DropTorchAction {
+    entity: Entity,
+}
+
+impl DropTorchAction {
+    pub fn new(entity: Entity) -> Self { Self { entity } }
+}
+
+impl GameAction for DropTorchAction {
+    fn action_type(&self) -> ActionType { ActionType::DropTorch }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let position =
+            world.get::<Position>(self.entity).copied().ok_or(GameError::EntityNotFound(self.entity))?;
+        let Some(fuel) = world.get::<Fuel>(self.entity).copied() else {
+            log::info!("You aren't holding a lit torch.");
+            return Err(GameError::NoValidTargets);
+        };
+
+        let mut entity = world.entity_mut(self.entity);
+        entity.remove::<(Light, Fuel)>();
+        let torch = entity
+            .get_mut::<Equipment>()
+            .and_then(|mut equipment| equipment.unequip(TORCH))
+            .unwrap_or_else(|| InventoryItem::basic(TORCH, 1));
+
+        world.spawn((floor_item(torch, position), Light::torch(fuel.range()), fuel));
+        log::info!("You set the burning torch down.");
+
+        Ok(self.duration())
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::{
+        components::{Inventory, Satiety},
+        types::{ActionType, GameAction, GameError},
+    },
+    gameplay::player::hunger::HungerRules,
+};
+
+/// Item id of the rations that stave off hunger
+pub const RATIONS: &str = "rations";
+
+/// Use up one of the items in an inventory slot
+#[derive(Clone, Debug)]
+pub struct UseItemAction {
+    entity: Entity,
+    slot: usize,
+}
+
+impl UseItemAction {
+    pub fn new(entity: Entity, slot: usize) -> Self { Self { entity, slot } }
+}
+
+impl GameAction for UseItemAction {
+    fn action_type(&self) -> ActionType { ActionType::UseItem { slot: self.slot } }
+
+    fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
+        let inventory = world.get::<Inventory>(self.entity).ok_or(GameError::MissingComponent {
+            entity: self.entity,
+            component: std::any::type_name::<Inventory>(),
+        })?;
+        let item = inventory.get_item(self.slot).cloned().ok_or(GameError::InsufficientResources)?;
+
+        match item.item_id.as_str() {
+            RATIONS => {
+                let ration_satiety = world.resource::<HungerRules>().ration_satiety();
+                let mut satiety =
+                    world.get_mut::<Satiety>(self.entity).ok_or(GameError::MissingComponent {
+                        entity: self.entity,
+                        component: std::any::type_name::<Satiety>(),
+                    })?;
+                if satiety.current >= satiety.max {
+                    log::info!("You are too full to eat.");
+                    return Err(GameError::ActionBlocked);
+                }
+                satiety.eat(ration_satiety);
+                log::info!("You eat one of your {}.", item.name);
+            }
+            _ => {
+                log::info!("You can't use the {}.", item.name);
+                return Err(GameError::ActionBlocked);
+            }
+        }
+
+        if let Some(mut inventory) = world.get_mut::<Inventory>(self.entity) {
+            let _ = inventory.remove_item(self.slot, 1);
+        }
+
+        Ok(self.duration())
+    }
+}
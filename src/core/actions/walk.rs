@@ -1,14 +1,16 @@
 use bevy::{ecs::system::SystemState, prelude::*};
 use brtk::prelude::Direction;
+use echos_assets::entities::MovementType;
 
 use crate::{
     core::{
         actions::AttackAction,
-        components::Position,
+        components::{Encumbrance, Faction, Movement, Position},
+        events::{NoiseEvent, NoiseKind},
         resources::CurrentMap,
         types::{ActionType, GameAction, GameError},
     },
-    gameplay::world::components::TerrainType,
+    gameplay::factions::FactionRegistry,
 };
 
 #[derive(Clone, Debug)]
@@ -25,14 +27,19 @@ impl GameAction for MoveAction {
     fn action_type(&self) -> ActionType { ActionType::MoveDelta(self.direction) }
 
     fn execute(&mut self, world: &mut World) -> Result<u64, GameError> {
-        let mut state: SystemState<(ResMut<CurrentMap>, Query<&mut Position>)> = SystemState::new(world);
+        let mut state: SystemState<(
+            Res<CurrentMap>,
+            Res<FactionRegistry>,
+            Query<(&Position, Option<&Movement>)>,
+            Query<&Faction>,
+        )> = SystemState::new(world);
 
         // Get references to the data
-        let (mut current_map, mut q_position) = state.get_mut(world);
+        let (current_map, factions, q_position, q_faction) = state.get(world);
 
         // Get the entity's current position
-        let current_pos = match q_position.get(self.entity).copied() {
-            Ok(pos) => pos,
+        let (current_pos, movement_type) = match q_position.get(self.entity) {
+            Ok((pos, movement)) => (*pos, Movement::type_of(movement)),
             Err(_) => return Err(GameError::EntityNotFound(self.entity)),
         };
 
@@ -43,50 +50,62 @@ impl GameAction for MoveAction {
             return Err(GameError::MissingComponent { entity: self.entity, component: "TerrainType" });
         };
 
-        // Check for wall collision first
-        match terrain_type {
-            TerrainType::Wall => {
-                log::error!("Wall in the way");
+        // Check whether this mover can enter the tile at all (terrain or a closed door)
+        let Some(terrain_cost) = current_map.movement_cost(new_pos, movement_type) else {
+            let obstacle = current_map
+                .get_feature_kind(new_pos)
+                .filter(|feature| feature.blocks_movement())
+                .map_or_else(|| terrain_type.description(), |feature| feature.description());
+            log::debug!("{obstacle} in the way of {movement_type:?} movement");
+            return Err(GameError::MovementBlocked {
+                from: current_pos,
+                to: new_pos,
+                reason: format!("{obstacle} in the way"),
+            });
+        };
+
+        // Check if target position is occupied by another entity (bump-to-attack)
+        if let Some(target_entity) = current_map.get_actor(new_pos) {
+            // Allies shuffle around each other rather than trading blows
+            if let (Ok(mover), Ok(occupant)) = (q_faction.get(self.entity), q_faction.get(target_entity))
+                && factions.is_friendly(*mover, *occupant)
+            {
                 return Err(GameError::MovementBlocked {
                     from: current_pos,
                     to: new_pos,
-                    reason: "Wall in the way".to_string(),
+                    reason: "An ally is in the way".to_string(),
                 });
             }
-            _ => {
-                // Check if target position is occupied by another entity (bump-to-attack)
-                if let Some(_target_entity) = current_map.get_actor(new_pos) {
-                    // Release the system state before creating attack action
-                    state.apply(world);
-
-                    // Convert movement to attack
-                    log::info!("Movement into occupied space - converting to attack at {new_pos:?}");
-                    let mut attack_action = AttackAction::new(self.entity, new_pos);
-                    return attack_action.execute(world);
-                }
-
-                // Normal movement - update position
-                if let Ok(mut current_pos_mut) = q_position.get_mut(self.entity) {
-                    // Update the map's actor tracking
-                    if let Err(e) = current_map.move_actor(self.entity, new_pos) {
-                        log::error!("Failed to move actor on map: {e}");
-                        return Err(GameError::MovementBlocked {
-                            from: *current_pos_mut,
-                            to: new_pos,
-                            reason: e,
-                        });
-                    }
-
-                    // Update the entity's position component
-                    *current_pos_mut = new_pos;
-                }
-            }
+
+            // Release the system state before creating attack action
+            state.apply(world);
+
+            // Convert movement to attack
+            log::info!("Movement into occupied space - converting to attack at {new_pos:?}");
+            let mut attack_action = AttackAction::new(self.entity, new_pos);
+            return attack_action.execute(world);
         }
 
-        // Return the system state to update the world
-        state.apply(world);
+        // Too much weight to take a step, though a swing at something close is still possible
+        if world.get::<Encumbrance>(self.entity).is_some_and(|load| !load.can_move()) {
+            log::info!("Entity {} is too overloaded to move", self.entity);
+            return Err(GameError::MovementBlocked {
+                from: current_pos,
+                to: new_pos,
+                reason: "Carrying too much to move".to_string(),
+            });
+        }
+
+        // Normal movement - re-inserting Position runs its hooks, which move the actor on the map
+        world.entity_mut(self.entity).insert(new_pos);
+
+        // Flyers never touch the ground, so only they move silently
+        if movement_type != MovementType::Flying {
+            world.send_event(NoiseEvent::new(self.entity, new_pos, NoiseKind::Footstep));
+        }
 
-        Ok(self.duration())
+        // Difficult terrain takes proportionally longer to cross
+        Ok(self.duration() * terrain_cost as u64)
     }
 }
 
@@ -1,11 +1,11 @@
 use bevy::prelude::*;
 use echos_assets::entities::AIBehaviorType;
-use leafwing_input_manager::prelude::InputMap;
+use leafwing_input_manager::prelude::{ButtonlikeChord, InputMap, ModifierKey};
 
 use crate::{
     core::components::*,
     gameplay::{
-        enemies::components::{AIBehavior, AIComponent},
+        enemies::components::{AIBehavior, AIComponent, AIMemory, AITarget, Grudges, SquadOrders, SquadRole},
         player::{actions::PlayerAction, components::AwaitingInput},
     },
 };
@@ -37,8 +37,11 @@ pub struct PlayerBundle {
     pub player: PlayerTag,
     pub awaiting_input: AwaitingInput,
     pub input_map: InputMap<PlayerAction>,
+    pub name: Name,
     pub position: Position,
     pub description: Description,
+    pub experience: Experience,
+    pub satiety: Satiety,
     // pub actor: ActorBundle,
 }
 
@@ -49,7 +52,10 @@ impl PlayerBundle {
             player: PlayerTag,
             awaiting_input: AwaitingInput,
             input_map: Self::default_input_map(),
+            name: Name::new(name.to_string()),
             description: Description::new(name.to_string()),
+            experience: Experience::default(),
+            satiety: Satiety::default(),
         }
     }
 
@@ -79,7 +85,32 @@ impl PlayerBundle {
             // Wait
             (PlayerAction::Wait, KeyCode::Period),
             (PlayerAction::Wait, KeyCode::Numpad5),
+            // Echolocation
+            (PlayerAction::Ping, KeyCode::KeyE),
+            // Look around (free)
+            (PlayerAction::Look, KeyCode::KeyL),
+            // Search a corpse
+            (PlayerAction::Search, KeyCode::KeyF),
+            // Pick up what's underfoot
+            (PlayerAction::PickUp, KeyCode::KeyG),
+            // Eat a ration
+            (PlayerAction::Eat, KeyCode::KeyR),
+            // Light a fresh torch
+            (PlayerAction::LightTorch, KeyCode::KeyT),
+            // Special attack on the nearest foe
+            (PlayerAction::SpecialAttack, KeyCode::KeyQ),
+            // Open or close the nearest door
+            (PlayerAction::ToggleDoor, KeyCode::KeyO),
+            // Hold Ctrl and pick a direction to dig
+            (PlayerAction::Dig, KeyCode::ControlLeft),
+            (PlayerAction::Dig, KeyCode::ControlRight),
         ])
+        // Shift+G takes it even if it overloads the player
+        .with(PlayerAction::PickUpOverloading, ButtonlikeChord::modified(ModifierKey::Shift, KeyCode::KeyG))
+        // Shift+T sets the lit torch down to light the room
+        .with(PlayerAction::DropTorch, ButtonlikeChord::modified(ModifierKey::Shift, KeyCode::KeyT))
+        // '>' takes the stairs down
+        .with(PlayerAction::Descend, ButtonlikeChord::modified(ModifierKey::Shift, KeyCode::Period))
     }
 }
 
@@ -88,6 +119,13 @@ pub struct EnemyBundle {
     pub ai_tag: AITag,
     pub ai_behavior: AIBehavior,
     pub ai: AIComponent,
+    pub awareness: Awareness,
+    pub memory: AIMemory,
+    pub target: AITarget,
+    pub grudges: Grudges,
+    pub squad_role: SquadRole,
+    pub squad_orders: SquadOrders,
+    pub view_shed: ViewShed,
     pub actor: ActorBundle,
 }
 
@@ -103,7 +141,19 @@ impl EnemyBundle {
         let actor = ActorBundle::new(name, description, position);
         let ai_behavior = Self::create_ai_behavior_for_type(behavior_type);
 
-        Self { ai_tag, ai_behavior, ai, actor }
+        Self {
+            ai_tag,
+            ai_behavior,
+            ai,
+            awareness: Awareness::default(),
+            memory: AIMemory::default(),
+            target: AITarget::default(),
+            grudges: Grudges::default(),
+            squad_role: SquadRole::default(),
+            squad_orders: SquadOrders::default(),
+            view_shed: ViewShed::default(),
+            actor,
+        }
     }
 
     fn create_ai_behavior_for_type(behavior_type: AIBehaviorType) -> AIBehavior {
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use brtk::random::Random;
 use echos_assets::entities::{EntityDefinition, EntityDefinitions};
 
 use crate::{
@@ -6,8 +7,15 @@ use crate::{
         components::Position,
         resources::{CurrentMap, TurnQueue},
     },
-    gameplay::world::spawning::{
-        spawn_ai_from_definition, spawn_player_from_definition, spawn_random_ai_from_definition,
+    gameplay::{
+        player::character::NewCharacter,
+        world::{
+            scaling::MonsterScaling,
+            spawn_table::SpawnTable,
+            spawning::{
+                spawn_ai_from_definition, spawn_player_from_definition, spawn_random_ai_from_definition,
+            },
+        },
     },
 };
 
@@ -21,7 +29,7 @@ pub struct SpawnPlayerCommand {
 #[derive(Component)]
 pub struct SpawnAICommand {
     pub position: Position,
-    pub ai_name: Option<String>, // None for random enemy
+    pub ai_name: Option<String>, // None to roll on the spawn table
 }
 
 /// System to process spawn commands
@@ -29,6 +37,10 @@ pub fn process_spawn_commands(
     mut commands: Commands,
     mut current_map: ResMut<CurrentMap>,
     mut turn_queue: ResMut<TurnQueue>,
+    spawn_table: Res<SpawnTable>,
+    monster_scaling: Res<MonsterScaling>,
+    mut shared_rng: ResMut<Random>,
+    new_character: Res<NewCharacter>,
     entity_definitions: Option<Res<EntityDefinitions>>,
     assets: Option<Res<Assets<EntityDefinition>>>,
     player_commands: Query<(Entity, &SpawnPlayerCommand)>,
@@ -41,6 +53,7 @@ pub fn process_spawn_commands(
                 commands.reborrow(),
                 entity_definitions,
                 assets,
+                &new_character,
                 spawn_cmd.position,
                 &mut current_map,
                 &mut turn_queue,
@@ -64,6 +77,12 @@ pub fn process_spawn_commands(
     }
 
     // Process enemy spawn commands
+    if ai_commands.is_empty() {
+        return;
+    }
+
+    // Roll them on the shared RNG so seeded games replay alike
+    let mut rng = shared_rng.to_fastrand();
     for (entity, spawn_cmd) in ai_commands.iter() {
         if let (Some(entity_definitions), Some(assets)) = (entity_definitions.as_ref(), assets.as_ref()) {
             let spawn_result: Result<Entity, String> = match &spawn_cmd.ai_name {
@@ -73,21 +92,26 @@ pub fn process_spawn_commands(
                         commands.reborrow(),
                         entity_definitions,
                         assets,
+                        &monster_scaling,
                         name,
                         spawn_cmd.position,
                         &mut current_map,
                         &mut turn_queue,
+                        &mut rng,
                     )
                 }
                 None => {
-                    // Spawn random enemy
+                    // Spawn an enemy rolled for the current depth
                     spawn_random_ai_from_definition(
                         commands.reborrow(),
                         entity_definitions,
                         assets,
+                        &spawn_table,
+                        &monster_scaling,
                         spawn_cmd.position,
                         &mut current_map,
                         &mut turn_queue,
+                        &mut rng,
                     )
                 }
             };
@@ -122,7 +146,7 @@ pub trait SpawnEntityCommands {
     /// Spawn a player at the given position
     fn spawn_player(&mut self, position: Position);
 
-    /// Spawn a random enemy at the given position
+    /// Spawn an enemy rolled on the current depth's spawn table at the given position
     fn spawn_random_enemy(&mut self, position: Position);
 
     /// Spawn a specific enemy by name at the given position
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+use super::{StatModifiers, Stats};
+
+/// The values an entity's derived attributes are built from, before its stats are counted.
+///
+/// Max health, carry capacity, speed and view radius are recomputed from these whenever the
+/// entity's [`Stats`] or [`StatModifiers`] change.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct BaseAttributes {
+    pub max_health: i32,
+    pub carry_capacity: f32,
+    pub speed: u32,
+    pub view_radius: u8,
+}
+
+impl BaseAttributes {
+    /// Work out the derived attributes for `stats`, with any temporary modifiers applied
+    pub fn derive(&self, stats: &Stats, modifiers: Option<&StatModifiers>) -> DerivedAttributes {
+        let stats = modifiers.map_or_else(|| stats.clone(), |modifiers| stats.with_modifiers(modifiers));
+
+        DerivedAttributes {
+            max_health: (self.max_health + stats.health_bonus()).max(1),
+            carry_capacity: (self.carry_capacity + stats.carry_capacity_bonus()).max(0.0),
+            speed: ((self.speed as f32 * stats.speed_multiplier()).round() as u32).max(1),
+            view_radius: (self.view_radius as i32 + stats.view_radius_bonus()).clamp(1, u8::MAX as i32) as u8,
+        }
+    }
+}
+
+/// Attributes worked out from [`BaseAttributes`] and stats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DerivedAttributes {
+    pub max_health: i32,
+    pub carry_capacity: f32,
+    pub speed: u32,
+    pub view_radius: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::components::StatType;
+
+    const BASE: BaseAttributes =
+        BaseAttributes { max_health: 100, carry_capacity: 150.0, speed: 1000, view_radius: 8 };
+
+    #[test]
+    fn test_average_stats_keep_base_values() {
+        let derived = BASE.derive(&Stats::balanced(10), None);
+
+        assert_eq!(derived.max_health, 100);
+        assert_eq!(derived.carry_capacity, 150.0);
+        assert_eq!(derived.speed, 1000);
+        assert_eq!(derived.view_radius, 8);
+    }
+
+    #[test]
+    fn test_stats_and_modifiers_shift_attributes() {
+        let stats = Stats::new(14, 10, 18, 12, 12, 10);
+        let mut modifiers = StatModifiers::new();
+        modifiers.add_modifier(StatType::Vitality, -4);
+
+        let derived = BASE.derive(&stats, Some(&modifiers));
+        assert_eq!(derived.max_health, 90);
+        assert_eq!(derived.carry_capacity, 170.0);
+        assert_eq!(derived.speed, 1100);
+        assert_eq!(derived.view_radius, 10);
+    }
+}
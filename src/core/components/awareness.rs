@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+
+/// Suspicion at which an AI starts to investigate
+const SUSPICIOUS_THRESHOLD: f32 = 0.3;
+/// Suspicion at which an AI is sure the player is there
+const ALERT_THRESHOLD: f32 = 0.7;
+/// Suspicion gained each turn the AI notices something
+const SUSPICION_GAIN: f32 = 0.4;
+/// Suspicion lost each turn the AI notices nothing
+const SUSPICION_DECAY: f32 = 0.1;
+
+/// How aware an AI is of the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Reflect)]
+pub enum AwarenessLevel {
+    #[default]
+    Unaware,
+    Suspicious,
+    Alert,
+}
+
+/// An AI's awareness of the player, built up turn by turn from what it sees and hears
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct Awareness {
+    pub level: AwarenessLevel,
+    /// 0.0 (nothing noticed) to 1.0 (certain)
+    pub suspicion: f32,
+    /// Game time of the last detection roll, so each turn is only rolled once
+    pub last_checked: Option<u64>,
+}
+
+impl Awareness {
+    /// An AI that already knows exactly where the player is
+    pub fn alert() -> Self { Self { level: AwarenessLevel::Alert, suspicion: 1.0, last_checked: None } }
+
+    pub fn is_unaware(&self) -> bool { self.level == AwarenessLevel::Unaware }
+
+    pub fn is_alert(&self) -> bool { self.level == AwarenessLevel::Alert }
+
+    /// Resolve one turn of detection: `roll` (0..1) under `chance` means the AI noticed something
+    pub fn observe(&mut self, chance: f32, roll: f32) {
+        if chance > 0.0 && roll < chance {
+            self.suspicion += SUSPICION_GAIN;
+        } else {
+            self.suspicion -= SUSPICION_DECAY;
+        }
+        self.suspicion = self.suspicion.clamp(0.0, 1.0);
+
+        self.level = match self.level {
+            // Once alert, an AI only calms down when its suspicion has nearly gone
+            _ if self.suspicion >= ALERT_THRESHOLD => AwarenessLevel::Alert,
+            AwarenessLevel::Alert if self.suspicion >= SUSPICIOUS_THRESHOLD => AwarenessLevel::Alert,
+            _ if self.suspicion >= SUSPICIOUS_THRESHOLD => AwarenessLevel::Suspicious,
+            _ => AwarenessLevel::Unaware,
+        };
+    }
+
+    /// Snap straight to alert (attacked, hurt, ...)
+    pub fn alarm(&mut self) {
+        self.suspicion = 1.0;
+        self.level = AwarenessLevel::Alert;
+    }
+}
+
+/// Chance (0..1) that an observer notices the player this turn.
+///
+/// * `sight` - `None` when the player is out of the observer's FOV, otherwise the distance as a
+///   fraction of the observer's view range (0.0 adjacent, 1.0 at the edge)
+/// * `brightness` - how lit the player's tile is (0.0 dark, 1.0 fully lit)
+/// * `noise_level` - loudness of player-made noise reaching the observer
+/// * `stealth` - the player's stealth bonus from agility
+pub fn detection_chance(sight: Option<f32>, brightness: f32, noise_level: f32, stealth: i32) -> f32 {
+    // Even a dark silhouette can be spotted up close
+    let visual = sight.map_or(0.0, |distance| {
+        (0.2 + 0.8 * brightness.clamp(0.0, 1.0)) * (1.0 - 0.5 * distance.clamp(0.0, 1.0))
+    });
+    let auditory = (noise_level / 8.0).clamp(0.0, 1.0) * 0.6;
+
+    // Either sense can give the player away
+    let combined = 1.0 - (1.0 - visual) * (1.0 - auditory);
+    let stealth_factor = (1.0 - stealth as f32 * 0.04).clamp(0.4, 1.0);
+
+    combined * stealth_factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_light_and_noise_raise_detection() {
+        let dark = detection_chance(Some(0.5), 0.0, 0.0, 0);
+        let lit = detection_chance(Some(0.5), 1.0, 0.0, 0);
+        assert!(lit > dark);
+
+        let unseen = detection_chance(None, 1.0, 0.0, 0);
+        assert_eq!(unseen, 0.0);
+        assert!(detection_chance(None, 0.0, 6.0, 0) > 0.0);
+
+        // Agile players are harder to notice
+        assert!(detection_chance(Some(0.5), 1.0, 0.0, 5) < lit);
+    }
+
+    #[test]
+    fn test_awareness_escalates_and_calms_down() {
+        let mut awareness = Awareness::default();
+
+        awareness.observe(1.0, 0.0);
+        assert_eq!(awareness.level, AwarenessLevel::Suspicious);
+        awareness.observe(1.0, 0.0);
+        assert_eq!(awareness.level, AwarenessLevel::Alert);
+
+        // Alert AIs stay alert while suspicion lingers
+        awareness.observe(0.0, 0.0);
+        awareness.observe(0.0, 0.0);
+        assert!(awareness.is_alert());
+
+        for _ in 0..10 {
+            awareness.observe(0.0, 0.0);
+        }
+        assert!(awareness.is_unaware());
+    }
+}
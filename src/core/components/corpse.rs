@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use brtk::random::Random;
+
+use super::{InventoryItem, LootTable};
+
+/// How long a corpse lies around before it rots away (a hundred standard turns)
+pub const CORPSE_DECAY_TIME: u64 = 100_000;
+
+/// Sprite tint for the remains of the dead
+pub const CORPSE_TINT: Color = Color::srgb(0.45, 0.4, 0.4);
+
+/// The remains of something that died, left lying on its tile.
+///
+/// Corpses don't block the tile and don't take turns. Their loot table is rolled when they are
+/// searched, or spills out when they rot away or get eaten first.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct Corpse {
+    /// Name of what died
+    pub of: String,
+    /// Game time the corpse rots away
+    pub decays_at: u64,
+    /// Whether its loot has been rolled yet
+    pub searched: bool,
+}
+
+impl Corpse {
+    /// Remains of `of`, which died at game time `now`
+    pub fn new(of: impl ToString, now: u64) -> Self {
+        Self { of: of.to_string(), decays_at: now.saturating_add(CORPSE_DECAY_TIME), searched: false }
+    }
+
+    /// The corpse's name, like "Berserker corpse"
+    pub fn name(&self) -> String { format!("{} corpse", self.of) }
+
+    /// Whether the corpse has rotted away by game time `now`
+    pub fn is_rotten(&self, now: u64) -> bool { now >= self.decays_at }
+
+    /// Roll the corpse's loot at `depth`. Only the first search turns anything up.
+    pub fn search(
+        &mut self,
+        loot_table: Option<&LootTable>,
+        depth: u32,
+        random: &mut Random,
+    ) -> Vec<InventoryItem> {
+        if std::mem::replace(&mut self.searched, true) {
+            return Vec::new();
+        }
+
+        loot_table.map(|loot_table| loot_table.roll(depth, random)).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use echos_assets::entities::{LootEntryData, LootTableData};
+
+    use super::*;
+
+    #[test]
+    fn test_corpse_rots_after_decay_time() {
+        let corpse = Corpse::new("Berserker", 5_000);
+
+        assert_eq!(corpse.name(), "Berserker corpse");
+        assert!(!corpse.is_rotten(5_000 + CORPSE_DECAY_TIME - 1));
+        assert!(corpse.is_rotten(5_000 + CORPSE_DECAY_TIME));
+    }
+
+    #[test]
+    fn test_corpse_is_only_searched_once() {
+        let loot_table = LootTable::new(LootTableData::new().with_entry(LootEntryData::new("gold", 1.0)));
+        let mut corpse = Corpse::new("Hostile Guard", 0);
+        let mut random = Random::new(5);
+
+        assert_eq!(corpse.search(Some(&loot_table), 1, &mut random).len(), 1);
+        assert!(corpse.searched);
+        assert!(corpse.search(Some(&loot_table), 1, &mut random).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use echos_assets::progression::ProgressionTable;
+
+use super::{StatType, Stats};
+
+/// Experience earned by whoever kills this entity
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Deref)]
+#[reflect(Component)]
+pub struct ExperienceValue(pub u32);
+
+/// A character's experience, level and stat points waiting to be spent
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct Experience {
+    /// Experience earned over the whole game
+    pub total: u64,
+    pub level: u32,
+    pub unspent_points: u32,
+}
+
+impl Default for Experience {
+    fn default() -> Self { Self { total: 0, level: 1, unspent_points: 0 } }
+}
+
+impl Experience {
+    /// Earn experience, returning how many levels it was worth
+    pub fn gain(&mut self, amount: u32, table: &ProgressionTable) -> u32 {
+        self.total = self.total.saturating_add(amount as u64);
+
+        let levels = table.level_for(self.total).saturating_sub(self.level);
+        self.level += levels;
+        self.unspent_points += levels * table.stat_points_per_level();
+        levels
+    }
+
+    /// Experience earned into the current level and the amount needed to finish it, or `None` at
+    /// the maximum level
+    pub fn progress(&self, table: &ProgressionTable) -> Option<(u64, u64)> {
+        (self.level < table.max_level()).then(|| {
+            (self.total.saturating_sub(table.threshold(self.level)), table.experience_to_advance(self.level))
+        })
+    }
+
+    /// Spend a stat point on `stat`, returning whether there was one to spend.
+    ///
+    /// Derived attributes like max health catch up with the new stats on their own.
+    pub fn spend_point(&mut self, stat: StatType, stats: &mut Stats) -> bool {
+        if self.unspent_points == 0 {
+            return false;
+        }
+        self.unspent_points -= 1;
+        stats.modify_stat(stat, 1);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use echos_assets::scaling::ScalingCurve;
+
+    use super::*;
+
+    #[test]
+    fn test_gain_levels_up_and_hands_out_points() {
+        let table = ProgressionTable::new(100, ScalingCurve::Linear(0.5)).with_stat_points_per_level(2);
+        let mut experience = Experience::default();
+
+        assert_eq!(experience.gain(60, &table), 0);
+        assert_eq!(experience.progress(&table), Some((60, 100)));
+
+        assert_eq!(experience.gain(200, &table), 2);
+        assert_eq!((experience.level, experience.unspent_points), (3, 4));
+        assert_eq!(experience.progress(&table), Some((10, 200)));
+    }
+
+    #[test]
+    fn test_spend_point_needs_a_point() {
+        let mut experience = Experience { unspent_points: 1, ..Default::default() };
+        let mut stats = Stats::balanced(10);
+
+        assert!(experience.spend_point(StatType::Vitality, &mut stats));
+        assert_eq!(stats.vitality, 11);
+
+        assert!(!experience.spend_point(StatType::Strength, &mut stats));
+        assert_eq!(stats.strength, 10);
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+/// Which side an actor is on.
+///
+/// Ids index the [`FactionRegistry`](crate::gameplay::factions::FactionRegistry) resource, which
+/// is filled from the faction table asset and answers how factions regard each other. The built-in
+/// factions keep fixed ids so they can be used as fallbacks.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub struct Faction(pub u16);
+
+impl Faction {
+    pub const PLAYER: Self = Self(0);
+    pub const MONSTERS: Self = Self(1);
+    pub const CRITTERS: Self = Self(2);
+    pub const WANDERERS: Self = Self(3);
+
+    /// Faction of an actor, falling back to the player or monster faction when it has none
+    pub fn of(faction: Option<&Faction>, is_player: bool) -> Self {
+        faction.copied().unwrap_or(if is_player { Self::PLAYER } else { Self::MONSTERS })
+    }
+}
@@ -60,6 +60,19 @@ impl Health {
         self.current = self.current.min(self.max);
     }
 
+    /// Set max health, scaling current health so the entity stays just as hurt.
+    /// The living never drop to zero from this.
+    pub fn set_max_proportional(&mut self, max_health: i32) {
+        let max_health = max_health.max(1);
+        if max_health == self.max {
+            return;
+        }
+
+        let current = (self.percentage() * max_health as f32).round() as i32;
+        self.current = if self.is_alive() { current.clamp(1, max_health) } else { 0 };
+        self.max = max_health;
+    }
+
     /// Increase max health and optionally heal to the new max
     pub fn increase_max(&mut self, increase: i32, heal_to_new_max: bool) {
         self.max += increase;
@@ -190,4 +203,15 @@ mod tests {
         let health = Health::new_with_current(100, 100);
         assert_eq!(health.percentage(), 1.0);
     }
+
+    #[test]
+    fn test_set_max_proportional() {
+        let mut health = Health::new_with_current(40, 80);
+        health.set_max_proportional(100);
+        assert_eq!((health.current, health.max), (50, 100));
+
+        let mut health = Health::new_with_current(1, 100);
+        health.set_max_proportional(20);
+        assert_eq!((health.current, health.max), (1, 20));
+    }
 }
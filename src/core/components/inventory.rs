@@ -32,6 +32,11 @@ impl InventoryItem {
         Self { item_id, name, quantity: quantity.min(max_stack), max_stack, weight, description }
     }
 
+    /// A plain stack known only by its id, until items have definitions of their own
+    pub fn basic(item_id: &str, quantity: u32) -> Self {
+        Self::new(item_id.to_string(), item_id.to_string(), quantity, 99, 1.0, format!("A {item_id}"))
+    }
+
     /// Get the total weight of this item stack
     pub fn total_weight(&self) -> f32 { self.weight * self.quantity as f32 }
 
@@ -83,6 +88,7 @@ impl InventoryItem {
 /// Inventory component for entities that can carry items
 #[derive(Component, Reflect, Debug, Clone, Serialize, Deserialize)]
 #[reflect(Component)]
+#[require(Encumbrance)]
 pub struct Inventory {
     /// Items stored in the inventory, indexed by slot
     pub items: HashMap<usize, InventoryItem>,
@@ -117,9 +123,15 @@ impl Inventory {
         if self.max_weight <= 0.0 { 0.0 } else { (self.current_weight / self.max_weight).clamp(0.0, 1.0) }
     }
 
-    /// Check if adding an item would exceed weight capacity
-    pub fn would_exceed_weight(&self, item: &InventoryItem) -> bool {
-        self.current_weight + item.total_weight() > self.max_weight
+    /// Total weight borne: the inventory plus everything equipped, which counts against the same
+    /// capacity
+    pub fn load(&self, equipment: Option<&Equipment>) -> f32 {
+        self.current_weight + equipment.map_or(0.0, Equipment::total_weight)
+    }
+
+    /// Check if adding an item would exceed weight capacity, counting what is equipped
+    pub fn would_exceed_weight(&self, item: &InventoryItem, equipment: Option<&Equipment>) -> bool {
+        self.load(equipment) + item.total_weight() > self.max_weight
     }
 
     /// Find the first empty slot
@@ -137,14 +149,33 @@ impl Inventory {
         None
     }
 
-    /// Try to add an item to the inventory, returns the amount that couldn't be added
-    pub fn add_item(&mut self, item: InventoryItem) -> Result<u32, InventoryError> {
+    /// Try to add an item to the inventory, returns the amount that couldn't be added.
+    ///
+    /// The weight limit counts the carrier's `equipment` as well.
+    pub fn add_item(
+        &mut self,
+        item: InventoryItem,
+        equipment: Option<&Equipment>,
+    ) -> Result<u32, InventoryError> {
+        self.add_item_with_overload(item, equipment, false)
+    }
+
+    /// Try to add an item to the inventory, returns the amount that couldn't be added.
+    ///
+    /// With `allow_overload` the item goes in even past the weight limit, leaving the carrier
+    /// [`Encumbrance::Overloaded`].
+    pub fn add_item_with_overload(
+        &mut self,
+        item: InventoryItem,
+        equipment: Option<&Equipment>,
+        allow_overload: bool,
+    ) -> Result<u32, InventoryError> {
         if item.quantity == 0 {
             return Ok(0);
         }
 
         // Check weight capacity
-        if self.would_exceed_weight(&item) {
+        if !allow_overload && self.would_exceed_weight(&item, equipment) {
             return Err(InventoryError::ExceedsWeightLimit);
         }
 
@@ -264,6 +295,11 @@ impl Inventory {
         self.items.values().filter(|item| item.item_id == item_id).map(|item| item.quantity).sum()
     }
 
+    /// Find the first slot holding an item type
+    pub fn find_item(&self, item_id: &str) -> Option<usize> {
+        self.items.iter().filter(|(_, item)| item.item_id == item_id).map(|(slot, _)| *slot).min()
+    }
+
     /// Check if the inventory contains at least the specified quantity of an item
     pub fn has_item(&self, item_id: &str, quantity: u32) -> bool { self.count_item(item_id) >= quantity }
 
@@ -292,6 +328,101 @@ impl Default for Inventory {
     }
 }
 
+/// How weighed down an entity is by what it carries and wears
+#[derive(Component, Reflect, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[reflect(Component)]
+pub enum Encumbrance {
+    #[default]
+    Unencumbered,
+    /// Over half the carry capacity
+    Burdened,
+    /// Over three quarters of the carry capacity
+    Stressed,
+    /// Past the carry capacity; too heavy to walk
+    Overloaded,
+}
+
+impl Encumbrance {
+    /// The tier for carrying `weight` with `capacity` to spare
+    pub fn for_load(weight: f32, capacity: f32) -> Self {
+        if capacity <= 0.0 {
+            return if weight > 0.0 { Self::Overloaded } else { Self::Unencumbered };
+        }
+
+        match weight / capacity {
+            load if load > 1.0 => Self::Overloaded,
+            load if load > 0.75 => Self::Stressed,
+            load if load > 0.5 => Self::Burdened,
+            _ => Self::Unencumbered,
+        }
+    }
+
+    /// Multiplier on how long every action takes
+    pub fn duration_multiplier(&self) -> f32 {
+        match self {
+            Self::Unencumbered => 1.0,
+            Self::Burdened => 1.25,
+            Self::Stressed => 1.5,
+            Self::Overloaded => 2.0,
+        }
+    }
+
+    /// Stretch an action's duration by the multiplier
+    pub fn slow(&self, duration: u64) -> u64 { (duration as f32 * self.duration_multiplier()).round() as u64 }
+
+    /// Percentage points taken off the carrier's evasion
+    pub fn evasion_penalty(&self) -> f32 {
+        match self {
+            Self::Unencumbered => 0.0,
+            Self::Burdened => 5.0,
+            Self::Stressed => 10.0,
+            Self::Overloaded => 20.0,
+        }
+    }
+
+    /// Whether the carrier can still walk
+    pub fn can_move(&self) -> bool { *self != Self::Overloaded }
+
+    /// Name for the HUD and messages (empty when unencumbered)
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Unencumbered => "",
+            Self::Burdened => "Burdened",
+            Self::Stressed => "Stressed",
+            Self::Overloaded => "Overloaded",
+        }
+    }
+}
+
+/// Items an entity has worn or wielded. They're kept apart from its [`Inventory`] slots.
+#[derive(Component, Reflect, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct Equipment {
+    pub items: Vec<InventoryItem>,
+}
+
+impl Equipment {
+    /// Equipment made up of a plain item for each id
+    pub fn basic<'a>(item_ids: impl IntoIterator<Item = &'a String>) -> Self {
+        Self { items: item_ids.into_iter().map(|item_id| InventoryItem::basic(item_id, 1)).collect() }
+    }
+
+    /// Wear or wield an item
+    pub fn equip(&mut self, item: InventoryItem) { self.items.push(item); }
+
+    /// Take off the first item with this id, if it's equipped
+    pub fn unequip(&mut self, item_id: &str) -> Option<InventoryItem> {
+        let index = self.items.iter().position(|item| item.item_id == item_id)?;
+        Some(self.items.remove(index))
+    }
+
+    /// Check whether an item with this id is equipped
+    pub fn is_equipped(&self, item_id: &str) -> bool { self.items.iter().any(|item| item.item_id == item_id) }
+
+    /// Get the total weight of everything equipped
+    pub fn total_weight(&self) -> f32 { self.items.iter().map(InventoryItem::total_weight).sum() }
+}
+
 /// Errors that can occur during inventory operations
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum InventoryError {
@@ -351,7 +482,7 @@ mod tests {
         let mut inventory = Inventory::new(5, 50.0);
         let item = create_test_item("sword", 1);
 
-        let remaining = inventory.add_item(item).unwrap();
+        let remaining = inventory.add_item(item, None).unwrap();
         assert_eq!(remaining, 0);
         assert_eq!(inventory.used_slots(), 1);
         assert_eq!(inventory.current_weight, 1.0);
@@ -363,8 +494,8 @@ mod tests {
         let item1 = create_test_item("potion", 5);
         let item2 = create_test_item("potion", 3);
 
-        inventory.add_item(item1).unwrap();
-        inventory.add_item(item2).unwrap();
+        inventory.add_item(item1, None).unwrap();
+        inventory.add_item(item2, None).unwrap();
 
         assert_eq!(inventory.used_slots(), 1);
         assert_eq!(inventory.count_item("potion"), 8);
@@ -382,7 +513,7 @@ mod tests {
             "Very heavy".to_string(),
         );
 
-        let result = inventory.add_item(heavy_item);
+        let result = inventory.add_item(heavy_item, None);
         assert!(matches!(result, Err(InventoryError::ExceedsWeightLimit)));
     }
 
@@ -391,7 +522,7 @@ mod tests {
         let mut inventory = Inventory::new(5, 50.0);
         let item = create_test_item("sword", 1);
 
-        inventory.add_item(item).unwrap();
+        inventory.add_item(item, None).unwrap();
         let removed = inventory.remove_item(0, 1).unwrap();
 
         assert!(removed.is_some());
@@ -404,7 +535,7 @@ mod tests {
         let mut inventory = Inventory::new(5, 50.0);
         let item = create_test_item("sword", 1);
 
-        inventory.add_item(item).unwrap();
+        inventory.add_item(item, None).unwrap();
         inventory.move_item(0, 2).unwrap();
 
         assert!(inventory.get_item(0).is_none());
@@ -442,8 +573,8 @@ mod tests {
         let item1 = create_test_item("sword", 1);
         let item2 = create_test_item("potion", 5);
 
-        inventory.add_item(item1).unwrap();
-        inventory.add_item(item2).unwrap();
+        inventory.add_item(item1, None).unwrap();
+        inventory.add_item(item2, None).unwrap();
 
         assert!(inventory.has_item("sword", 1));
         assert!(inventory.has_item("potion", 5));
@@ -452,4 +583,46 @@ mod tests {
         assert_eq!(inventory.count_item("potion"), 5);
         assert_eq!(inventory.count_item("nonexistent"), 0);
     }
+
+    #[test]
+    fn test_overloading_only_when_allowed() {
+        let mut inventory = Inventory::new(5, 4.0);
+
+        let result = inventory.add_item(create_test_item("rock", 6), None);
+        assert!(matches!(result, Err(InventoryError::ExceedsWeightLimit)));
+
+        assert_eq!(inventory.add_item_with_overload(create_test_item("rock", 6), None, true), Ok(0));
+        assert_eq!(inventory.current_weight, 6.0);
+        assert_eq!(
+            Encumbrance::for_load(inventory.current_weight, inventory.max_weight),
+            Encumbrance::Overloaded
+        );
+    }
+
+    #[test]
+    fn test_equipped_weight_counts_against_the_limit() {
+        let mut inventory = Inventory::new(5, 4.0);
+        let equipment = Equipment { items: vec![create_test_item("torch", 3)] };
+
+        let result = inventory.add_item(create_test_item("rock", 2), Some(&equipment));
+        assert!(matches!(result, Err(InventoryError::ExceedsWeightLimit)));
+
+        assert_eq!(inventory.add_item(create_test_item("rock", 1), Some(&equipment)), Ok(0));
+        assert_eq!(inventory.load(Some(&equipment)), 4.0);
+        assert_ne!(
+            Encumbrance::for_load(inventory.load(Some(&equipment)), inventory.max_weight),
+            Encumbrance::Overloaded
+        );
+    }
+
+    #[test]
+    fn test_encumbrance_tiers() {
+        assert_eq!(Encumbrance::for_load(50.0, 100.0), Encumbrance::Unencumbered);
+        assert_eq!(Encumbrance::for_load(60.0, 100.0), Encumbrance::Burdened);
+        assert_eq!(Encumbrance::for_load(80.0, 100.0), Encumbrance::Stressed);
+        assert_eq!(Encumbrance::for_load(100.5, 100.0), Encumbrance::Overloaded);
+
+        assert_eq!(Encumbrance::Stressed.slow(1000), 1500);
+        assert!(!Encumbrance::Overloaded.can_move());
+    }
 }
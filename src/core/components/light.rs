@@ -16,3 +16,93 @@ pub struct Light {
 impl Default for Light {
     fn default() -> Self { Self { range: 8, color: Color::WHITE, falloff: 1.5 } }
 }
+
+impl Light {
+    /// The warm light of a burning torch
+    pub fn torch(range: u32) -> Self { Self { range, color: Color::srgb(1.0, 0.9, 0.7), falloff: 1.5 } }
+}
+
+/// Item id of the torches that light the way
+pub const TORCH: &str = "torch";
+/// Standard turns a fresh torch burns for
+pub const TORCH_FUEL: u32 = 400;
+/// How far a torch reaches when its bearer has no radius of their own
+pub const TORCH_RANGE: u32 = 8;
+/// Share of its fuel a light has left when it starts to dim
+const LOW_FUEL_FRACTION: f32 = 0.25;
+
+/// How far a torch carried by this entity reaches
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect, Deref)]
+#[reflect(Component)]
+pub struct LightRadius(pub u32);
+
+impl Default for LightRadius {
+    fn default() -> Self { Self(TORCH_RANGE) }
+}
+
+/// Fuel left in a burning light, in standard turns. The [`Light`] it feeds dims once the fuel
+/// runs low and goes out with it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Fuel {
+    pub turns_left: u32,
+    pub capacity: u32,
+    /// Range of the light while there's plenty of fuel
+    pub full_range: u32,
+}
+
+impl Fuel {
+    /// A freshly lit torch
+    pub fn torch(full_range: u32) -> Self {
+        Self { turns_left: TORCH_FUEL, capacity: TORCH_FUEL, full_range }
+    }
+
+    /// Burn through `turns` turns of fuel
+    pub fn burn(&mut self, turns: u32) { self.turns_left = self.turns_left.saturating_sub(turns); }
+
+    /// Whether the fuel is all gone
+    pub fn is_spent(&self) -> bool { self.turns_left == 0 }
+
+    /// Whether the light has started to dim
+    pub fn is_low(&self) -> bool { (self.turns_left as f32) < self.low_threshold() }
+
+    /// How far the light reaches with the fuel left: its full range until the fuel runs low, then
+    /// shrinking with it, and nothing once it's spent
+    pub fn range(&self) -> u32 {
+        if self.is_spent() {
+            return 0;
+        }
+        if !self.is_low() {
+            return self.full_range;
+        }
+
+        ((self.full_range as f32 * self.turns_left as f32 / self.low_threshold()).ceil() as u32).max(1)
+    }
+
+    fn low_threshold(&self) -> f32 { self.capacity as f32 * LOW_FUEL_FRACTION }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_shrinks_as_fuel_runs_low() {
+        let mut fuel = Fuel { turns_left: 100, capacity: 100, full_range: 8 };
+        assert_eq!(fuel.range(), 8);
+
+        fuel.burn(75);
+        assert_eq!(fuel.range(), 8);
+
+        fuel.burn(13);
+        assert!(fuel.is_low());
+        assert_eq!(fuel.range(), 4);
+
+        fuel.burn(11);
+        assert_eq!(fuel.range(), 1);
+
+        fuel.burn(5);
+        assert!(fuel.is_spent());
+        assert_eq!(fuel.range(), 0);
+    }
+}
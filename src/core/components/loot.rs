@@ -0,0 +1,125 @@
+use bevy::{
+    ecs::{component::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use brtk::random::Random;
+use echos_assets::entities::LootTableData;
+
+use crate::core::{
+    components::{InventoryItem, Position},
+    resources::CurrentMap,
+};
+
+/// What an entity drops when it dies, on top of its inventory
+#[derive(Component, Reflect, Debug, Clone, Deref)]
+#[reflect(Component)]
+pub struct LootTable(pub LootTableData);
+
+impl LootTable {
+    pub fn new(data: LootTableData) -> Self { Self(data) }
+
+    /// Roll the drops for a death at `depth`, one stack per kind of item
+    pub fn roll(&self, depth: u32, random: &mut Random) -> Vec<InventoryItem> {
+        let mut drops: Vec<InventoryItem> = Vec::new();
+
+        let total =
+            self.nothing_weight() + self.entries.iter().map(|entry| entry.weight_at(depth)).sum::<f32>();
+        if total <= 0.0 {
+            return drops;
+        }
+
+        for _ in 0..random.roll(self.rolls()).max(0) {
+            let mut pick = random.f32(0.0..total) - self.nothing_weight();
+            if pick < 0.0 {
+                continue;
+            }
+
+            let Some(entry) = self.entries.iter().find(|entry| {
+                let weight = entry.weight_at(depth);
+                pick -= weight;
+                pick < 0.0 && weight > 0.0
+            }) else {
+                continue;
+            };
+
+            let quantity = random.roll(entry.quantity()).max(1) as u32;
+            match drops.iter_mut().find(|drop| drop.item_id == entry.item) {
+                Some(drop) => drop.quantity += quantity,
+                None => drops.push(InventoryItem::basic(&entry.item, quantity)),
+            }
+        }
+
+        drops
+    }
+}
+
+/// An item stack lying on the floor.
+///
+/// Like [`OccupiesTile`](super::OccupiesTile) for actors, it keeps the `CurrentMap` item index in
+/// step with the entity's [`Position`].
+#[derive(Component, Reflect, Debug, Clone, Deref)]
+#[reflect(Component)]
+#[require(Position)]
+#[component(on_insert = sync_item_on_map, on_remove = clear_item_from_map)]
+pub struct FloorItem(pub InventoryItem);
+
+/// Hook: place an item on the map at its current position
+fn sync_item_on_map(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let Some(position) = world.get::<Position>(entity).copied() else {
+        return;
+    };
+
+    let Some(mut current_map) = world.get_resource_mut::<CurrentMap>() else {
+        return;
+    };
+
+    if let Err(e) = current_map.place_item(position, entity) {
+        warn!("Failed to place item {entity} at {position:?} on map: {e}");
+    }
+}
+
+/// Hook: drop an item from the map's item index
+fn clear_item_from_map(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if let Some(mut current_map) = world.get_resource_mut::<CurrentMap>() {
+        current_map.remove_item(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use echos_assets::entities::LootEntryData;
+
+    use super::*;
+
+    #[test]
+    fn test_roll_merges_stacks() {
+        let table = LootTable::new(
+            LootTableData::new()
+                .with_rolls("4d1")
+                .with_entry(LootEntryData::new("gold", 1.0).with_quantity("2d1")),
+        );
+
+        let drops = table.roll(1, &mut Random::new(11));
+        assert_eq!(drops.len(), 1);
+        assert_eq!((drops[0].item_id.as_str(), drops[0].quantity), ("gold", 8));
+    }
+
+    #[test]
+    fn test_roll_respects_depth() {
+        let table =
+            LootTable::new(LootTableData::new().with_entry(LootEntryData::new("gem", 1.0).with_min_depth(5)));
+        let mut random = Random::new(7);
+
+        assert!(table.roll(4, &mut random).is_empty());
+        assert_eq!(table.roll(5, &mut random)[0].item_id, "gem");
+    }
+
+    #[test]
+    fn test_nothing_weight_can_empty_the_roll() {
+        let table = LootTable::new(
+            LootTableData::new().with_nothing_weight(1.0).with_entry(LootEntryData::new("gold", 0.0)),
+        );
+
+        assert!(table.roll(1, &mut Random::new(3)).is_empty());
+    }
+}
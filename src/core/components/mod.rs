@@ -21,6 +21,39 @@ pub use inventory::*;
 pub mod light;
 pub use light::*;
 
+mod movement;
+pub use movement::*;
+
+mod occupancy;
+pub use occupancy::*;
+
+mod awareness;
+pub use awareness::*;
+
+mod view_shed;
+pub use view_shed::*;
+
+mod faction;
+pub use faction::*;
+
+mod special_attacks;
+pub use special_attacks::*;
+
+mod loot;
+pub use loot::*;
+
+mod corpse;
+pub use corpse::*;
+
+mod experience;
+pub use experience::*;
+
+mod attributes;
+pub use attributes::*;
+
+mod satiety;
+pub use satiety::*;
+
 // Re-export commonly used types for convenience
 pub use bevy::prelude::{Component, Entity};
 
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use echos_assets::entities::MovementType;
+
+/// How an entity moves over terrain (walking, flying, swimming or burrowing)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub struct Movement(pub MovementType);
+
+impl Movement {
+    pub fn new(movement_type: MovementType) -> Self { Self(movement_type) }
+
+    /// Movement type for an optional component, defaulting to walking
+    pub fn type_of(movement: Option<&Movement>) -> MovementType {
+        movement.map(|movement| movement.0).unwrap_or_default()
+    }
+}
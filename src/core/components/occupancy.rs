@@ -0,0 +1,44 @@
+use bevy::{
+    ecs::{component::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+
+use crate::core::{components::Position, resources::CurrentMap};
+
+/// Marks an entity as occupying its map tile.
+///
+/// While an entity carries both `OccupiesTile` and [`Position`], the `CurrentMap` actor index
+/// follows it automatically: inserting or replacing either component places the actor, removing
+/// either (including on despawn) clears it. In-place writes through `&mut Position` do not run
+/// hooks, so actions that move actors re-insert the component instead.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+#[require(Position)]
+#[component(on_insert = sync_actor_on_map, on_remove = clear_actor_from_map)]
+pub struct OccupiesTile;
+
+/// Hook: place (or move) an occupying entity on the map at its current position
+pub(crate) fn sync_actor_on_map(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if world.get::<OccupiesTile>(entity).is_none() {
+        return;
+    }
+
+    let Some(position) = world.get::<Position>(entity).copied() else {
+        return;
+    };
+
+    let Some(mut current_map) = world.get_resource_mut::<CurrentMap>() else {
+        return;
+    };
+
+    if let Err(e) = current_map.place_actor(position, entity) {
+        warn!("Failed to sync actor {entity} to {position:?} on map: {e}");
+    }
+}
+
+/// Hook: drop an entity from the map's actor index
+pub(crate) fn clear_actor_from_map(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if let Some(mut current_map) = world.get_resource_mut::<CurrentMap>() {
+        current_map.remove_actor(entity);
+    }
+}
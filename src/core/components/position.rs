@@ -4,11 +4,16 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::tiles::TilePos;
 use brtk::distance::Distance;
 
+use super::occupancy::{clear_actor_from_map, sync_actor_on_map};
 use crate::core::resources::DistanceSettings;
 
 /// Position component for entities in the game world
+///
+/// Entities that also carry [`OccupiesTile`](super::OccupiesTile) are kept in the map's actor
+/// index by this component's insert/remove hooks.
 #[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Deref, DerefMut)]
 #[reflect(Component)]
+#[component(on_insert = sync_actor_on_map, on_remove = clear_actor_from_map)]
 pub struct Position(pub IVec2);
 
 impl Position {
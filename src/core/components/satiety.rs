@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use echos_assets::survival::{HungerSettings, HungerStage};
+
+use super::{StatModifiers, StatType};
+
+/// How well fed a character is. It drains as game time passes and fills up again with food.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+#[require(StatModifiers)]
+pub struct Satiety {
+    pub current: f32,
+    pub max: f32,
+    pub stage: HungerStage,
+    /// Stat changes the current stage is applying through [`StatModifiers`]
+    pub penalties: Vec<(StatType, i32)>,
+    /// Starvation damage built up but not yet dealt
+    starvation: f32,
+}
+
+impl Default for Satiety {
+    fn default() -> Self { Self::new(HungerSettings::default().max_satiety()) }
+}
+
+impl Satiety {
+    /// A character fed to the brim
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max, stage: HungerStage::Satiated, penalties: Vec::new(), starvation: 0.0 }
+    }
+
+    /// Share of the maximum left, from 0.0 to 1.0
+    pub fn fraction(&self) -> f32 { if self.max > 0.0 { self.current / self.max } else { 0.0 } }
+
+    /// Change the maximum, keeping the character just as full
+    pub fn set_max(&mut self, max: f32) {
+        let fraction = self.fraction();
+        self.max = max.max(1.0);
+        self.current = self.max * fraction;
+    }
+
+    /// Go hungrier by `amount`
+    pub fn drain(&mut self, amount: f32) { self.current = (self.current - amount).max(0.0); }
+
+    /// Eat something worth `amount`, returning how much of it was taken in
+    pub fn eat(&mut self, amount: f32) -> f32 {
+        let eaten = amount.min(self.max - self.current).max(0.0);
+        self.current += eaten;
+        if self.current > 0.0 {
+            self.starvation = 0.0;
+        }
+        eaten
+    }
+
+    /// Build up `damage` from starving, returning the whole points of it to deal now
+    pub fn starve(&mut self, damage: f32) -> i32 {
+        self.starvation += damage;
+        let whole = self.starvation.floor();
+        self.starvation -= whole;
+        whole as i32
+    }
+
+    /// The stage `settings` give for the satiety left
+    pub fn stage_in(&self, settings: &HungerSettings) -> HungerStage {
+        settings.stage_for(self.current).map_or(HungerStage::Satiated, |data| data.stage)
+    }
+
+    /// Move to the stage `settings` give for the satiety left, swapping the old stage's stat
+    /// changes for the new one's. Returns the new stage if it changed.
+    pub fn settle_stage(
+        &mut self,
+        settings: &HungerSettings,
+        modifiers: &mut StatModifiers,
+    ) -> Option<HungerStage> {
+        let stage = self.stage_in(settings);
+        if stage == self.stage {
+            return None;
+        }
+
+        for (stat, amount) in self.penalties.drain(..) {
+            modifiers.add_modifier(stat, -amount);
+        }
+        if let Some(data) = settings.stage_for(self.current) {
+            for (name, amount) in &data.stat_modifiers {
+                match StatType::from_name(name) {
+                    Some(stat) => {
+                        modifiers.add_modifier(stat, *amount);
+                        self.penalties.push((stat, *amount));
+                    }
+                    None => warn!("Hunger stage {:?} changes unknown stat '{name}'", data.stage),
+                }
+            }
+        }
+
+        self.stage = stage;
+        Some(stage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use echos_assets::survival::HungerStageData;
+
+    use super::*;
+
+    fn settings() -> HungerSettings {
+        HungerSettings::default()
+            .with_max_satiety(100)
+            .with_stage(HungerStageData::new(HungerStage::Hungry, 0.5))
+            .with_stage(HungerStageData::new(HungerStage::Weak, 0.2).with_stat_modifier("strength", -3))
+    }
+
+    #[test]
+    fn test_stage_changes_swap_stat_modifiers() {
+        let settings = settings();
+        let mut satiety = Satiety::new(100.0);
+        let mut modifiers = StatModifiers::new();
+
+        satiety.drain(60.0);
+        assert_eq!(satiety.settle_stage(&settings, &mut modifiers), Some(HungerStage::Hungry));
+        assert_eq!(satiety.settle_stage(&settings, &mut modifiers), None);
+
+        satiety.drain(30.0);
+        assert_eq!(satiety.settle_stage(&settings, &mut modifiers), Some(HungerStage::Weak));
+        assert_eq!(modifiers.get_modifier(StatType::Strength), -3);
+
+        satiety.eat(80.0);
+        assert_eq!(satiety.settle_stage(&settings, &mut modifiers), Some(HungerStage::Satiated));
+        assert_eq!(modifiers.get_modifier(StatType::Strength), 0);
+    }
+
+    #[test]
+    fn test_eating_stops_at_max_and_starving_builds_up() {
+        let mut satiety = Satiety::new(100.0);
+        satiety.drain(150.0);
+        assert_eq!(satiety.current, 0.0);
+
+        assert_eq!(satiety.starve(0.6), 0);
+        assert_eq!(satiety.starve(0.6), 1);
+
+        assert_eq!(satiety.eat(130.0), 100.0);
+        assert_eq!(satiety.fraction(), 1.0);
+    }
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use echos_assets::entities::SpecialAttackData;
+
+use crate::core::components::Position;
+
+/// The special attacks an entity knows, each with its own cooldown
+#[derive(Component, Debug, Clone, Default)]
+pub struct SpecialAttacks {
+    attacks: Vec<SpecialAttackData>,
+    /// Turns left before each attack is ready again
+    cooldowns: Vec<u32>,
+    /// The attack and target an AI has lined up for its next turn
+    pub planned: Option<(u8, Position)>,
+}
+
+impl SpecialAttacks {
+    pub fn new(attacks: Vec<SpecialAttackData>) -> Self {
+        let cooldowns = vec![0; attacks.len()];
+        Self { attacks, cooldowns, planned: None }
+    }
+
+    pub fn get(&self, index: u8) -> Option<&SpecialAttackData> { self.attacks.get(index as usize) }
+
+    /// Every attack with its index
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &SpecialAttackData)> {
+        self.attacks.iter().enumerate().map(|(index, attack)| (index as u8, attack))
+    }
+
+    /// Turns left before an attack can be used again
+    pub fn remaining(&self, index: u8) -> u32 { self.cooldowns.get(index as usize).copied().unwrap_or(0) }
+
+    pub fn is_ready(&self, index: u8) -> bool { self.remaining(index) == 0 }
+
+    /// Put an attack on cooldown after using it
+    pub fn start_cooldown(&mut self, index: u8) {
+        if let (Some(attack), Some(cooldown)) =
+            (self.attacks.get(index as usize), self.cooldowns.get_mut(index as usize))
+        {
+            *cooldown = attack.cooldown;
+        }
+    }
+
+    /// Count down every cooldown by one turn, once for each action the owner takes
+    pub fn tick(&mut self) {
+        for cooldown in &mut self.cooldowns {
+            *cooldown = cooldown.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use echos_assets::entities::AttackShape;
+
+    use super::*;
+
+    #[test]
+    fn test_cooldown() {
+        let mut attacks = SpecialAttacks::new(vec![SpecialAttackData::new("Swipe", AttackShape::Swipe, 3)]);
+        assert!(attacks.is_ready(0));
+
+        // Using the attack is itself an action, so it ticks straight away
+        attacks.start_cooldown(0);
+        attacks.tick();
+        assert_eq!(attacks.remaining(0), 2);
+
+        attacks.tick();
+        assert!(!attacks.is_ready(0));
+        attacks.tick();
+        assert!(attacks.is_ready(0));
+    }
+}
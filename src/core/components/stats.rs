@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use echos_assets::entities::StatsData;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -68,9 +69,21 @@ impl Stats {
     /// Calculate evasion bonus from agility
     pub fn evasion_bonus(&self) -> i32 { (self.agility - 10).max(0) }
 
+    /// Calculate stealth bonus from agility
+    pub fn stealth_bonus(&self) -> i32 { (self.agility - 10).max(0) }
+
     /// Calculate health bonus from vitality
     pub fn health_bonus(&self) -> i32 { (self.vitality - 10) * 5 }
 
+    /// Calculate carry capacity bonus from strength
+    pub fn carry_capacity_bonus(&self) -> f32 { (self.strength - 10) as f32 * 5.0 }
+
+    /// Calculate the multiplier on base speed from agility (5% per point, never below half speed)
+    pub fn speed_multiplier(&self) -> f32 { (1.0 + (self.agility - 10) as f32 * 0.05).max(0.5) }
+
+    /// Calculate view radius bonus from intelligence
+    pub fn view_radius_bonus(&self) -> i32 { (self.intelligence - 10) / 4 }
+
     /// Calculate critical hit chance from luck (as percentage)
     pub fn critical_chance(&self) -> f32 { (self.luck as f32 * 0.5).clamp(0.0, 25.0) }
 
@@ -129,6 +142,12 @@ impl Default for Stats {
     }
 }
 
+impl From<&StatsData> for Stats {
+    fn from(data: &StatsData) -> Self {
+        Self::new(data.strength, data.defense, data.intelligence, data.agility, data.vitality, data.luck)
+    }
+}
+
 /// Enumeration of different stat types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
 pub enum StatType {
@@ -165,6 +184,11 @@ impl StatType {
         }
     }
 
+    /// Look a stat up by its name, ignoring case
+    pub fn from_name(name: &str) -> Option<StatType> {
+        Self::all().into_iter().find(|stat| stat.display_name().eq_ignore_ascii_case(name.trim()))
+    }
+
     /// Get a short abbreviation for the stat
     pub fn abbreviation(&self) -> &'static str {
         match self {
@@ -299,5 +323,7 @@ mod tests {
         assert_eq!(StatType::Strength.display_name(), "Strength");
         assert_eq!(StatType::Agility.abbreviation(), "AGI");
         assert_eq!(StatType::all().len(), 6);
+        assert_eq!(StatType::from_name("vitality"), Some(StatType::Vitality));
+        assert_eq!(StatType::from_name("charisma"), None);
     }
 }
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::core::{
+    components::Position,
+    resources::{FovMap, Map},
+};
+
+/// The tiles an AI can currently see, computed from its own [`FieldOfView`](super::FieldOfView).
+///
+/// Shadowcasting is cached: the view is only recomputed when the entity moves, its radius
+/// changes, or the map's terrain has been modified since the last cast.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ViewShed {
+    visible: HashSet<Position>,
+    origin: Option<Position>,
+    radius: u8,
+    terrain_revision: u64,
+}
+
+impl ViewShed {
+    /// Checks if a position was visible from the last computed origin
+    pub fn can_see(&self, position: Position) -> bool { self.visible.contains(&position) }
+
+    /// Where the view was last computed from
+    pub fn origin(&self) -> Option<Position> { self.origin }
+
+    /// Every position in view
+    pub fn visible(&self) -> impl Iterator<Item = &Position> { self.visible.iter() }
+
+    /// Whether the cached view no longer matches the entity or the map
+    pub fn is_stale(&self, origin: Position, radius: u8, terrain_revision: u64) -> bool {
+        self.origin != Some(origin) || self.radius != radius || self.terrain_revision != terrain_revision
+    }
+
+    /// Recasts the view from `origin`
+    pub fn update(&mut self, map: &Map, origin: Position, radius: u8) {
+        let visibility = FovMap::compute_temporary_fov(origin, radius, map);
+
+        self.visible.clear();
+        self.visible.insert(origin);
+        self.visible.extend(visibility.get_visible_set().iter().map(|&(x, y)| Position::new(x, y)));
+
+        self.origin = Some(origin);
+        self.radius = radius;
+        self.terrain_revision = map.terrain_revision();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::world::components::TerrainType;
+
+    #[test]
+    fn test_view_shed_refreshes_only_when_stale() {
+        let mut map = Map::new_with_terrain((10, 10), TerrainType::FLOOR);
+        let origin = Position::new(2, 5);
+
+        let mut view_shed = ViewShed::default();
+        assert!(view_shed.is_stale(origin, 6, map.terrain_revision()));
+
+        view_shed.update(&map, origin, 6);
+        assert!(view_shed.can_see(Position::new(6, 5)));
+        assert!(!view_shed.is_stale(origin, 6, map.terrain_revision()));
+
+        // Walling off the corridor invalidates the cache
+        map.set_terrain(Position::new(4, 5), TerrainType::WALL);
+        assert!(view_shed.is_stale(origin, 6, map.terrain_revision()));
+
+        view_shed.update(&map, origin, 6);
+        assert!(!view_shed.can_see(Position::new(6, 5)));
+    }
+}
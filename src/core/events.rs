@@ -36,6 +36,12 @@ pub struct EntityDeathEvent {
     pub killer: Option<Entity>,
 }
 
+/// Event fired when the player takes the stairs down to the next level
+#[derive(Event, Debug)]
+pub struct DescendEvent {
+    pub entity: Entity,
+}
+
 /// General combat-related events
 #[derive(Event, Debug)]
 pub enum CombatEvent {
@@ -43,3 +49,44 @@ pub enum CombatEvent {
     AttackMissed { attacker: Entity, target: Entity },
     CriticalHit { attacker: Entity, target: Entity, damage: i32 },
 }
+
+/// Event fired when an action makes a sound that others might hear
+#[derive(Event, Debug, Clone, Copy)]
+pub struct NoiseEvent {
+    pub source: Entity,
+    pub position: Position,
+    pub kind: NoiseKind,
+    /// How far the sound carries through open floor, in tiles
+    pub loudness: u32,
+}
+
+impl NoiseEvent {
+    /// Create a noise with the default loudness for its kind
+    pub fn new(source: Entity, position: Position, kind: NoiseKind) -> Self {
+        Self { source, position, kind, loudness: kind.loudness() }
+    }
+}
+
+/// What made a noise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum NoiseKind {
+    Footstep,
+    Door,
+    Combat,
+    Digging,
+    /// An echolocation ping
+    Ping,
+}
+
+impl NoiseKind {
+    /// Default loudness for this kind of noise
+    pub const fn loudness(&self) -> u32 {
+        match self {
+            Self::Footstep => 4,
+            Self::Door => 6,
+            Self::Combat => 8,
+            Self::Digging => 12,
+            Self::Ping => 10,
+        }
+    }
+}
@@ -26,7 +26,9 @@ pub fn plugin(app: &mut App) {
         .init_resource::<resources::FovMap>()
         .init_resource::<resources::SpawnPoint>()
         .init_resource::<resources::DistanceSettings>()
-        .init_resource::<resources::LightMap>();
+        .init_resource::<resources::LightMap>()
+        .init_resource::<resources::NoiseMap>()
+        .init_resource::<resources::GameDifficulty>();
 
     // Register core components for reflection
     app.register_type::<components::Position>()
@@ -36,6 +38,21 @@ pub fn plugin(app: &mut App) {
         .register_type::<components::AITag>()
         .register_type::<components::DeadTag>()
         .register_type::<components::Light>()
+        .register_type::<components::Movement>()
+        .register_type::<components::OccupiesTile>()
+        .register_type::<components::Awareness>()
+        .register_type::<components::Faction>()
+        .register_type::<components::LootTable>()
+        .register_type::<components::FloorItem>()
+        .register_type::<components::Corpse>()
+        .register_type::<components::Experience>()
+        .register_type::<components::ExperienceValue>()
+        .register_type::<components::BaseAttributes>()
+        .register_type::<components::Encumbrance>()
+        .register_type::<components::Satiety>()
+        .register_type::<components::Fuel>()
+        .register_type::<components::LightRadius>()
+        .register_type::<resources::GameDifficulty>()
         .register_type::<resources::DistanceSettings>();
 
     // Register core events
@@ -43,7 +60,9 @@ pub fn plugin(app: &mut App) {
         .add_event::<events::GameEnded>()
         .add_event::<events::CombatEvent>()
         .add_event::<events::DamageDealtEvent>()
-        .add_event::<events::EntityDeathEvent>();
+        .add_event::<events::EntityDeathEvent>()
+        .add_event::<events::NoiseEvent>()
+        .add_event::<events::DescendEvent>();
 
     app.add_systems(
         PreUpdate,
@@ -58,12 +77,44 @@ pub fn plugin(app: &mut App) {
     // Add fov systems
     app.add_systems(
         Update,
-        (systems::fov::compute_fov, systems::light::calculate_light_map)
+        (
+            // Torches burn down before the light is cast, and the light before the player looks
+            (systems::light::burn_fuel, systems::light::calculate_light_map, systems::fov::compute_fov)
+                .chain(),
+            systems::fov::fade_echoes,
+        )
             .run_if(in_state(ScreenState::Gameplay))
             .run_if(in_state(GameState::ProcessTurns))
             .in_set(crate::gameplay::GameplaySystemSet::WorldUpdate),
     );
 
+    // Spread noise every frame so no event is missed while waiting for input
+    app.add_systems(
+        Update,
+        systems::noise::propagate_noise
+            .run_if(in_state(ScreenState::Gameplay))
+            .in_set(crate::gameplay::GameplaySystemSet::WorldUpdate),
+    );
+
+    // Verify the map's actor index every turn in debug builds
+    #[cfg(debug_assertions)]
+    app.add_systems(
+        Update,
+        systems::occupancy::check_actor_index
+            .run_if(in_state(ScreenState::Gameplay))
+            .run_if(in_state(GameState::ProcessTurns))
+            .in_set(crate::gameplay::GameplaySystemSet::WorldUpdate),
+    );
+
+    // Keep derived attributes in step with stats, and encumbrance with the carry capacity they give
+    app.add_systems(
+        Update,
+        (systems::attributes::update_derived_attributes, systems::encumbrance::update_encumbrance)
+            .chain()
+            .run_if(in_state(ScreenState::Gameplay))
+            .in_set(crate::gameplay::GameplaySystemSet::WorldUpdate),
+    );
+
     // Add combat systems
     app.add_systems(
         Update,
@@ -76,6 +127,15 @@ pub fn plugin(app: &mut App) {
             .in_set(crate::gameplay::GameplaySystemSet::ActionProcessing),
     );
 
+    // Corpses rot away as game time passes
+    app.add_systems(
+        Update,
+        systems::corpse::decay_corpses_system
+            .run_if(resource_changed::<resources::TurnQueue>)
+            .run_if(in_state(ScreenState::Gameplay))
+            .in_set(crate::gameplay::GameplaySystemSet::WorldUpdate),
+    );
+
     // Add cleanup system
     app.add_systems(
         Update,
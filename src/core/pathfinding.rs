@@ -4,6 +4,7 @@
 
 use crate::core::{components::Position, resources::CurrentMap};
 use brtk::pathfinding::{PathCacheConfig, PathProvider, PathfindingManager};
+use echos_assets::entities::MovementType;
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use std::time::Duration;
@@ -48,8 +49,11 @@ where
 }
 
 /// PathProvider implementation for CurrentMap
+///
+/// The `movement_type` id maps onto [`MovementType`] so flyers, swimmers and burrowers get their
+/// own passability and terrain costs.
 impl PathProvider for CurrentMap {
-    fn is_walkable(&mut self, position: (i32, i32), _movement_type: u8) -> bool {
+    fn is_walkable(&mut self, position: (i32, i32), movement_type: u8) -> bool {
         let pos = Position::new(position.0, position.1);
 
         // Check bounds first
@@ -57,26 +61,19 @@ impl PathProvider for CurrentMap {
             return false;
         }
 
-        // Check if terrain is walkable and no actor is present
-        // Note: using the Map's is_walkable method, not the trait method
-        (**self).is_walkable(pos) && self.get_actor(pos).is_none()
+        // Check if terrain is passable for this mover and no actor is present
+        self.is_passable(pos, MovementType::from_u8(movement_type)) && self.get_actor(pos).is_none()
     }
 
-    fn cost(&mut self, position: (i32, i32), _movement_type: u8) -> u32 {
+    fn cost(&mut self, position: (i32, i32), movement_type: u8) -> u32 {
         let pos = Position::new(position.0, position.1);
 
-        // Basic cost system - can be extended later for different terrain types
         if !self.in_bounds(pos) {
             return u32::MAX; // Impassable
         }
 
-        // For now, all walkable terrain has the same cost
-        // Future: different terrain types could have different costs
-        if (**self).is_walkable(pos) {
-            1 // Base movement cost
-        } else {
-            u32::MAX // Impassable
-        }
+        // Terrain cost is a multiplier of the base step cost (1 = open floor)
+        self.movement_cost(pos, MovementType::from_u8(movement_type)).unwrap_or(u32::MAX)
     }
 }
 
@@ -90,12 +87,23 @@ pub mod utils {
         destination: Position,
         map: &mut CurrentMap,
         allow_partial: bool,
+    ) -> Option<Vec<Position>> {
+        find_path_for(origin, destination, map, MovementType::Walking, allow_partial)
+    }
+
+    /// Find a cached A* path for a specific movement type
+    pub fn find_path_for(
+        origin: Position,
+        destination: Position,
+        map: &mut CurrentMap,
+        movement_type: MovementType,
+        allow_partial: bool,
     ) -> Option<Vec<Position>> {
         with_pathfinding_manager_write(|manager| {
             let path_coords = manager.find_path(
                 (origin.x(), origin.y()),
                 (destination.x(), destination.y()),
-                0, // movement_type - can be extended for different entity types
+                movement_type.as_u8(),
                 allow_partial,
                 map,
             )?;
@@ -106,7 +114,7 @@ pub mod utils {
         .flatten()
         .or_else(|| {
             log::warn!("Pathfinding failed, trying uncached path");
-            find_path_uncached(origin, destination, map, allow_partial)
+            find_path_uncached_for(origin, destination, map, movement_type, allow_partial)
         })
     }
 
@@ -116,11 +124,22 @@ pub mod utils {
         destination: Position,
         map: &mut CurrentMap,
         allow_partial: bool,
+    ) -> Option<Vec<Position>> {
+        find_path_uncached_for(origin, destination, map, MovementType::Walking, allow_partial)
+    }
+
+    /// Find an uncached path for a specific movement type
+    pub fn find_path_uncached_for(
+        origin: Position,
+        destination: Position,
+        map: &mut CurrentMap,
+        movement_type: MovementType,
+        allow_partial: bool,
     ) -> Option<Vec<Position>> {
         let path_coords = brtk::pathfinding::PathFinder::AStar.compute(
             (origin.x(), origin.y()),
             (destination.x(), destination.y()),
-            0, // movement_type - can be extended for different entity types
+            movement_type.as_u8(),
             allow_partial,
             map,
         )?;
@@ -278,12 +297,12 @@ pub mod utils {
 
     /// Validate a path to ensure it's still walkable
     pub fn validate_path(path: &[Position], map: &CurrentMap) -> bool {
-        for &pos in path {
-            if !(**map).is_walkable(pos) || map.get_actor(pos).is_some() {
-                return false;
-            }
-        }
-        true
+        validate_path_for(path, map, MovementType::Walking)
+    }
+
+    /// Validate a path for a specific movement type
+    pub fn validate_path_for(path: &[Position], map: &CurrentMap, movement_type: MovementType) -> bool {
+        path.iter().all(|&pos| map.is_passable(pos, movement_type) && map.get_actor(pos).is_none())
     }
 
     /// Get the next valid step from a path, skipping any blocked positions
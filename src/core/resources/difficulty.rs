@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+use echos_assets::survival::Difficulty;
+
+/// The difficulty picked for this game
+#[derive(Resource, Reflect, Debug, Clone, Copy, Default, Deref, DerefMut)]
+#[reflect(Resource)]
+pub struct GameDifficulty(pub Difficulty);
@@ -1,8 +1,21 @@
 use bevy::prelude::*;
 use bitvec::prelude::*;
 use brtk::fov::{FovAlgorithm as BrtkFovAlgorithm, FovReceiver, Shadowcast, VisibilityMap};
-
-use crate::core::{components::Position, constants::ModelConstants, resources::Map};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::core::{
+    components::Position,
+    constants::ModelConstants,
+    resources::{LightMap, Map},
+};
+
+/// An actor caught by an echolocation ping, outlined where it stood at the time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Silhouette {
+    pub position: Position,
+    /// Turns left before the silhouette fades
+    pub turns: u8,
+}
 
 /// Field of view map using bit-level storage for memory efficiency.
 /// This implementation uses the BitVec crate to store boolean values as individual bits.
@@ -12,6 +25,10 @@ pub struct FovMap {
     height: usize,
     visible: BitVec,
     revealed: BitVec,
+    /// Turns left before each tile's echolocation reveal fades, 0 when not echoed
+    echo: Vec<u8>,
+    /// Actors caught by echolocation, kept apart from the tile outlines since actors move on
+    silhouettes: HashMap<Entity, Silhouette>,
 }
 
 impl FromWorld for FovMap {
@@ -25,7 +42,14 @@ impl FovMap {
     pub fn new(width: usize, height: usize) -> Self {
         let size = width * height;
 
-        Self { width, height, revealed: bitvec![0; size], visible: bitvec![0; size] }
+        Self {
+            width,
+            height,
+            revealed: bitvec![0; size],
+            visible: bitvec![0; size],
+            echo: vec![0; size],
+            silhouettes: HashMap::new(),
+        }
     }
 
     /// Converts 2D coordinates to a 1D index
@@ -67,6 +91,92 @@ impl FovMap {
         }
     }
 
+    /// Turns left on a position's echolocation reveal (0 when not echoed)
+    pub fn echo_at(&self, pos: Position) -> u8 {
+        let (x, y) = pos.into();
+        self.coords_to_index(x, y).map(|idx| self.echo[idx]).unwrap_or(0)
+    }
+
+    /// Checks if a position is currently outlined by an echo
+    pub fn is_echoed(&self, pos: Position) -> bool { self.echo_at(pos) > 0 }
+
+    /// Outlines a position for `turns` turns and marks it as revealed
+    pub fn set_echo(&mut self, pos: Position, turns: u8) {
+        let (x, y) = pos.into();
+        if let Some(idx) = self.coords_to_index(x, y) {
+            self.echo[idx] = self.echo[idx].max(turns);
+            self.revealed.set(idx, true);
+        }
+    }
+
+    /// Ages every echo by the given number of turns
+    pub fn fade_echoes(&mut self, turns: u8) {
+        for echo in self.echo.iter_mut() {
+            *echo = echo.saturating_sub(turns);
+        }
+    }
+
+    /// The silhouette an echolocation ping caught of an actor, if it has not faded yet
+    pub fn silhouette(&self, entity: Entity) -> Option<Silhouette> { self.silhouettes.get(&entity).copied() }
+
+    /// Every actor silhouette that has not faded yet
+    pub fn silhouettes(&self) -> impl Iterator<Item = (Entity, Silhouette)> + '_ {
+        self.silhouettes.iter().map(|(entity, silhouette)| (*entity, *silhouette))
+    }
+
+    /// Ages every actor silhouette by the given number of turns, dropping the ones that faded
+    pub fn fade_silhouettes(&mut self, turns: u8) {
+        self.silhouettes.retain(|_, silhouette| {
+            silhouette.turns = silhouette.turns.saturating_sub(turns);
+            silhouette.turns > 0
+        });
+    }
+
+    /// Sends out an echolocation ping from `origin`.
+    ///
+    /// The sound wave floods through open ground up to `radius` steps, so it reaches around
+    /// corners, but it only picks out the outlines of the solid terrain it hits, which last
+    /// `outline_turns`, and the silhouettes of actors in its path, which last `silhouette_turns`.
+    /// Returns the positions that were echoed.
+    pub fn echolocate(
+        &mut self,
+        map: &Map,
+        origin: Position,
+        radius: u32,
+        outline_turns: u8,
+        silhouette_turns: u8,
+    ) -> Vec<Position> {
+        let mut echoed = Vec::new();
+        let mut reached = HashSet::from([origin]);
+        let mut frontier = VecDeque::from([(origin, 0)]);
+
+        while let Some((position, steps)) = frontier.pop_front() {
+            if position != origin
+                && let Some(actor) = map.get_actor(position)
+            {
+                // The actor is outlined where it stands now, not wherever it goes next
+                self.silhouettes.insert(actor, Silhouette { position, turns: silhouette_turns });
+                echoed.push(position);
+            }
+
+            for neighbor in map.get_neighbors(position) {
+                if !reached.insert(neighbor) {
+                    continue;
+                }
+
+                if !map.get_terrain(neighbor).is_some_and(|terrain| terrain.is_traversable()) {
+                    // The wave bounces off solid terrain, outlining it
+                    self.set_echo(neighbor, outline_turns);
+                    echoed.push(neighbor);
+                } else if steps < radius {
+                    frontier.push_back((neighbor, steps + 1));
+                }
+            }
+        }
+
+        echoed
+    }
+
     /// Clears all visibility flags (called at the start of each turn)
     pub fn clear_visibility(&mut self) { self.visible.fill(false); }
 
@@ -81,6 +191,20 @@ impl FovMap {
         self.compute_fov_advanced_shadowcasting(map, origin, radius as u32)
     }
 
+    /// Updates the FOV for an entity without a light of its own: only tiles some other light
+    /// reaches can be seen, besides the one it stands on
+    pub fn compute_lit_fov(&mut self, map: &Map, origin: Position, radius: u8, light_map: &LightMap) {
+        self.clear_visibility();
+        self.set_visible(origin, true);
+
+        let in_sight = Self::compute_temporary_fov(origin, radius, map);
+        for &position in in_sight.get_visible_set() {
+            if light_map.is_lit(position) {
+                self.set_visible(Position::new(position.0, position.1), true);
+            }
+        }
+    }
+
     // ============================================================================
     // ENTITY VISIBILITY UTILITY FUNCTIONS
     // ============================================================================
@@ -195,7 +319,7 @@ mod tests {
         for y in 0..height {
             for x in 0..width {
                 let pos = Position::new(x as i32, y as i32);
-                map.set_terrain(pos, TerrainType::Floor);
+                map.set_terrain(pos, TerrainType::FLOOR);
             }
         }
 
@@ -204,7 +328,7 @@ mod tests {
 
     fn create_test_map_with_wall(width: u32, height: u32, wall_pos: Position) -> Map {
         let mut map = create_test_map(width, height);
-        map.set_terrain(wall_pos, TerrainType::Wall);
+        map.set_terrain(wall_pos, TerrainType::WALL);
         map
     }
 
@@ -254,6 +378,22 @@ mod tests {
         assert!(can_see, "can_see_entity should work as wrapper around can_see_position");
     }
 
+    #[test]
+    fn test_lit_fov_only_sees_lit_tiles() {
+        let map = create_test_map(10, 10);
+        let mut light_map = LightMap::new();
+        light_map.set_light((7, 5), Color::WHITE);
+        light_map.set_light((8, 5), Color::srgb(0.01, 0.01, 0.01));
+
+        let mut fov_map = FovMap::new(10, 10);
+        fov_map.compute_lit_fov(&map, Position::new(5, 5), 5, &light_map);
+
+        assert!(fov_map.is_visible(Position::new(5, 5)), "Observer's own tile should be visible");
+        assert!(fov_map.is_visible(Position::new(7, 5)), "Lit tile in range should be visible");
+        assert!(!fov_map.is_visible(Position::new(8, 5)), "Barely lit tile should stay dark");
+        assert!(!fov_map.is_revealed(Position::new(6, 5)), "Dark tiles should not be revealed");
+    }
+
     #[test]
     fn test_compute_temporary_fov() {
         let map = create_test_map(10, 10);
@@ -274,4 +414,45 @@ mod tests {
         // Positions beyond range should not be visible
         assert!(!visibility_map.get_visible((10, 10)), "Position beyond range should not be visible");
     }
+
+    #[test]
+    fn test_echolocation_reaches_around_corners_and_fades() {
+        // An L-shaped corridor: the ping starts at the bottom and the far end is out of sight
+        let mut map = Map::new((10, 10));
+        for y in 1..6 {
+            map.set_terrain(Position::new(2, y), TerrainType::FLOOR);
+        }
+        for x in 2..8 {
+            map.set_terrain(Position::new(x, 5), TerrainType::FLOOR);
+        }
+        let hidden_actor = Entity::from_raw(7);
+        map.place_actor(Position::new(6, 5), hidden_actor).unwrap();
+
+        let mut fov_map = FovMap::new(10, 10);
+        let echoed = fov_map.echolocate(&map, Position::new(2, 1), 12, 3, 1);
+
+        // Walls along the far leg are outlined and the actor around the corner is caught
+        assert!(echoed.contains(&Position::new(6, 5)));
+        assert!(fov_map.is_echoed(Position::new(6, 4)));
+        assert!(fov_map.is_revealed(Position::new(6, 4)));
+        assert_eq!(
+            fov_map.silhouette(hidden_actor),
+            Some(Silhouette { position: Position::new(6, 5), turns: 1 })
+        );
+
+        // Open floor itself is not picked out, even under the actor
+        assert!(!fov_map.is_echoed(Position::new(4, 5)));
+        assert!(!fov_map.is_echoed(Position::new(6, 5)));
+
+        // The silhouette fades on its own clock
+        fov_map.fade_silhouettes(1);
+        assert!(fov_map.silhouette(hidden_actor).is_none());
+        assert!(fov_map.is_echoed(Position::new(6, 4)));
+
+        fov_map.fade_echoes(2);
+        assert_eq!(fov_map.echo_at(Position::new(6, 4)), 1);
+        fov_map.fade_echoes(1);
+        assert!(!fov_map.is_echoed(Position::new(6, 4)));
+        assert!(fov_map.is_revealed(Position::new(6, 4)));
+    }
 }
@@ -1,6 +1,9 @@
 use bevy::prelude::{Color, Resource};
 use std::collections::HashMap;
 
+/// Brightest color channel a tile needs to count as lit
+const LIT_THRESHOLD: f32 = 0.05;
+
 /// A resource that stores the combined light intensity and color for each tile on the map.
 #[derive(Resource, Default, Debug, Clone)]
 pub struct LightMap {
@@ -18,6 +21,12 @@ impl LightMap {
         *self.map.get(&position).unwrap_or(&Color::BLACK)
     }
 
+    /// Whether a position gets enough light to see by.
+    pub fn is_lit(&self, position: (i32, i32)) -> bool {
+        let light = self.get_light(position).to_linear();
+        light.red.max(light.green).max(light.blue) > LIT_THRESHOLD
+    }
+
     /// Clears all light information from the map.
     pub fn clear(&mut self) { self.map.clear(); }
 }
@@ -1,10 +1,12 @@
 use bevy::{platform::collections::HashMap, prelude::*};
 use bevy_ecs_tilemap::prelude::*;
 use brtk::prelude::*;
+use echos_assets::entities::MovementType;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::{
     core::{components::Position, constants::ModelConstants},
-    gameplay::world::components::TerrainType,
+    gameplay::world::components::{Feature, TerrainType},
 };
 
 /// Represents a single tile in the map with all its properties
@@ -13,12 +15,33 @@ pub struct Tile {
     pub terrain: TerrainType,
     pub tile_entity: Option<Entity>,
     pub actor: Option<Entity>,
+    /// Fixed feature on the tile (door, trap, altar, stairs) and what it currently is
+    pub feature: Option<(Entity, Feature)>,
+    /// Pile of item entities lying on the tile
+    pub items: Vec<Entity>,
+    /// Purely cosmetic marks (blood, rubble, scorch marks)
+    pub decorations: Vec<Entity>,
+    /// Part of an echo chamber, where sound carries further
+    pub echo_chamber: bool,
 }
 
 impl Default for Tile {
-    fn default() -> Self { Self { terrain: TerrainType::Wall, tile_entity: None, actor: None } }
+    fn default() -> Self {
+        Self {
+            terrain: TerrainType::WALL,
+            tile_entity: None,
+            actor: None,
+            feature: None,
+            items: Vec::new(),
+            decorations: Vec::new(),
+            echo_chamber: false,
+        }
+    }
 }
 
+/// Generation handed to the next map built
+static NEXT_MAP_GENERATION: AtomicU64 = AtomicU64::new(0);
+
 #[derive(Reflect, Clone, Resource)]
 pub struct Map {
     pub size: (u32, u32),
@@ -26,8 +49,19 @@ pub struct Map {
     pub tiles: Grid<Tile>,
     /// Fast reverse lookup: entity -> position
     pub actor_positions: HashMap<Entity, Position>,
+    /// Reverse lookup for the feature layer
+    pub feature_positions: HashMap<Entity, Position>,
+    /// Reverse lookup for the item layer
+    pub item_positions: HashMap<Entity, Position>,
+    /// Reverse lookup for the decoration layer
+    pub decoration_positions: HashMap<Entity, Position>,
     /// Tilemap storage for rendering (derived from tiles)
     pub tile_storage: TileStorage,
+    /// Bumped whenever a tile's terrain changes or a feature starts or stops blocking it, so cached
+    /// views know to refresh
+    terrain_revision: u64,
+    /// Tells maps apart, since every new map starts again at the same terrain revision
+    generation: u64,
 }
 
 impl FromWorld for Map {
@@ -46,21 +80,14 @@ impl FovProvider for Map {
             return true;
         }
 
-        // Check if terrain blocks vision
-        self.get_terrain(pos).map(|terrain| terrain.blocks_vision()).unwrap_or(true)
+        // Check if terrain or a feature (closed doors) blocks vision
+        self.get_feature_kind(pos).is_some_and(|feature| feature.blocks_vision())
+            || self.get_terrain(pos).map(|terrain| terrain.blocks_vision()).unwrap_or(true)
     }
 }
 
 impl Map {
-    pub fn new(size: (u32, u32)) -> Self {
-        let tiles = Grid::new_fill(size, Tile::default());
-        Self {
-            size,
-            tiles,
-            actor_positions: HashMap::new(),
-            tile_storage: TileStorage::empty(TilemapSize::new(size.0, size.1)),
-        }
-    }
+    pub fn new(size: (u32, u32)) -> Self { Self::new_with_terrain(size, TerrainType::WALL) }
 
     pub fn new_with_terrain(size: (u32, u32), terrain: TerrainType) -> Self {
         let tiles = Grid::new_fill(size, Tile { terrain, ..Default::default() });
@@ -69,7 +96,12 @@ impl Map {
             size,
             tiles,
             actor_positions: HashMap::new(),
+            feature_positions: HashMap::new(),
+            item_positions: HashMap::new(),
+            decoration_positions: HashMap::new(),
             tile_storage: TileStorage::empty(TilemapSize::new(size.0, size.1)),
+            terrain_revision: 0,
+            generation: NEXT_MAP_GENERATION.fetch_add(1, Ordering::Relaxed),
         }
     }
 
@@ -93,16 +125,56 @@ impl Map {
     }
 
     pub fn set_terrain(&mut self, position: Position, terrain: TerrainType) {
-        if let Some(tile) = self.tiles.get_mut(position.into()) {
+        let Some(tile) = self.tiles.get_mut(position.into()) else {
+            return;
+        };
+
+        if tile.terrain != terrain {
             tile.terrain = terrain;
+            self.terrain_revision += 1;
         }
     }
 
+    /// Counter that changes every time terrain is modified or a door opens or closes
+    pub fn terrain_revision(&self) -> u64 { self.terrain_revision }
+
+    /// Id of this map, different for every map built
+    pub fn generation(&self) -> u64 { self.generation }
+
     pub fn is_walkable(&self, position: Position) -> bool {
-        self.get_terrain(position).map(|terrain| terrain != TerrainType::Wall).unwrap_or(false)
+        !self.feature_blocks_movement(position)
+            && self.get_terrain(position).map(|terrain| terrain.is_walkable()).unwrap_or(false)
+    }
+
+    /// Check if an entity with the given movement type can enter a position
+    pub fn is_passable(&self, position: Position, movement_type: MovementType) -> bool {
+        !self.feature_blocks_movement(position)
+            && self.get_terrain(position).map(|terrain| terrain.is_passable(movement_type)).unwrap_or(false)
+    }
+
+    /// Movement cost multiplier for entering a position, `None` if impassable
+    pub fn movement_cost(&self, position: Position, movement_type: MovementType) -> Option<u32> {
+        if self.feature_blocks_movement(position) {
+            return None;
+        }
+        self.get_terrain(position).and_then(|terrain| terrain.movement_cost(movement_type))
+    }
+
+    fn feature_blocks_movement(&self, position: Position) -> bool {
+        self.get_feature_kind(position).is_some_and(|feature| feature.blocks_movement())
     }
 
     // Actor management with bidirectional lookup
+    pub fn is_echo_chamber(&self, position: Position) -> bool {
+        self.tiles.get(position.into()).is_some_and(|tile| tile.echo_chamber)
+    }
+
+    pub fn set_echo_chamber(&mut self, position: Position, echo_chamber: bool) {
+        if let Some(tile) = self.tiles.get_mut(position.into()) {
+            tile.echo_chamber = echo_chamber;
+        }
+    }
+
     pub fn get_actor(&self, position: Position) -> Option<Entity> {
         self.tiles.get(position.into()).and_then(|tile| tile.actor)
     }
@@ -132,12 +204,16 @@ impl Map {
             return Err("Position out of bounds".to_string());
         }
 
-        if !self.is_walkable(position) {
-            return Err("Position not walkable".to_string());
+        // Movement rules are enforced by actions; the map only rejects solid terrain
+        if !self.get_terrain(position).is_some_and(|terrain| terrain.is_traversable()) {
+            return Err("Position not traversable".to_string());
         }
 
-        if self.get_actor(position).is_some() {
-            return Err("Position already occupied".to_string());
+        match self.get_actor(position) {
+            // Re-placing an actor where it already stands is a no-op
+            Some(occupant) if occupant == actor => return Ok(()),
+            Some(_) => return Err("Position already occupied".to_string()),
+            None => {}
         }
 
         // Remove actor from previous position if it exists
@@ -181,6 +257,183 @@ impl Map {
         }
     }
 
+    /// Compare the actor index against the positions the ECS reports for occupying entities.
+    ///
+    /// Returns a human-readable line for every disagreement between `actors`, `actor_positions`
+    /// and the per-tile `actor` slots. An empty result means the index is consistent.
+    pub fn actor_index_mismatches(
+        &self,
+        actors: impl IntoIterator<Item = (Entity, Position)>,
+    ) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        let actors: HashMap<Entity, Position> = actors.into_iter().collect();
+
+        for (&entity, &position) in actors.iter() {
+            match self.actor_positions.get(&entity) {
+                Some(&indexed) if indexed == position => {}
+                Some(&indexed) => mismatches
+                    .push(format!("{entity} is at {position:?} but the map indexes it at {indexed:?}")),
+                None => mismatches.push(format!("{entity} is at {position:?} but missing from the map")),
+            }
+        }
+
+        for (&entity, &indexed) in self.actor_positions.iter() {
+            if !actors.contains_key(&entity) {
+                mismatches
+                    .push(format!("map indexes {entity} at {indexed:?} but it no longer occupies a tile"));
+            }
+
+            if self.get_actor(indexed) != Some(entity) {
+                mismatches.push(format!("tile {indexed:?} does not hold {entity} indexed there"));
+            }
+        }
+
+        for (x, y) in self.tiles.position_iter() {
+            let position = Position::new(x, y);
+            if let Some(entity) = self.get_actor(position)
+                && self.actor_positions.get(&entity) != Some(&position)
+            {
+                mismatches.push(format!("tile {position:?} holds {entity} but the index disagrees"));
+            }
+        }
+
+        mismatches
+    }
+
+    // Feature layer (one per tile)
+    pub fn get_feature(&self, position: Position) -> Option<Entity> {
+        self.tiles.get(position.into()).and_then(|tile| tile.feature).map(|(entity, _)| entity)
+    }
+
+    /// What the feature on a tile currently is (a door knows whether it's open)
+    pub fn get_feature_kind(&self, position: Position) -> Option<Feature> {
+        self.tiles.get(position.into()).and_then(|tile| tile.feature).map(|(_, kind)| kind)
+    }
+
+    pub fn get_feature_position(&self, feature: Entity) -> Option<Position> {
+        self.feature_positions.get(&feature).copied()
+    }
+
+    /// Put a feature on a tile, or update the state of one already there
+    pub fn place_feature(
+        &mut self,
+        position: Position,
+        feature: Entity,
+        kind: Feature,
+    ) -> Result<(), String> {
+        if !self.in_bounds(position) {
+            return Err("Position out of bounds".to_string());
+        }
+
+        if self.get_feature(position).is_some_and(|existing| existing != feature) {
+            return Err("Position already has a feature".to_string());
+        }
+
+        let previous = self.take_feature(feature);
+        if let Some(tile) = self.tiles.get_mut(position.into()) {
+            tile.feature = Some((feature, kind));
+            self.feature_positions.insert(feature, position);
+        }
+
+        // A door opening or closing changes what can be seen and walked through
+        let reblocked = match previous {
+            Some((old_position, old_kind)) if old_position == position => {
+                Self::feature_blocks(old_kind) != Self::feature_blocks(kind)
+            }
+            Some((_, old_kind)) => Self::feature_blocks(old_kind) || Self::feature_blocks(kind),
+            None => Self::feature_blocks(kind),
+        };
+        if reblocked {
+            self.terrain_revision += 1;
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_feature(&mut self, feature: Entity) -> Option<Position> {
+        let (position, kind) = self.take_feature(feature)?;
+        if Self::feature_blocks(kind) {
+            self.terrain_revision += 1;
+        }
+        Some(position)
+    }
+
+    /// Lift a feature off its tile without touching the terrain revision
+    fn take_feature(&mut self, feature: Entity) -> Option<(Position, Feature)> {
+        let position = self.feature_positions.remove(&feature)?;
+        let (_, kind) = self.tiles.get_mut(position.into())?.feature.take()?;
+        Some((position, kind))
+    }
+
+    /// Whether a feature stops anything passing through its tile
+    fn feature_blocks(kind: Feature) -> bool { kind.blocks_movement() || kind.blocks_vision() }
+
+    // Item layer (piles of any size)
+    pub fn get_items(&self, position: Position) -> &[Entity] {
+        self.tiles.get(position.into()).map(|tile| tile.items.as_slice()).unwrap_or_default()
+    }
+
+    pub fn get_item_position(&self, item: Entity) -> Option<Position> {
+        self.item_positions.get(&item).copied()
+    }
+
+    pub fn place_item(&mut self, position: Position, item: Entity) -> Result<(), String> {
+        if !self.in_bounds(position) {
+            return Err("Position out of bounds".to_string());
+        }
+
+        if !self.get_terrain(position).is_some_and(|terrain| terrain.is_traversable()) {
+            return Err("Position not traversable".to_string());
+        }
+
+        self.remove_item(item);
+        if let Some(tile) = self.tiles.get_mut(position.into()) {
+            tile.items.push(item);
+            self.item_positions.insert(item, position);
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_item(&mut self, item: Entity) -> Option<Position> {
+        let position = self.item_positions.remove(&item)?;
+        if let Some(tile) = self.tiles.get_mut(position.into()) {
+            tile.items.retain(|&entity| entity != item);
+        }
+        Some(position)
+    }
+
+    // Decoration layer (stacks freely, never blocks)
+    pub fn get_decorations(&self, position: Position) -> &[Entity] {
+        self.tiles.get(position.into()).map(|tile| tile.decorations.as_slice()).unwrap_or_default()
+    }
+
+    pub fn get_decoration_position(&self, decoration: Entity) -> Option<Position> {
+        self.decoration_positions.get(&decoration).copied()
+    }
+
+    pub fn place_decoration(&mut self, position: Position, decoration: Entity) -> Result<(), String> {
+        if !self.in_bounds(position) {
+            return Err("Position out of bounds".to_string());
+        }
+
+        self.remove_decoration(decoration);
+        if let Some(tile) = self.tiles.get_mut(position.into()) {
+            tile.decorations.push(decoration);
+            self.decoration_positions.insert(decoration, position);
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_decoration(&mut self, decoration: Entity) -> Option<Position> {
+        let position = self.decoration_positions.remove(&decoration)?;
+        if let Some(tile) = self.tiles.get_mut(position.into()) {
+            tile.decorations.retain(|&entity| entity != decoration);
+        }
+        Some(position)
+    }
+
     // Tile entity management for rendering
     pub fn get_tile_entity(&self, position: Position) -> Option<Entity> {
         self.tiles.get(position.into()).and_then(|tile| tile.tile_entity)
@@ -243,22 +496,158 @@ impl Map {
     }
 
     pub fn get_actors_in_radius(&self, center: Position, radius: i32) -> Vec<(Position, Entity)> {
-        let mut actors = Vec::new();
+        self.positions_in_radius(center, radius)
+            .filter_map(|pos| self.get_actor(pos).map(|e| (pos, e)))
+            .collect()
+    }
+
+    pub fn get_features_in_radius(&self, center: Position, radius: i32) -> Vec<(Position, Entity)> {
+        self.positions_in_radius(center, radius)
+            .filter_map(|pos| self.get_feature(pos).map(|e| (pos, e)))
+            .collect()
+    }
+
+    pub fn get_items_in_radius(&self, center: Position, radius: i32) -> Vec<(Position, Entity)> {
+        self.positions_in_radius(center, radius)
+            .flat_map(|pos| self.get_items(pos).iter().map(move |&e| (pos, e)))
+            .collect()
+    }
+
+    pub fn get_decorations_in_radius(&self, center: Position, radius: i32) -> Vec<(Position, Entity)> {
+        self.positions_in_radius(center, radius)
+            .flat_map(|pos| self.get_decorations(pos).iter().map(move |&e| (pos, e)))
+            .collect()
+    }
+
+    /// In-bounds positions within a circular radius of `center`
+    fn positions_in_radius(&self, center: Position, radius: i32) -> impl Iterator<Item = Position> + '_ {
         let (cx, cy) = center.into();
 
-        for x in (cx - radius)..=(cx + radius) {
-            for y in (cy - radius)..=(cy + radius) {
-                let pos = Position::new(x, y);
-                if self.in_bounds(pos) {
-                    let distance_sq = (x - cx).pow(2) + (y - cy).pow(2);
-                    if distance_sq <= radius.pow(2)
-                        && let Some(actor) = self.get_actor(pos)
-                    {
-                        actors.push((pos, actor));
-                    }
-                }
-            }
-        }
-        actors
+        ((cx - radius)..=(cx + radius))
+            .flat_map(move |x| ((cy - radius)..=(cy + radius)).map(move |y| Position::new(x, y)))
+            .filter(move |pos| {
+                self.in_bounds(*pos) && (pos.x - cx).pow(2) + (pos.y - cy).pow(2) <= radius.pow(2)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn floor_map() -> Map { Map::new_with_terrain((10, 10), TerrainType::FLOOR) }
+
+    #[test]
+    fn test_new_maps_are_told_apart_at_the_same_revision() {
+        let (first, second) = (floor_map(), floor_map());
+
+        assert_eq!(first.terrain_revision(), second.terrain_revision());
+        assert_ne!(first.generation(), second.generation());
+    }
+
+    #[test]
+    fn test_feature_layer_bidirectional_lookup() {
+        let mut map = floor_map();
+        let door = Entity::from_raw(1);
+        let trap = Entity::from_raw(2);
+
+        map.place_feature(Position::new(2, 2), door, Feature::Door { open: true }).unwrap();
+        assert_eq!(map.get_feature(Position::new(2, 2)), Some(door));
+        assert_eq!(map.get_feature_position(door), Some(Position::new(2, 2)));
+
+        // Only one feature per tile
+        assert!(map.place_feature(Position::new(2, 2), trap, Feature::Trap { armed: true }).is_err());
+
+        // Moving a feature clears its old tile
+        map.place_feature(Position::new(3, 3), door, Feature::Door { open: true }).unwrap();
+        assert_eq!(map.get_feature(Position::new(2, 2)), None);
+        assert_eq!(map.remove_feature(door), Some(Position::new(3, 3)));
+        assert_eq!(map.get_feature(Position::new(3, 3)), None);
+    }
+
+    #[test]
+    fn test_closed_doors_block_movement_and_vision() {
+        let mut map = floor_map();
+        let door = Entity::from_raw(1);
+        let doorway = Position::new(4, 4);
+
+        map.place_feature(doorway, door, Feature::Door { open: false }).unwrap();
+        assert!(!map.is_walkable(doorway));
+        assert!(!map.is_passable(doorway, MovementType::Flying));
+        assert_eq!(map.movement_cost(doorway, MovementType::Walking), None);
+        assert!(map.is_opaque(doorway.into(), 0));
+
+        // Opening the door re-places it with its new state, and cached views must refresh
+        let revision = map.terrain_revision();
+        map.place_feature(doorway, door, Feature::Door { open: true }).unwrap();
+        assert!(map.is_walkable(doorway));
+        assert!(!map.is_opaque(doorway.into(), 0));
+        assert_ne!(map.terrain_revision(), revision);
+
+        // Re-placing it unchanged leaves them be
+        let revision = map.terrain_revision();
+        map.place_feature(doorway, door, Feature::Door { open: true }).unwrap();
+        assert_eq!(map.terrain_revision(), revision);
+    }
+
+    #[test]
+    fn test_item_piles_and_radius_queries() {
+        let mut map = floor_map();
+        let sword = Entity::from_raw(1);
+        let potion = Entity::from_raw(2);
+        let far_item = Entity::from_raw(3);
+
+        map.place_item(Position::new(5, 5), sword).unwrap();
+        map.place_item(Position::new(5, 5), potion).unwrap();
+        map.place_item(Position::new(9, 9), far_item).unwrap();
+
+        assert_eq!(map.get_items(Position::new(5, 5)), &[sword, potion]);
+        assert_eq!(map.get_item_position(potion), Some(Position::new(5, 5)));
+
+        let nearby = map.get_items_in_radius(Position::new(5, 5), 2);
+        assert_eq!(nearby.len(), 2);
+
+        map.remove_item(sword);
+        assert_eq!(map.get_items(Position::new(5, 5)), &[potion]);
+        assert_eq!(map.get_item_position(sword), None);
+
+        // Items can't be dropped into solid rock
+        map.set_terrain(Position::new(0, 0), TerrainType::WALL);
+        assert!(map.place_item(Position::new(0, 0), sword).is_err());
+    }
+
+    #[test]
+    fn test_decorations_stack() {
+        let mut map = floor_map();
+        let blood = Entity::from_raw(1);
+        let scorch = Entity::from_raw(2);
+
+        map.place_decoration(Position::new(1, 1), blood).unwrap();
+        map.place_decoration(Position::new(1, 1), scorch).unwrap();
+
+        assert_eq!(map.get_decorations(Position::new(1, 1)).len(), 2);
+        assert_eq!(map.get_decorations_in_radius(Position::new(0, 0), 1).len(), 0);
+        assert_eq!(map.get_decorations_in_radius(Position::new(0, 0), 2).len(), 2);
+        assert_eq!(map.remove_decoration(blood), Some(Position::new(1, 1)));
+    }
+
+    #[test]
+    fn test_actor_index_mismatches() {
+        let mut map = floor_map();
+        let goblin = Entity::from_raw(1);
+        let rat = Entity::from_raw(2);
+
+        map.place_actor(Position::new(1, 1), goblin).unwrap();
+        // Placing an actor where it already stands is not an error
+        map.place_actor(Position::new(1, 1), goblin).unwrap();
+        assert!(map.actor_index_mismatches([(goblin, Position::new(1, 1))]).is_empty());
+
+        // The ECS moved the goblin and spawned a rat without telling the map
+        let mismatches =
+            map.actor_index_mismatches([(goblin, Position::new(2, 1)), (rat, Position::new(3, 3))]);
+        assert_eq!(mismatches.len(), 2);
+
+        // A despawned actor left behind in the index
+        assert_eq!(map.actor_index_mismatches([]).len(), 1);
     }
 }
@@ -18,3 +18,9 @@ pub use self::distance_settings::*;
 
 mod light_map;
 pub use self::light_map::*;
+
+mod noise_map;
+pub use self::noise_map::*;
+
+mod difficulty;
+pub use self::difficulty::*;
@@ -0,0 +1,223 @@
+use bevy::prelude::{Entity, Resource};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::core::{
+    components::Position,
+    events::{NoiseEvent, NoiseKind},
+    resources::Map,
+};
+
+/// Propagation cost of one tile of open ground, in half-tile units of loudness
+const OPEN_COST: u32 = 2;
+/// Walls and other solid terrain muffle sound heavily
+const SOLID_COST: u32 = 8;
+/// Echo chambers carry sound twice as far
+const ECHO_COST: u32 = 1;
+
+/// The loudest noise from one source heard on a tile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeardNoise {
+    /// Remaining loudness on this tile; anything above zero is audible
+    pub level: f32,
+    /// Where the noise was made
+    pub origin: Position,
+    pub source: Entity,
+    pub kind: NoiseKind,
+    /// Game time the noise was made
+    pub time: u64,
+}
+
+/// A resource that stores the noise reaching each tile of the map.
+///
+/// Noises are flood-filled out from their origin, losing loudness with distance. Solid terrain
+/// muffles them and echo chambers carry them further. Each tile keeps the loudest recent noise of
+/// every source, so a listener's own footsteps never drown out what it is listening for.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct NoiseMap {
+    heard: HashMap<(i32, i32), Vec<HeardNoise>>,
+}
+
+impl NoiseMap {
+    pub fn new() -> Self { Self::default() }
+
+    /// Spread a noise across the map, returning the number of tiles it reached
+    pub fn propagate(&mut self, map: &Map, noise: &NoiseEvent, time: u64) -> usize {
+        if !map.in_bounds(noise.position) {
+            return 0;
+        }
+
+        let budget = noise.loudness * OPEN_COST;
+        let mut costs: HashMap<(i32, i32), u32> = HashMap::new();
+        let mut frontier = BinaryHeap::new();
+
+        let origin: (i32, i32) = noise.position.into();
+        costs.insert(origin, 0);
+        frontier.push(Reverse((0, origin)));
+
+        while let Some(Reverse((cost, (x, y)))) = frontier.pop() {
+            if costs.get(&(x, y)).is_some_and(|best| *best < cost) {
+                continue;
+            }
+
+            // Solid tiles hear the noise, but it does not carry on out of them
+            let position = Position::new(x, y);
+            if cost > 0 && !map.get_terrain(position).is_some_and(|terrain| terrain.is_traversable()) {
+                continue;
+            }
+
+            for (dx, dy) in NEIGHBOURS {
+                let next = Position::new(x + dx, y + dy);
+                let Some(terrain) = map.get_terrain(next) else {
+                    continue;
+                };
+
+                let step = if !terrain.is_traversable() {
+                    SOLID_COST
+                } else if map.is_echo_chamber(next) {
+                    ECHO_COST
+                } else {
+                    OPEN_COST
+                };
+
+                let next_cost = cost + step;
+                if next_cost >= budget || costs.get(&next.into()).is_some_and(|best| *best <= next_cost) {
+                    continue;
+                }
+
+                costs.insert(next.into(), next_cost);
+                frontier.push(Reverse((next_cost, next.into())));
+            }
+        }
+
+        for (&tile, &cost) in costs.iter() {
+            let level = (budget - cost) as f32 / OPEN_COST as f32;
+            let heard =
+                HeardNoise { level, origin: noise.position, source: noise.source, kind: noise.kind, time };
+
+            // Keep the louder of overlapping noises from the same source
+            let noises = self.heard.entry(tile).or_default();
+            match noises.iter_mut().find(|existing| existing.source == noise.source) {
+                Some(existing) if existing.level < level => *existing = heard,
+                Some(_) => {}
+                None => noises.push(heard),
+            }
+        }
+
+        costs.len()
+    }
+
+    /// The loudest noise audible at a position, if any
+    pub fn noise_at(&self, position: Position) -> Option<&HeardNoise> {
+        self.noises_at(position).max_by(|a, b| a.level.total_cmp(&b.level))
+    }
+
+    /// The loudest noise a given source made that is audible at a position, if any
+    pub fn noise_from(&self, position: Position, source: Entity) -> Option<&HeardNoise> {
+        self.noises_at(position).find(|noise| noise.source == source)
+    }
+
+    /// Every source's noise audible at a position
+    pub fn noises_at(&self, position: Position) -> impl Iterator<Item = &HeardNoise> {
+        self.heard.get(&position.into()).into_iter().flatten()
+    }
+
+    /// Noise at a position that is at least `threshold` loud
+    pub fn hears(&self, position: Position, threshold: f32) -> Option<&HeardNoise> {
+        self.noise_at(position).filter(|noise| noise.level >= threshold)
+    }
+
+    /// All tiles currently carrying noise
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &HeardNoise)> {
+        self.heard
+            .iter()
+            .flat_map(|(&(x, y), noises)| noises.iter().map(move |noise| (Position::new(x, y), noise)))
+    }
+
+    /// Forget noises made `lifetime` or more time units before `now`
+    pub fn fade(&mut self, now: u64, lifetime: u64) {
+        self.heard.retain(|_, noises| {
+            noises.retain(|noise| now.saturating_sub(noise.time) < lifetime);
+            !noises.is_empty()
+        });
+    }
+
+    /// Clears all noise from the map.
+    pub fn clear(&mut self) { self.heard.clear(); }
+}
+
+const NEIGHBOURS: [(i32, i32); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::world::components::TerrainType;
+
+    fn floor_map() -> Map { Map::new_with_terrain((30, 5), TerrainType::FLOOR) }
+
+    fn footstep_at(x: i32, y: i32) -> NoiseEvent {
+        NoiseEvent::new(Entity::from_raw(1), Position::new(x, y), NoiseKind::Footstep)
+    }
+
+    #[test]
+    fn test_noise_fades_with_distance() {
+        let mut noise_map = NoiseMap::new();
+        noise_map.propagate(&floor_map(), &footstep_at(2, 2), 0);
+
+        let near = noise_map.noise_at(Position::new(3, 2)).unwrap().level;
+        let far = noise_map.noise_at(Position::new(5, 2)).unwrap().level;
+        assert!(near > far);
+        assert!(noise_map.noise_at(Position::new(10, 2)).is_none());
+    }
+
+    #[test]
+    fn test_walls_muffle_and_echo_chambers_carry() {
+        let mut walled = floor_map();
+        for y in 0..5 {
+            walled.set_terrain(Position::new(4, y), TerrainType::WALL);
+        }
+        let mut noise_map = NoiseMap::new();
+        noise_map.propagate(&walled, &footstep_at(2, 2), 0);
+        assert!(noise_map.noise_at(Position::new(5, 2)).is_none());
+
+        let mut echoing = floor_map();
+        for x in 0..30 {
+            for y in 0..5 {
+                echoing.set_echo_chamber(Position::new(x, y), true);
+            }
+        }
+        let mut noise_map = NoiseMap::new();
+        noise_map.propagate(&echoing, &footstep_at(2, 2), 0);
+        assert!(noise_map.noise_at(Position::new(8, 2)).is_some());
+    }
+
+    #[test]
+    fn test_own_footsteps_do_not_mask_quieter_noise() {
+        let mut noise_map = NoiseMap::new();
+        let listener = Entity::from_raw(2);
+        noise_map.propagate(&floor_map(), &footstep_at(2, 2), 0);
+        noise_map.propagate(
+            &floor_map(),
+            &NoiseEvent::new(listener, Position::new(4, 2), NoiseKind::Footstep),
+            0,
+        );
+
+        assert_eq!(noise_map.noise_at(Position::new(4, 2)).unwrap().source, listener);
+        let heard = noise_map.noise_from(Position::new(4, 2), Entity::from_raw(1)).unwrap();
+        assert_eq!(heard.origin, Position::new(2, 2));
+    }
+
+    #[test]
+    fn test_noise_fades_over_time() {
+        let mut noise_map = NoiseMap::new();
+        noise_map.propagate(&floor_map(), &footstep_at(2, 2), 0);
+
+        noise_map.fade(500, 1000);
+        assert!(noise_map.noise_at(Position::new(2, 2)).is_some());
+
+        noise_map.fade(1000, 1000);
+        assert!(noise_map.noise_at(Position::new(2, 2)).is_none());
+    }
+}
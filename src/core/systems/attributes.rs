@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::components::{BaseAttributes, FieldOfView, Health, Inventory, StatModifiers, Stats},
+    gameplay::turns::components::TurnActor,
+};
+
+/// System that recomputes derived attributes whenever an entity's stats or modifiers change.
+///
+/// Current health keeps its share of the new maximum, so a vitality boost doesn't count as a
+/// heal and losing one doesn't kill.
+pub fn update_derived_attributes(
+    mut query: Query<
+        (
+            &BaseAttributes,
+            &Stats,
+            Option<&StatModifiers>,
+            Option<&mut Health>,
+            Option<&mut Inventory>,
+            Option<&mut TurnActor>,
+            Option<&mut FieldOfView>,
+        ),
+        Or<(Changed<BaseAttributes>, Changed<Stats>, Changed<StatModifiers>)>,
+    >,
+) {
+    for (base, stats, modifiers, health, inventory, turn_actor, field_of_view) in &mut query {
+        let derived = base.derive(stats, modifiers);
+
+        if let Some(mut health) = health
+            && health.max != derived.max_health
+        {
+            health.set_max_proportional(derived.max_health);
+        }
+        if let Some(mut inventory) = inventory
+            && inventory.max_weight != derived.carry_capacity
+        {
+            inventory.max_weight = derived.carry_capacity;
+        }
+        if let Some(mut turn_actor) = turn_actor
+            && turn_actor.speed() != derived.speed
+        {
+            turn_actor.set_speed(derived.speed);
+        }
+        if let Some(mut field_of_view) = field_of_view
+            && field_of_view.0 != derived.view_radius
+        {
+            field_of_view.0 = derived.view_radius;
+        }
+    }
+}
@@ -1,14 +1,24 @@
 use bevy::prelude::*;
+use big_brain::thinker::HasThinker;
+use brtk::random::Random;
 
 use crate::{
     core::{
-        components::tag::PlayerTag,
+        components::{
+            CORPSE_TINT, Corpse, DeadTag, Description, FloorItem, Inventory, InventoryItem, LootTable,
+            Position, tag::PlayerTag,
+        },
         events::{CombatEvent, DamageDealtEvent, EntityDeathEvent, GameEndReason, GameEnded},
-        resources::CurrentMap,
+        resources::{CurrentMap, FovMap, Map, TurnQueue},
     },
     debug_combat,
+    gameplay::world::generation::GenConfig,
+    rendering::{RenderingConstants, components::TileSprite},
 };
 
+/// Furthest from a body that its belongings land
+const SCATTER_RADIUS: i32 = 2;
+
 /// System that handles combat events for logging and effects
 pub fn handle_combat_events(mut combat_events: EventReader<CombatEvent>) {
     for event in combat_events.read() {
@@ -48,27 +58,181 @@ pub fn handle_damage_events(mut damage_events: EventReader<DamageDealtEvent>) {
     }
 }
 
-/// System that handles entity death events
+/// System that handles entity death events.
+///
+/// Whatever the dead carried falls to the floor around them. The player is removed, and
+/// everything else is left lying on its tile as a [`Corpse`].
 pub fn handle_entity_death(
     mut commands: Commands,
     mut death_events: EventReader<EntityDeathEvent>,
     mut game_end_events: EventWriter<GameEnded>,
     mut map: ResMut<CurrentMap>,
+    mut random: ResMut<Random>,
+    gen_config: Res<GenConfig>,
+    turn_queue: Res<TurnQueue>,
     player_query: Query<Entity, With<PlayerTag>>,
+    remains_query: Query<
+        (&Name, Option<&Inventory>, Option<&LootTable>, Option<&TileSprite>),
+        Without<DeadTag>,
+    >,
 ) {
     for death_event in death_events.read() {
         debug_combat!("Entity {:?} died at position {:?}", death_event.entity, death_event.position);
 
-        // Remove the entity from the map
+        // Free the tile right away; the despawn hooks only run once commands are applied
         map.remove_actor(death_event.entity);
 
+        let Ok((name, inventory, loot_table, tile_sprite)) = remains_query.get(death_event.entity) else {
+            continue;
+        };
+        let is_player = player_query.contains(death_event.entity);
+
+        // What it carried spills onto the floor around it. The player leaves no corpse to search,
+        // so its loot comes out too.
+        let mut drops = inventory.map(carried_items).unwrap_or_default();
+        if is_player && let Some(loot_table) = loot_table {
+            drops.extend(loot_table.roll(gen_config.depth as u32, &mut random));
+        }
+
+        let mut rng = random.to_fastrand();
+        for (item, position) in scatter_drops(&map, death_event.position, drops, &mut rng) {
+            debug_combat!("{} dropped {} x{} at {:?}", name, item.name, item.quantity, position);
+            commands.spawn(floor_item(item, position));
+        }
+
         // Check if the dead entity was the player
-        if player_query.contains(death_event.entity) {
+        if is_player {
             debug_combat!("Player died! Game over.");
             game_end_events.write(GameEnded { reason: GameEndReason::PlayerDeath });
+            commands.entity(death_event.entity).despawn();
+            continue;
+        }
+
+        // Everything else stays behind as a corpse: no turns, no thoughts, nothing blocking the tile
+        let corpse = Corpse::new(name, turn_queue.current_time());
+        debug_combat!("{} left a corpse at {:?}", name, death_event.position);
+
+        // Keep only what a body needs to be seen, named and searched; what it carried already
+        // spilled onto the floor
+        let mut entity_commands = commands.entity(death_event.entity);
+        entity_commands
+            .retain::<(
+                Position,
+                Name,
+                Description,
+                LootTable,
+                TileSprite,
+                Sprite,
+                Transform,
+                GlobalTransform,
+                Visibility,
+                InheritedVisibility,
+                ViewVisibility,
+                // Left for big-brain to notice the missing thinker and clean up after it
+                HasThinker,
+            )>()
+            .insert((
+                DeadTag,
+                Name::new(corpse.name()),
+                Description::new(corpse.name()),
+                // Reinserted so the corpse is redrawn beneath whoever steps on it
+                death_event.position,
+                corpse,
+            ));
+
+        if let Some(tile_sprite) = tile_sprite {
+            entity_commands.insert(tile_sprite.clone().with_tint(CORPSE_TINT));
+        }
+    }
+}
+
+/// An inventory's stacks, in slot order
+fn carried_items(inventory: &Inventory) -> Vec<InventoryItem> {
+    let mut slots: Vec<_> = inventory.items.iter().collect();
+    slots.sort_by_key(|(slot, _)| **slot);
+    slots.into_iter().map(|(_, item)| item.clone()).collect()
+}
+
+/// Pair each dropped stack with the floor tile around `origin` it lands on
+pub fn scatter_drops(
+    map: &Map,
+    origin: Position,
+    drops: Vec<InventoryItem>,
+    rng: &mut fastrand::Rng,
+) -> Vec<(InventoryItem, Position)> {
+    let tiles = scatter_positions(map, origin, drops.len(), rng);
+    drops.into_iter().zip(tiles).collect()
+}
+
+/// Scatter item stacks around `origin` from an action, spawning each where it lands
+pub fn drop_items(world: &mut World, origin: Position, drops: Vec<InventoryItem>) {
+    let mut rng = world.resource_mut::<Random>().to_fastrand();
+    let landed = scatter_drops(world.resource::<CurrentMap>(), origin, drops, &mut rng);
+
+    for (item, position) in landed {
+        world.spawn(floor_item(item, position));
+    }
+}
+
+/// Floor tiles around `origin` for `count` dropped stacks.
+///
+/// Stacks land on the body's own tile first, then spread over open tiles in sight of it, nearest
+/// first. Once every tile has a stack they start to pile up.
+pub fn scatter_positions(
+    map: &Map,
+    origin: Position,
+    count: usize,
+    rng: &mut fastrand::Rng,
+) -> Vec<Position> {
+    let in_sight = FovMap::compute_temporary_fov(origin, SCATTER_RADIUS as u8, map);
+
+    let mut tiles = Vec::new();
+    for dy in -SCATTER_RADIUS..=SCATTER_RADIUS {
+        for dx in -SCATTER_RADIUS..=SCATTER_RADIUS {
+            let tile = Position::new(origin.x() + dx, origin.y() + dy);
+            if map.is_walkable(tile)
+                && (tile == origin || in_sight.get_visible_set().contains(&(tile.x(), tile.y())))
+            {
+                tiles.push(tile);
+            }
         }
+    }
+
+    // Shuffle so each ring fills in a different order, then keep the nearest first
+    rng.shuffle(&mut tiles);
+    tiles.sort_by_key(|tile| (map.get_items(*tile).len(), tile.distance_squared(&origin)));
+
+    if tiles.is_empty() {
+        tiles.push(origin);
+    }
+    tiles.iter().copied().cycle().take(count).collect()
+}
+
+/// An item stack lying on the floor, ready to spawn
+pub fn floor_item(item: InventoryItem, position: Position) -> impl Bundle {
+    (
+        Name::new(item.name.clone()),
+        Description::new(&item.description),
+        TileSprite::new(RenderingConstants::FLOOR_ITEM_SPRITE, Vec2::splat(RenderingConstants::TILE_SIZE)),
+        position,
+        FloorItem(item),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::world::components::TerrainType;
+
+    #[test]
+    fn test_drops_start_on_the_body_and_spread_out() {
+        let map = Map::new_with_terrain((10, 10), TerrainType::FLOOR);
+        let origin = Position::new(5, 5);
+
+        let tiles = scatter_positions(&map, origin, 4, &mut fastrand::Rng::with_seed(9));
 
-        // Despawn the entity
-        commands.entity(death_event.entity).despawn();
+        assert_eq!(tiles[0], origin);
+        assert!(tiles[1..].iter().all(|tile| tile.distance_squared(&origin) <= 2));
+        assert_eq!(tiles.iter().collect::<std::collections::HashSet<_>>().len(), 4);
     }
 }
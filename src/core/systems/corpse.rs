@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use brtk::random::Random;
+
+use crate::{
+    core::{
+        components::{Corpse, LootTable, Position},
+        resources::{CurrentMap, TurnQueue},
+        systems::combat::{floor_item, scatter_drops},
+    },
+    debug_combat,
+    gameplay::world::generation::GenConfig,
+};
+
+/// System that rots away corpses once their time is up.
+///
+/// A corpse nobody searched spills its loot as it goes, so nothing it would have dropped is lost.
+pub fn decay_corpses_system(
+    mut commands: Commands,
+    turn_queue: Res<TurnQueue>,
+    map: Res<CurrentMap>,
+    mut random: ResMut<Random>,
+    gen_config: Res<GenConfig>,
+    mut corpse_query: Query<(Entity, &mut Corpse, &Position, Option<&LootTable>)>,
+) {
+    let now = turn_queue.current_time();
+
+    for (entity, mut corpse, position, loot_table) in &mut corpse_query {
+        if !corpse.is_rotten(now) {
+            continue;
+        }
+
+        debug_combat!("{} rotted away at {:?}", corpse.name(), position);
+
+        let drops = corpse.search(loot_table, gen_config.depth as u32, &mut random);
+        let mut rng = random.to_fastrand();
+        for (item, tile) in scatter_drops(&map, *position, drops, &mut rng) {
+            commands.spawn(floor_item(item, tile));
+        }
+
+        commands.entity(entity).despawn();
+    }
+}
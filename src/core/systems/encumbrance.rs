@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::core::components::{Encumbrance, Equipment, Inventory, PlayerTag};
+
+/// System that works out how weighed down everything is as its load or carry capacity changes.
+///
+/// The player is told whenever their encumbrance changes.
+pub fn update_encumbrance(
+    mut query: Query<
+        (&Inventory, Option<&Equipment>, &mut Encumbrance, Has<PlayerTag>),
+        Or<(Changed<Inventory>, Changed<Equipment>)>,
+    >,
+) {
+    for (inventory, equipment, mut encumbrance, is_player) in &mut query {
+        let new_encumbrance = Encumbrance::for_load(inventory.load(equipment), inventory.max_weight);
+        if *encumbrance == new_encumbrance {
+            continue;
+        }
+
+        if is_player {
+            match new_encumbrance {
+                Encumbrance::Unencumbered => info!("You are no longer encumbered."),
+                Encumbrance::Overloaded => {
+                    info!("You are overloaded and can't move until you lighten your load!")
+                }
+                tier if tier > *encumbrance => info!("You are {} by your load.", tier.label().to_lowercase()),
+                tier => info!("Your load eases; you are only {} now.", tier.label().to_lowercase()),
+            }
+        }
+        *encumbrance = new_encumbrance;
+    }
+}
@@ -1,21 +1,31 @@
 use bevy::prelude::*;
 
 use crate::core::{
-    components::{FieldOfView, PlayerTag, Position},
-    resources::{CurrentMap, FovMap},
+    components::{AITag, FieldOfView, PlayerTag, Position, ViewShed, light::Light},
+    resources::{CurrentMap, FovMap, LightMap, TurnQueue},
 };
 
-/// System that computes FOV for all entities with a ViewShed component
+/// Game time it takes an echo to fade by one step (one standard turn)
+const ECHO_FADE_INTERVAL: u64 = 1000;
+
+/// System that computes FOV for all entities with a ViewShed component.
+///
+/// A player without a light of their own only sees what the [`LightMap`] lights up.
 pub fn compute_fov(
     map: Res<CurrentMap>,
+    light_map: Res<LightMap>,
     mut fov_map: ResMut<FovMap>,
-    query: Query<(&Position, &FieldOfView), With<PlayerTag>>,
+    query: Query<(&Position, &FieldOfView, Has<Light>), With<PlayerTag>>,
 ) {
     match query.single() {
-        Ok((player_pos, view_shed)) => {
+        Ok((player_pos, view_shed, true)) => {
             debug!("Computing FOV for player at {:?}", player_pos);
             fov_map.compute_fov(&map, *player_pos, **view_shed);
         }
+        Ok((player_pos, view_shed, false)) => {
+            debug!("Computing lit FOV for player in the dark at {:?}", player_pos);
+            fov_map.compute_lit_fov(&map, *player_pos, **view_shed, &light_map);
+        }
         Err(bevy::ecs::query::QuerySingleError::NoEntities(_)) => {
             // No player entity found - this is normal during game initialization
             debug!("No player entity found for FOV computation");
@@ -26,3 +36,40 @@ pub fn compute_fov(
         }
     }
 }
+
+/// System that shadowcasts a private view for every AI.
+///
+/// Views are computed in parallel and only for AIs whose position, radius or the map's terrain
+/// changed since their last cast, so a room full of idle monsters costs nothing.
+pub fn compute_view_sheds(
+    current_map: Res<CurrentMap>,
+    mut query: Query<(&Position, &FieldOfView, &mut ViewShed), With<AITag>>,
+) {
+    let map = &**current_map;
+    let terrain_revision = map.terrain_revision();
+
+    query.par_iter_mut().for_each(|(position, field_of_view, mut view_shed)| {
+        if view_shed.is_stale(*position, **field_of_view, terrain_revision) {
+            view_shed.update(map, *position, **field_of_view);
+        }
+    });
+}
+
+/// System that fades echolocation outlines and silhouettes as game time passes, one step per
+/// standard turn
+pub fn fade_echoes(turn_queue: Res<TurnQueue>, mut fov_map: ResMut<FovMap>, mut last_faded: Local<u64>) {
+    let now = turn_queue.current_time();
+    if now < *last_faded {
+        // A new game restarted the clock
+        *last_faded = now;
+        return;
+    }
+
+    let turns = (now - *last_faded) / ECHO_FADE_INTERVAL;
+    if turns > 0 {
+        let turns = turns.min(u8::MAX as u64) as u8;
+        fov_map.fade_echoes(turns);
+        fov_map.fade_silhouettes(turns);
+        *last_faded += turns * ECHO_FADE_INTERVAL;
+    }
+}
@@ -1,8 +1,11 @@
 use bevy::prelude::*;
 
-use crate::core::{
-    components::{Position, light::Light},
-    resources::{CurrentMap, LightMap},
+use crate::{
+    core::{
+        components::{Equipment, FloorItem, Fuel, PlayerTag, Position, TORCH, light::Light},
+        resources::{CurrentMap, LightMap, TurnQueue},
+    },
+    gameplay::world::terrain::with_terrain_registry,
 };
 
 use brtk::fov::{
@@ -11,49 +14,177 @@ use brtk::fov::{
     traits::{FovAlgorithm, FovProvider},
 };
 
+/// Game time it takes a light to burn one turn of fuel (one standard turn)
+const FUEL_BURN_INTERVAL: u64 = 1000;
+
+/// System that burns the fuel of every lit torch as game time passes, whether it's in hand or on
+/// the floor. Lights dim as their fuel runs low and go out when it's spent, taking the torch with
+/// them.
+pub fn burn_fuel(
+    mut commands: Commands,
+    turn_queue: Res<TurnQueue>,
+    mut last_burned: Local<u64>,
+    mut query: Query<(
+        Entity,
+        &mut Fuel,
+        Option<&mut Light>,
+        Option<&mut Equipment>,
+        Has<PlayerTag>,
+        Has<FloorItem>,
+    )>,
+) {
+    let now = turn_queue.current_time();
+    if now < *last_burned {
+        // A new game restarted the clock
+        *last_burned = now;
+        return;
+    }
+
+    let turns = (now - *last_burned) / FUEL_BURN_INTERVAL;
+    if turns == 0 {
+        return;
+    }
+    *last_burned += turns * FUEL_BURN_INTERVAL;
+
+    for (entity, mut fuel, light, equipment, is_player, on_floor) in &mut query {
+        let was_low = fuel.is_low();
+        fuel.burn(turns.min(u32::MAX as u64) as u32);
+
+        if fuel.is_spent() {
+            if on_floor {
+                commands.entity(entity).despawn();
+                continue;
+            }
+
+            commands.entity(entity).remove::<(Fuel, Light)>();
+            if let Some(mut equipment) = equipment {
+                equipment.unequip(TORCH);
+            }
+            if is_player {
+                info!("Your torch gutters out, leaving you in the dark.");
+            }
+            continue;
+        }
+
+        if is_player && fuel.is_low() && !was_low {
+            info!("Your torch is burning low.");
+        }
+        if let Some(mut light) = light
+            && light.range != fuel.range()
+        {
+            light.range = fuel.range();
+        }
+    }
+}
+
+/// Light-emitting terrain tiles, gathered once per map and terrain change rather than every frame
+#[derive(Default)]
+pub struct EmissiveTiles {
+    /// Generation and terrain revision of the map last scanned
+    scanned: Option<(u64, u64)>,
+    lights: Vec<(Position, Light)>,
+}
+
+impl EmissiveTiles {
+    /// Re-scan the map for emissive terrain if it's a new map or any terrain changed since the
+    /// last scan
+    fn refresh(&mut self, map: &CurrentMap) {
+        let scanned = (map.generation(), map.terrain_revision());
+        if self.scanned == Some(scanned) {
+            return;
+        }
+
+        let emissive = with_terrain_registry(|registry| {
+            registry
+                .emissive()
+                .into_iter()
+                .filter_map(|terrain| {
+                    let terrain_light = registry.get(terrain).light.as_ref()?;
+                    let (red, green, blue) = terrain_light.color;
+                    let light = Light {
+                        range: terrain_light.range,
+                        color: Color::linear_rgb(red, green, blue),
+                        falloff: terrain_light.falloff,
+                    };
+                    Some((terrain, light))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        self.lights.clear();
+        if !emissive.is_empty() {
+            for (x, y) in map.tiles.position_iter() {
+                let position = Position::new(x, y);
+                let Some(terrain) = map.get_terrain(position) else {
+                    continue;
+                };
+                if let Some((_, light)) = emissive.iter().find(|(emitter, _)| *emitter == terrain) {
+                    self.lights.push((position, *light));
+                }
+            }
+        }
+        self.scanned = Some(scanned);
+    }
+}
+
 /// System that calculates the light map based on all light sources in the world.
+///
+/// Light comes from entities with a [`Light`] component and from terrain whose definition
+/// declares a light (glowing ores and the like).
 pub fn calculate_light_map(
     mut light_map: ResMut<LightMap>,
     map: Res<CurrentMap>,
+    mut emissive_tiles: Local<EmissiveTiles>,
     query: Query<(&Position, &Light)>,
 ) {
     light_map.clear();
 
     for (light_pos, light) in query.iter() {
-        let mut visibility_map = VisibilityMap::with_range_capacity(light.range);
-        let map_provider = MapProvider::new(&*map, |map_ref, pos, _vision_type| map_ref.is_opaque(pos, 0));
-
-        Shadowcast::compute_fov(
-            (light_pos.x, light_pos.y),
-            0, // vision_type - not used for light calculation, but needed by trait
-            light.range,
-            &map_provider,
-            &mut visibility_map,
+        apply_light(&mut light_map, &map, *light_pos, light);
+    }
+
+    // Emissive terrain
+    emissive_tiles.refresh(&map);
+    for (position, light) in &emissive_tiles.lights {
+        apply_light(&mut light_map, &map, *position, light);
+    }
+}
+
+/// Cast a single light source into the light map
+fn apply_light(light_map: &mut LightMap, map: &CurrentMap, light_pos: Position, light: &Light) {
+    let mut visibility_map = VisibilityMap::with_range_capacity(light.range);
+    let map_provider = MapProvider::new(&**map, |map_ref, pos, _vision_type| map_ref.is_opaque(pos, 0));
+
+    Shadowcast::compute_fov(
+        (light_pos.x, light_pos.y),
+        0, // vision_type - not used for light calculation, but needed by trait
+        light.range,
+        &map_provider,
+        &mut visibility_map,
+    );
+
+    for visible_pos in visibility_map.get_all_visible() {
+        let visible_position = Position::new(visible_pos.0, visible_pos.1);
+        let distance = light_pos.distance(&visible_position);
+        let intensity = (1.0 - (distance / light.range as f32).powf(light.falloff)).max(0.0);
+
+        // Convert color to linear RGB for calculations
+        let light_linear = light.color.to_linear();
+        let multiplied_color = Color::linear_rgb(
+            light_linear.red * intensity,
+            light_linear.green * intensity,
+            light_linear.blue * intensity,
         );
 
-        for visible_pos in visibility_map.get_all_visible() {
-            let visible_position = Position::new(visible_pos.0, visible_pos.1);
-            let distance = light_pos.distance(&visible_position);
-            let intensity = (1.0 - (distance / light.range as f32).powf(light.falloff)).max(0.0);
-
-            // Convert color to linear RGB for calculations
-            let light_linear = light.color.to_linear();
-            let multiplied_color = Color::linear_rgb(
-                light_linear.red * intensity,
-                light_linear.green * intensity,
-                light_linear.blue * intensity,
-            );
-
-            // Combine light from multiple sources: take the brightest color component
-            let existing_linear = light_map.get_light(visible_pos).to_linear();
-            let multiplied_linear = multiplied_color.to_linear();
-            let combined_color = Color::linear_rgb(
-                existing_linear.red.max(multiplied_linear.red),
-                existing_linear.green.max(multiplied_linear.green),
-                existing_linear.blue.max(multiplied_linear.blue),
-            );
-
-            light_map.set_light(visible_pos, combined_color);
-        }
+        // Combine light from multiple sources: take the brightest color component
+        let existing_linear = light_map.get_light(visible_pos).to_linear();
+        let multiplied_linear = multiplied_color.to_linear();
+        let combined_color = Color::linear_rgb(
+            existing_linear.red.max(multiplied_linear.red),
+            existing_linear.green.max(multiplied_linear.green),
+            existing_linear.blue.max(multiplied_linear.blue),
+        );
+
+        light_map.set_light(visible_pos, combined_color);
     }
 }
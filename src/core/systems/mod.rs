@@ -1,8 +1,13 @@
 use bevy::prelude::*;
 
+pub mod attributes;
 pub mod combat;
+pub mod corpse;
+pub mod encumbrance;
 pub mod fov;
 pub mod light;
+pub mod noise;
+pub mod occupancy;
 
 /// Cleanup component for entities that should be removed when the game exits
 #[derive(Component)]
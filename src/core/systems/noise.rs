@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::{
+        events::NoiseEvent,
+        resources::{CurrentMap, NoiseMap, TurnQueue},
+    },
+    debug_world,
+};
+
+/// How long a noise stays in the noise map, in game time (one standard turn)
+pub const NOISE_LIFETIME: u64 = 1000;
+
+/// System that spreads this frame's noises across the map and forgets stale ones
+pub fn propagate_noise(
+    mut noise_events: EventReader<NoiseEvent>,
+    mut noise_map: ResMut<NoiseMap>,
+    current_map: Res<CurrentMap>,
+    turn_queue: Res<TurnQueue>,
+) {
+    let now = turn_queue.current_time();
+    noise_map.fade(now, NOISE_LIFETIME);
+
+    for noise in noise_events.read() {
+        let reached = noise_map.propagate(&current_map, noise, now);
+        debug_world!(
+            "{:?} noise (loudness {}) at {:?} reached {} tiles",
+            noise.kind,
+            noise.loudness,
+            noise.position,
+            reached
+        );
+    }
+}
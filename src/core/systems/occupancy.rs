@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::core::{
+    components::{OccupiesTile, Position},
+    resources::CurrentMap,
+};
+
+/// Debug-build invariant check: the map's actor index must agree with every occupying entity's
+/// `Position`. Mismatches mean something moved or despawned an actor behind the hooks' back.
+pub fn check_actor_index(
+    current_map: Res<CurrentMap>,
+    q_actors: Query<(Entity, &Position), With<OccupiesTile>>,
+) {
+    let mismatches = current_map.actor_index_mismatches(q_actors.iter().map(|(entity, pos)| (entity, *pos)));
+
+    for mismatch in mismatches {
+        error!("Actor index out of sync: {mismatch}");
+    }
+}
@@ -19,6 +19,18 @@ pub const WAIT_TIME: u64 = 1000;
 pub const ATTACK_TIME: u64 = 1000;
 pub const TELEPORT_TIME: u64 = 1000;
 pub const MOVE_DELTA_TIME: u64 = 1000;
+pub const DIG_TIME: u64 = 3000;
+pub const TOGGLE_DOOR_TIME: u64 = 1000;
+pub const PING_TIME: u64 = 1000;
+pub const SPECIAL_ATTACK_TIME: u64 = 1500;
+pub const SEARCH_TIME: u64 = 2000;
+pub const SCAVENGE_TIME: u64 = 3000;
+pub const PICK_UP_TIME: u64 = 1000;
+pub const USE_ITEM_TIME: u64 = 1000;
+pub const LIGHT_TORCH_TIME: u64 = 1000;
+pub const DROP_TORCH_TIME: u64 = 500;
+pub const DESCEND_TIME: u64 = 1000;
+pub const SHOOT_TIME: u64 = 1000;
 
 #[derive(Debug, Reflect, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ActionType {
@@ -26,6 +38,37 @@ pub enum ActionType {
     MoveDelta(Direction),
     Teleport(Position),
     Attack(Position),
+    Dig(Position),
+    ToggleDoor(Position),
+    Ping,
+    /// One of the actor's special attacks, by index, aimed at a position
+    SpecialAttack {
+        index: u8,
+        target: Position,
+    },
+    /// Search a corpse on or next to the actor for loot
+    Search,
+    /// Eat a corpse on or next to the actor
+    Scavenge,
+    /// Pick up what lies on the actor's tile, past its carry capacity if allowed
+    PickUp {
+        allow_overload: bool,
+    },
+    /// Use up one of the items in an inventory slot
+    UseItem {
+        slot: usize,
+    },
+    /// Light a torch from the inventory and take it in hand
+    LightTorch,
+    /// Set the torch in hand down, still burning
+    DropTorch,
+    /// Take the stairs down to the next level
+    Descend,
+    /// Strike a target in sight from up to `range` tiles away
+    Shoot {
+        target: Position,
+        range: u8,
+    },
 }
 
 impl ActionType {
@@ -35,6 +78,40 @@ impl ActionType {
             Self::Attack(_) => ATTACK_TIME,
             Self::Teleport(_) => TELEPORT_TIME,
             Self::MoveDelta(_) => MOVE_DELTA_TIME,
+            Self::Dig(_) => DIG_TIME,
+            Self::ToggleDoor(_) => TOGGLE_DOOR_TIME,
+            Self::Ping => PING_TIME,
+            Self::SpecialAttack { .. } => SPECIAL_ATTACK_TIME,
+            Self::Search => SEARCH_TIME,
+            Self::Scavenge => SCAVENGE_TIME,
+            Self::PickUp { .. } => PICK_UP_TIME,
+            Self::UseItem { .. } => USE_ITEM_TIME,
+            Self::LightTorch => LIGHT_TORCH_TIME,
+            Self::DropTorch => DROP_TORCH_TIME,
+            Self::Descend => DESCEND_TIME,
+            Self::Shoot { .. } => SHOOT_TIME,
+        }
+    }
+
+    /// Name the action goes by in data files
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Wait => "wait",
+            Self::MoveDelta(_) => "move",
+            Self::Teleport(_) => "teleport",
+            Self::Attack(_) => "attack",
+            Self::Dig(_) => "dig",
+            Self::ToggleDoor(_) => "door",
+            Self::Ping => "ping",
+            Self::SpecialAttack { .. } => "special_attack",
+            Self::Search => "search",
+            Self::Scavenge => "scavenge",
+            Self::PickUp { .. } => "pick_up",
+            Self::UseItem { .. } => "use_item",
+            Self::LightTorch => "light_torch",
+            Self::DropTorch => "drop_torch",
+            Self::Descend => "descend",
+            Self::Shoot { .. } => "shoot",
         }
     }
 
@@ -51,6 +128,26 @@ impl ActionType {
             ActionType::Attack(position) => {
                 Box::new(crate::core::actions::AttackAction::new(entity, position))
             }
+            ActionType::Dig(position) => Box::new(crate::core::actions::DigAction::new(entity, position)),
+            ActionType::ToggleDoor(position) => {
+                Box::new(crate::core::actions::ToggleDoorAction::new(entity, position))
+            }
+            ActionType::Ping => Box::new(crate::core::actions::PingAction::new(entity)),
+            ActionType::SpecialAttack { index, target } => {
+                Box::new(crate::core::actions::SpecialAttackAction::new(entity, index, target))
+            }
+            ActionType::Search => Box::new(crate::core::actions::SearchAction::new(entity)),
+            ActionType::Scavenge => Box::new(crate::core::actions::ScavengeAction::new(entity)),
+            ActionType::PickUp { allow_overload } => {
+                Box::new(crate::core::actions::PickUpAction::new(entity, allow_overload))
+            }
+            ActionType::UseItem { slot } => Box::new(crate::core::actions::UseItemAction::new(entity, slot)),
+            ActionType::LightTorch => Box::new(crate::core::actions::LightTorchAction::new(entity)),
+            ActionType::DropTorch => Box::new(crate::core::actions::DropTorchAction::new(entity)),
+            ActionType::Descend => Box::new(crate::core::actions::DescendAction::new(entity)),
+            ActionType::Shoot { target, range } => {
+                Box::new(crate::core::actions::ShootAction::new(entity, target, range))
+            }
         }
     }
 }
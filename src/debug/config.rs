@@ -21,6 +21,8 @@ pub struct DebugConfig {
     pub console_timestamps: bool,
     /// Whether to use colored output in console
     pub console_colors: bool,
+    /// Depth whose spawn table the debug tools show
+    pub spawn_table_depth: usize,
 }
 
 impl Default for DebugConfig {
@@ -44,6 +46,7 @@ impl Default for DebugConfig {
             max_log_files: 5,
             console_colors: true,
             console_timestamps: true,
+            spawn_table_depth: 1,
             log_file_path: PathBuf::from("logs"),
             max_log_file_size: 10 * 1024 * 1024, // 10MB
             file_logging_enabled: std::env::var("DEBUG_FILE_LOGGING").is_ok(),
@@ -8,8 +8,11 @@ use bevy_inspector_egui::{bevy_egui::EguiContext, egui};
 
 #[cfg(feature = "debug")]
 use crate::{
+    core::components::Position,
     debug::{DebugCategory, DebugConfig, generate_bug_report},
     debug_state_transitions,
+    gameplay::{enemies::components::PatrolRoute, world::spawn_table::SpawnTable},
+    prelude::assets::{EntityDefinition, EntityDefinitions},
 };
 
 /// Logs state transitions into console.
@@ -34,9 +37,13 @@ pub fn unified_debug_ui_system(world: &mut World) {
         Res<bevy::diagnostic::DiagnosticsStore>,
         ResMut<DebugConfig>,
         Query<&mut EguiContext, With<PrimaryWindow>>,
+        Query<(&Name, &Position, &PatrolRoute)>,
+        Option<Res<EntityDefinitions>>,
+        Option<Res<Assets<EntityDefinition>>>,
     )> = SystemState::new(world);
 
-    let (diagnostics, mut debug_config, equi_query) = state.get_mut(world);
+    let (diagnostics, mut debug_config, equi_query, patrol_query, entity_definitions, assets) =
+        state.get_mut(world);
     let Ok(egui_context) = equi_query.single() else {
         return;
     };
@@ -71,6 +78,48 @@ pub fn unified_debug_ui_system(world: &mut World) {
 
         ui.separator();
 
+        // Patrol routes section
+        ui.collapsing("🛡️ Patrol Routes", |ui| {
+            if patrol_query.is_empty() {
+                ui.label("No patrols");
+            }
+
+            for (name, position, route) in patrol_query.iter() {
+                ui.label(format!("{name} at ({}, {})", position.x(), position.y()));
+                for (index, waypoint) in route.waypoints.iter().enumerate() {
+                    let marker = if index == route.next { "➡" } else { "•" };
+                    ui.label(format!("  {marker} ({}, {})", waypoint.x(), waypoint.y()));
+                }
+            }
+        });
+
+        ui.separator();
+
+        // Spawn table section
+        ui.collapsing("🎲 Spawn Table", |ui| {
+            let (Some(entity_definitions), Some(assets)) = (entity_definitions.as_ref(), assets.as_ref())
+            else {
+                ui.label("Entity definitions not loaded");
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.label("Depth:");
+                ui.add(egui::DragValue::new(&mut debug_config.spawn_table_depth).range(1..=50));
+            });
+
+            let table =
+                SpawnTable::from_definitions(debug_config.spawn_table_depth, entity_definitions, assets);
+            let dump = table.describe();
+            ui.monospace(&dump);
+
+            if ui.button("Dump to Log").clicked() {
+                info!("{dump}");
+            }
+        });
+
+        ui.separator();
+
         // Debug logging section
         ui.collapsing("🐛 Debug Logging", |ui| {
             ui.heading("Debug Categories");
@@ -1,7 +1,11 @@
 use bevy::prelude::*;
 use big_brain::prelude::*;
 
-use crate::{core::states::GameState, gameplay::enemies::systems, rendering::screens::ScreenState};
+use crate::{
+    core::{states::GameState, systems::fov::compute_view_sheds},
+    gameplay::{GameplaySystemSet, enemies::systems},
+    rendering::screens::ScreenState,
+};
 
 /// AI plugin that handles big-brain AI behavior
 pub fn plugin(app: &mut App) {
@@ -14,19 +18,37 @@ pub fn plugin(app: &mut App) {
             // Actions
             (
                 systems::attack_player_action_system,
+                systems::special_attack_action_system,
                 systems::chase_player_action_system,
                 systems::flee_from_player_action_system,
                 systems::wander_action_system,
                 systems::idle_action_system,
+                systems::scavenge_action_system,
             )
                 .in_set(BigBrainSet::Actions)
                 .run_if(in_state(GameState::GatherActions))
                 .run_if(in_state(ScreenState::Gameplay)),
+            // Each AI's beat, its own view, its target, its place in the squad, then what it
+            // noticed, feed the scorers
+            (
+                systems::assign_patrol_routes_system,
+                compute_view_sheds,
+                systems::select_target_system,
+                systems::assign_squad_orders_system,
+                systems::update_awareness_system,
+            )
+                .chain()
+                .before(BigBrainSet::Scorers)
+                .run_if(in_state(GameState::GatherActions))
+                .run_if(in_state(ScreenState::Gameplay)),
             // Scorers
             (
                 systems::chase_player_scorer_system,
                 systems::flee_from_player_scorer_system,
                 systems::wander_scorer_system,
+                systems::low_health_scorer_system,
+                systems::special_attack_scorer_system,
+                systems::scavenge_scorer_system,
             )
                 .in_set(BigBrainSet::Scorers)
                 .run_if(in_state(GameState::GatherActions))
@@ -37,4 +59,13 @@ pub fn plugin(app: &mut App) {
             //     .run_if(in_state(ScreenState::Gameplay)),
         ),
     );
+
+    // Damage breeds grudges, which fade with time
+    app.add_systems(
+        Update,
+        (systems::record_grudges_system, systems::decay_grudges_system)
+            .chain()
+            .run_if(in_state(ScreenState::Gameplay))
+            .in_set(GameplaySystemSet::WorldUpdate),
+    );
 }
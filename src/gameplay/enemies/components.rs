@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
 use big_brain::prelude::*;
 use echos_assets::entities::AIBehaviorType;
 
@@ -13,13 +13,31 @@ use crate::core::components::Position;
 pub struct ChasePlayerScorer;
 
 /// Scorer that evaluates if the AI should flee from the player
-#[derive(Component, Debug, Clone, ScorerBuilder)]
-pub struct FleeFromPlayerScorer;
+#[derive(Component, Debug, Clone, Default, ScorerBuilder)]
+pub struct FleeFromPlayerScorer {
+    /// Flee once health falls to this fraction, whatever the behavior type (passive AIs always
+    /// flee)
+    pub health_threshold: Option<f32>,
+}
 
 /// Scorer that evaluates if the AI should wander randomly
 #[derive(Component, Debug, Clone, ScorerBuilder)]
 pub struct WanderScorer;
 
+/// Scorer that fires once the AI's health falls to a fraction of its maximum
+#[derive(Component, Debug, Clone, ScorerBuilder)]
+pub struct LowHealthScorer {
+    pub threshold: f32,
+}
+
+/// Scorer that evaluates if the AI has a special attack worth unleashing on its target
+#[derive(Component, Debug, Clone, ScorerBuilder)]
+pub struct SpecialAttackScorer;
+
+/// Scorer that evaluates if the AI can see a corpse to feed on
+#[derive(Component, Debug, Clone, ScorerBuilder)]
+pub struct ScavengeScorer;
+
 /// Scorer that evaluates if the AI can see the player
 #[derive(Component, Debug, Clone, ScorerBuilder)]
 pub struct PlayerVisibilityScorer;
@@ -42,6 +60,8 @@ pub struct ChasePlayerAction {
     pub(crate) target_when_path_generated: Option<Position>,
     /// AI position when path was generated (for regeneration detection)
     pub(crate) ai_pos_when_path_generated: Option<Position>,
+    /// Spot being checked while searching around the last seen position
+    pub(crate) search_target: Option<Position>,
 }
 
 /// Action for fleeing from the player
@@ -89,6 +109,14 @@ pub struct IdleAction;
 #[derive(Component, Debug, Clone, ActionBuilder, Default)]
 pub struct AttackAction;
 
+/// Action for using the special attack the scorer lined up
+#[derive(Component, Debug, Clone, ActionBuilder, Default)]
+pub struct SpecialAttackAction;
+
+/// Action for walking to the nearest corpse in view and eating it
+#[derive(Component, Debug, Clone, ActionBuilder, Default)]
+pub struct ScavengeAction;
+
 // ============================================================================
 // HELPER COMPONENTS
 // ============================================================================
@@ -149,6 +177,209 @@ impl AIBehavior {
     }
 }
 
+/// The actor an AI is focused on, picked each turn by the targeting system
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AITarget {
+    pub entity: Option<Entity>,
+    pub position: Option<Position>,
+    /// Whether the target is the player, whose detection is governed by [`Awareness`]
+    ///
+    /// [`Awareness`]: crate::core::components::Awareness
+    pub is_player: bool,
+}
+
+impl AITarget {
+    pub fn new(entity: Entity, position: Position, is_player: bool) -> Self {
+        Self { entity: Some(entity), position: Some(position), is_player }
+    }
+
+    /// The target's entity and position, if the AI has one
+    pub fn get(&self) -> Option<(Entity, Position)> { self.entity.zip(self.position) }
+}
+
+/// How an AI fights as part of a group
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SquadRole {
+    /// Closes in to fight next to the target
+    #[default]
+    Melee,
+    /// Keeps `range` tiles between itself and the target
+    Ranged { range: u8 },
+}
+
+impl SquadRole {
+    /// Role for an AI's preferred fighting range (anything beyond adjacent is ranged)
+    pub fn for_range(range: u8) -> Self { if range > 1 { Self::Ranged { range } } else { Self::Melee } }
+}
+
+/// Where the squad wants an AI while it fights alongside others with the same target
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SquadOrders {
+    /// Fight alone, straight at the target
+    #[default]
+    None,
+    /// Close in on this tile next to the target, then attack
+    Attack(Position),
+    /// Stand on this tile at range from the target and shoot from there
+    Keep(Position),
+    /// Wait on this tile until a spot next to the target frees up
+    Hold(Position),
+}
+
+impl SquadOrders {
+    /// Where the AI should head this turn, or `None` if it should stay put
+    pub fn destination(&self, ai_pos: Position, target_pos: Position) -> Option<Position> {
+        match *self {
+            Self::None => Some(target_pos),
+            // Next to the target already: step into it to attack
+            Self::Attack(_) if chebyshev_distance(ai_pos, target_pos) <= 1 => Some(target_pos),
+            Self::Attack(tile) => Some(tile),
+            Self::Keep(tile) | Self::Hold(tile) => (tile != ai_pos).then_some(tile),
+        }
+    }
+}
+
+/// Tiles between two positions when diagonal steps count as one
+pub fn chebyshev_distance(a: Position, b: Position) -> i32 {
+    (a.x() - b.x()).abs().max((a.y() - b.y()).abs())
+}
+
+/// What an AI remembers about its target once it slips out of view
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct AIMemory {
+    /// Where the target was last seen or heard
+    pub last_known_position: Option<Position>,
+    /// Game time of that sighting
+    pub last_seen_time: Option<u64>,
+    /// Game time the AI reached the last known position and began searching around it
+    pub search_started: Option<u64>,
+}
+
+impl AIMemory {
+    /// Record a fresh sighting, ending any search in progress
+    pub fn remember(&mut self, position: Position, time: u64) {
+        self.last_known_position = Some(position);
+        self.last_seen_time = Some(time);
+        self.search_started = None;
+    }
+
+    /// Start searching around the last known position (no-op if already searching)
+    pub fn start_search(&mut self, time: u64) { self.search_started.get_or_insert(time); }
+
+    pub fn is_searching(&self) -> bool { self.search_started.is_some() }
+
+    /// Whether the search has gone on for at least `duration` game time
+    pub fn search_expired(&self, now: u64, duration: u64) -> bool {
+        self.search_started.is_some_and(|started| now.saturating_sub(started) >= duration)
+    }
+
+    /// Give up on the target entirely
+    pub fn forget(&mut self) { *self = Self::default(); }
+}
+
+/// A guard's beat: waypoints walked in a loop, kept on the AI so it can pick up where it left off
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct PatrolRoute {
+    pub waypoints: Vec<Position>,
+    /// Index of the waypoint the AI is heading for
+    pub next: usize,
+}
+
+impl PatrolRoute {
+    pub fn new(waypoints: Vec<Position>) -> Self { Self { waypoints, next: 0 } }
+
+    pub fn is_empty(&self) -> bool { self.waypoints.is_empty() }
+
+    /// Head for the waypoint nearest `position`, as when coming back from a chase.
+    ///
+    /// The current waypoint wins ties, so a route that doubles back on itself keeps its direction.
+    pub fn resume(&mut self, position: Position) {
+        if let Some(nearest) = (0..self.waypoints.len())
+            .min_by_key(|&index| (self.waypoints[index].distance_squared(&position), index != self.next))
+        {
+            self.next = nearest;
+        }
+    }
+
+    /// The waypoint to head for from `position`, moving on once the current one is reached
+    pub fn next_waypoint(&mut self, position: Position) -> Option<Position> {
+        if self.waypoints.get(self.next) == Some(&position) {
+            self.next = (self.next + 1) % self.waypoints.len();
+        }
+        self.waypoints.get(self.next).copied()
+    }
+}
+
+/// Marks an enemy spawned as an elite variant, such as a champion
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct Elite {
+    /// The variant's name prefix
+    pub variant: String,
+}
+
+impl Elite {
+    pub fn new(variant: impl Into<String>) -> Self { Self { variant: variant.into() } }
+}
+
+/// Actors an AI has a score to settle with.
+///
+/// Each grudge lasts until a game time; while any is active a neutral AI behaves as hostile,
+/// reverting to its calm behavior once they have all expired.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Grudges {
+    /// Offender -> game time the grudge expires
+    grudges: HashMap<Entity, u64>,
+    /// Behavior to return to once every grudge has expired
+    calm_behavior: Option<AIBehaviorType>,
+    /// Whether this AI takes up grudges on behalf of allies it sees attacked
+    pub defends_allies: bool,
+}
+
+impl Grudges {
+    pub fn new(defends_allies: bool) -> Self { Self { defends_allies, ..Default::default() } }
+
+    /// Hold a grudge against `offender` until `until`, extending any existing one
+    pub fn hold(&mut self, offender: Entity, until: u64) {
+        let expiry = self.grudges.entry(offender).or_insert(until);
+        *expiry = (*expiry).max(until);
+    }
+
+    /// Whether there is an active grudge against `entity`
+    pub fn holds_against(&self, entity: Entity, now: u64) -> bool {
+        self.grudges.get(&entity).is_some_and(|until| *until > now)
+    }
+
+    /// Whether any grudge is active
+    pub fn is_provoked(&self, now: u64) -> bool { self.grudges.values().any(|until| *until > now) }
+
+    /// Switch a neutral AI to hostile, remembering how to calm it down later
+    pub fn provoke(&mut self, behavior: &mut AIBehavior) {
+        if behavior.behavior_type == AIBehaviorType::Neutral {
+            self.calm_behavior = Some(behavior.behavior_type);
+            behavior.behavior_type = AIBehaviorType::Hostile;
+        }
+    }
+
+    /// Drop expired grudges. Returns true when the last one expired and the AI calmed down.
+    pub fn decay(&mut self, now: u64, behavior: &mut AIBehavior) -> bool {
+        self.grudges.retain(|_, until| *until > now);
+        if !self.grudges.is_empty() {
+            return false;
+        }
+
+        match self.calm_behavior.take() {
+            Some(calm) => {
+                behavior.behavior_type = calm;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 #[derive(Reflect, Component, Default, Clone, Copy)]
 #[reflect(Component)]
 pub struct AIComponent {
@@ -1,7 +1,10 @@
 use brtk::prelude::Direction;
 use fastrand::Rng;
 
-use crate::core::components::Position;
+use crate::core::{
+    components::{Position, ViewShed},
+    resources::{FovMap, Map},
+};
 
 /// Calculate direction from one position to another using simple vector math
 pub fn calculate_direction_to_target(from: &Position, to: &Position) -> Option<Direction> {
@@ -90,6 +93,25 @@ pub fn calculate_direction_away_from_target(from: &Position, away_from: &Positio
     Some(Direction::from_coord((dir_x, dir_y)))
 }
 
+/// Check if an AI can see a target within `range`.
+///
+/// Uses the AI's cached [`ViewShed`] when it is up to date for `ai_pos`, and falls back to a
+/// one-off shadowcast otherwise (e.g. on the frame the AI moved, before its view is recast).
+pub fn can_see_target(
+    view_shed: Option<&ViewShed>,
+    ai_pos: Position,
+    range: u8,
+    target_pos: Position,
+    map: &Map,
+) -> bool {
+    match view_shed {
+        Some(view_shed) if view_shed.origin() == Some(ai_pos) => {
+            view_shed.can_see(target_pos) && ai_pos.fov_range_distance(&target_pos) <= range as f32
+        }
+        _ => FovMap::can_see_entity(ai_pos, range, target_pos, map),
+    }
+}
+
 /// Check if an attacker is in attack range of a victim
 pub fn in_attack_range(attacker_pos: &Position, victim_pos: &Position) -> bool {
     attacker_pos.distance(victim_pos) <= 1.0
@@ -3,13 +3,17 @@ use bevy::prelude::*;
 pub mod ai;
 pub mod components;
 pub mod systems;
+pub mod thinker;
 
 mod helpers;
 
 /// Enemies plugin that handles all enemy/AI functionality
 pub fn plugin(app: &mut App) {
     // Register AI components for reflection
-    app.register_type::<components::AIBehavior>();
+    app.register_type::<components::AIBehavior>()
+        .register_type::<components::AIMemory>()
+        .register_type::<components::PatrolRoute>()
+        .register_type::<components::Elite>();
 
     // Add AI plugin
     app.add_plugins(ai::plugin);
@@ -2,10 +2,13 @@ use bevy::prelude::*;
 use big_brain::prelude::*;
 
 use crate::{
-    core::components::{PlayerTag, Position},
+    core::components::{Awareness, Position},
     debug_ai,
     gameplay::{
-        enemies::{components::AttackAction, helpers},
+        enemies::{
+            components::{AITarget, AttackAction},
+            helpers,
+        },
         turns::components::TurnActor,
     },
 };
@@ -14,18 +17,13 @@ use crate::{
 // ACTION SYSTEMS (Execute the AI's actions)
 // ============================================================================
 
-/// System that handles chasing the player
+/// System that handles attacking the AI's target
 pub fn attack_player_action_system(
-    player_query: Query<&Position, With<PlayerTag>>,
-    mut ai_query: Query<(&Position, &mut TurnActor, &Name)>,
+    mut ai_query: Query<(&Position, &mut TurnActor, &AITarget, &Name, Option<&Awareness>)>,
     mut action_query: Query<(&Actor, &mut ActionState), With<AttackAction>>,
 ) {
-    let Ok(player_pos) = player_query.single() else {
-        return;
-    };
-
     for (Actor(actor_entity), mut action_state) in action_query.iter_mut() {
-        let Ok((ai_pos, mut ai_actor, ai_name)) = ai_query.get_mut(*actor_entity) else {
+        let Ok((ai_pos, mut ai_actor, target, ai_name, awareness)) = ai_query.get_mut(*actor_entity) else {
             warn!("Actor must have required components");
             continue;
         };
@@ -55,15 +53,29 @@ pub fn attack_player_action_system(
             ActionState::Executing => {}
         }
 
-        if try_attack_player(&mut ai_actor, ai_pos, player_pos, ai_name) {
+        let Some(target_pos) = target.position.as_ref() else {
+            debug_ai!("{} has no one to attack", ai_name);
+            *action_state = ActionState::Failure;
+            continue;
+        };
+
+        // Only AIs that are sure of the player commit to an attack; suspicious ones hold and look
+        if target.is_player && awareness.is_some_and(|awareness| !awareness.is_alert()) {
+            debug_ai!("{} is not alert enough to attack", ai_name);
+            ai_actor.queue_wait();
+            *action_state = ActionState::Failure;
+            continue;
+        }
+
+        if try_attack_player(&mut ai_actor, ai_pos, target_pos, ai_name) {
             *action_state = ActionState::Success;
         } else {
             *action_state = ActionState::Failure;
 
-            if let Some(dir) = helpers::calculate_direction_to_target(ai_pos, player_pos) {
+            if let Some(dir) = helpers::calculate_direction_to_target(ai_pos, target_pos) {
                 ai_actor.queue_move_delta(dir);
             } else {
-                warn!("{} is on the same tile as the player; skipping move queue", ai_name);
+                warn!("{} is on the same tile as its target; skipping move queue", ai_name);
             }
         }
     }
@@ -72,23 +84,23 @@ pub fn attack_player_action_system(
 fn try_attack_player(
     ai_actor: &mut TurnActor,
     ai_pos: &Position,
-    player_pos: &Position,
+    target_pos: &Position,
     ai_name: &str,
 ) -> bool {
-    if helpers::in_attack_range(ai_pos, player_pos) {
+    if helpers::in_attack_range(ai_pos, target_pos) {
         // Use tactical direction calculation for smarter diagonal positioning
-        if let Some(direction) = helpers::calculate_tactical_direction_to_target(ai_pos, player_pos) {
+        if let Some(direction) = helpers::calculate_tactical_direction_to_target(ai_pos, target_pos) {
             ai_actor.queue_move_delta(direction);
 
             // Log different behavior based on attack type
-            if helpers::is_diagonal_adjacent(ai_pos, player_pos) {
-                debug_ai!("{} performing diagonal attack on player!", ai_name);
+            if helpers::is_diagonal_adjacent(ai_pos, target_pos) {
+                debug_ai!("{} performing diagonal attack on target!", ai_name);
             } else {
-                debug_ai!("{} moving toward player for tactical attack!", ai_name);
+                debug_ai!("{} moving toward target for tactical attack!", ai_name);
             }
             true
         } else {
-            debug_ai!("{} cannot calculate tactical direction to player!", ai_name);
+            debug_ai!("{} cannot calculate tactical direction to target!", ai_name);
             false
         }
     } else {
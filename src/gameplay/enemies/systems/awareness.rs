@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use brtk::random::Random;
+
+use crate::{
+    core::{
+        components::{Awareness, PlayerTag, Position, Stats, ViewShed, detection_chance},
+        resources::{CurrentMap, LightMap, NoiseMap, TurnQueue},
+    },
+    debug_ai,
+    gameplay::enemies::{components::AIBehavior, helpers},
+};
+
+// ============================================================================
+// AWARENESS SYSTEMS (Decide what the AI has noticed)
+// ============================================================================
+
+/// System that rolls each AI's chance to notice the player, once per game turn.
+///
+/// Sight depends on the AI's own FOV and how brightly the player's tile is lit; hearing depends
+/// on player-made noise reaching the AI. The player's agility makes both harder.
+pub fn update_awareness_system(
+    turn_queue: Res<TurnQueue>,
+    current_map: Res<CurrentMap>,
+    light_map: Res<LightMap>,
+    noise_map: Res<NoiseMap>,
+    mut random: ResMut<Random>,
+    player_query: Query<(Entity, &Position, Option<&Stats>), With<PlayerTag>>,
+    mut ai_query: Query<(&Position, &AIBehavior, &mut Awareness, &Name, Option<&ViewShed>)>,
+) {
+    let Ok((player_entity, player_pos, player_stats)) = player_query.single() else {
+        return;
+    };
+
+    let current_turn = turn_queue.current_time();
+    let brightness = tile_brightness(&light_map, player_pos);
+    let stealth = player_stats.map_or(0, |stats| stats.stealth_bonus());
+
+    for (ai_pos, ai_behavior, mut awareness, ai_name, view_shed) in ai_query.iter_mut() {
+        if awareness.last_checked == Some(current_turn) {
+            continue;
+        }
+        awareness.last_checked = Some(current_turn);
+
+        let range = ai_behavior.detection_range;
+        let sight = helpers::can_see_target(view_shed, *ai_pos, range, *player_pos, &current_map)
+            .then(|| ai_pos.fov_range_distance(player_pos) / range.max(1) as f32);
+
+        let noise_level = noise_map.noise_from(*ai_pos, player_entity).map_or(0.0, |noise| noise.level);
+
+        let chance = detection_chance(sight, brightness, noise_level, stealth);
+        let previous = awareness.level;
+        awareness.observe(chance, random.f32(0.0..1.0));
+
+        if awareness.level != previous {
+            debug_ai!("{} awareness {:?} -> {:?} (chance {:.2})", ai_name, previous, awareness.level, chance);
+        }
+    }
+}
+
+/// How lit a tile is, from 0.0 (pitch black) to 1.0
+fn tile_brightness(light_map: &LightMap, position: &Position) -> f32 {
+    let light = light_map.get_light((position.x, position.y)).to_linear();
+    light.red.max(light.green).max(light.blue).clamp(0.0, 1.0)
+}
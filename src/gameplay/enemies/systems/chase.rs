@@ -1,50 +1,92 @@
 use bevy::prelude::*;
 use big_brain::prelude::*;
-use brtk::prelude::Direction;
+use brtk::{prelude::Direction, random::Random};
 
 use crate::{
     core::{
-        components::{PlayerTag, Position},
+        components::{Awareness, Movement, Position, ViewShed},
         pathfinding,
-        resources::{CurrentMap, FovMap, TurnQueue},
+        resources::{CurrentMap, FovMap, Map, NoiseMap, TurnQueue},
+        types::ActionType,
     },
     debug_ai,
     gameplay::{
         enemies::{
-            components::{AIBehavior, ChasePlayerAction, ChasePlayerScorer},
+            components::{
+                AIBehavior, AIMemory, AITarget, ChasePlayerAction, ChasePlayerScorer, SquadOrders, SquadRole,
+            },
             helpers,
         },
         turns::components::TurnActor,
     },
-    prelude::assets::AIBehaviorType,
+    prelude::assets::{AIBehaviorType, MovementType},
 };
 
+/// Quietest noise an AI will notice
+const HEARING_THRESHOLD: f32 = 1.0;
+
+/// How long an AI searches around the target's last known position before giving up
+const SEARCH_DURATION: u64 = 5000;
+
+/// How far from the last known position a searching AI will poke around
+const SEARCH_RADIUS: i32 = 3;
+
 // ============================================================================
 // SCORER SYSTEMS (Evaluate what the AI should do)
 // ============================================================================
 
-/// System that scores how much an AI wants to chase the player
+/// System that scores how much an AI wants to chase its target (the nearest hostile)
 pub fn chase_player_scorer_system(
     turn_queue: Res<TurnQueue>,
     current_map: Res<CurrentMap>,
-    player_query: Query<&Position, With<PlayerTag>>,
-    mut ai_query: Query<(&Position, &mut AIBehavior)>,
+    noise_map: Res<NoiseMap>,
+    mut ai_query: Query<(
+        &Position,
+        &mut AIBehavior,
+        &AITarget,
+        Option<&Awareness>,
+        Option<&ViewShed>,
+        Option<&mut AIMemory>,
+    )>,
     mut scorer_query: Query<(&Actor, &mut Score), With<ChasePlayerScorer>>,
 ) {
-    let Ok(player_pos) = player_query.single() else {
-        return;
-    };
-
     let current_turn = turn_queue.current_time();
 
     for (Actor(actor_entity), mut score) in scorer_query.iter_mut() {
-        let Ok((&ai_pos, mut ai_behavior)) = ai_query.get_mut(*actor_entity) else {
+        let Ok((&ai_pos, mut ai_behavior, target, awareness, view_shed, mut memory)) =
+            ai_query.get_mut(*actor_entity)
+        else {
             warn!("Actor must have required components");
             continue;
         };
 
-        let chase_score =
-            calculate_chase_score(&ai_pos, &mut ai_behavior, player_pos, current_turn, &current_map);
+        let Some((target_entity, target_pos)) = target.get() else {
+            score.set(0.0);
+            continue;
+        };
+
+        // An AI that hasn't noticed the player has nothing to chase
+        if target.is_player && awareness.is_some_and(|awareness| awareness.is_unaware()) {
+            score.set(0.0);
+            continue;
+        }
+
+        // Noise the target made that reached this AI
+        let heard_target = noise_map
+            .noise_from(ai_pos, target_entity)
+            .filter(|noise| noise.level >= HEARING_THRESHOLD)
+            .map(|noise| noise.origin);
+
+        let chase_score = calculate_chase_score(
+            &ai_pos,
+            &mut ai_behavior,
+            view_shed,
+            memory.as_deref_mut(),
+            &target_pos,
+            heard_target,
+            current_turn,
+            &current_map,
+        );
 
         score.set(chase_score);
     }
@@ -53,7 +95,10 @@ pub fn chase_player_scorer_system(
 fn calculate_chase_score(
     ai_pos: &Position,
     ai_behavior: &mut AIBehavior,
-    player_pos: &Position,
+    view_shed: Option<&ViewShed>,
+    mut memory: Option<&mut AIMemory>,
+    target_pos: &Position,
+    heard_target: Option<Position>,
     current_turn: u64,
     current_map: &CurrentMap,
 ) -> f32 {
@@ -61,9 +106,28 @@ fn calculate_chase_score(
         return 0.0;
     }
 
-    if FovMap::can_see_entity(*ai_pos, ai_behavior.detection_range, *player_pos, current_map) {
-        calculate_visible_player_score(ai_pos, ai_behavior, player_pos, current_turn)
-    } else if let Some(last_known_pos) = ai_behavior.last_known_player_position {
+    if helpers::can_see_target(view_shed, *ai_pos, ai_behavior.detection_range, *target_pos, current_map) {
+        if let Some(memory) = memory.as_deref_mut() {
+            memory.remember(*target_pos, current_turn);
+        }
+        return calculate_visible_player_score(ai_pos, ai_behavior, target_pos, current_turn);
+    }
+
+    // Out of sight but not out of earshot: investigate where the noise came from
+    if let Some(noise_origin) = heard_target {
+        ai_behavior.update_player_sighting(noise_origin, current_turn);
+        if let Some(memory) = memory.as_deref_mut() {
+            memory.remember(noise_origin, current_turn);
+        }
+    }
+
+    // Once an AI has searched and given up, it has nothing left to go on
+    let last_known_pos = match memory {
+        Some(memory) => memory.last_known_position,
+        None => ai_behavior.last_known_player_position,
+    };
+
+    if let Some(last_known_pos) = last_known_pos {
         calculate_remembered_position_score(ai_pos, ai_behavior, &last_known_pos, current_turn)
     } else {
         0.0
@@ -133,27 +197,58 @@ fn calculate_remembered_position_score(
 // ACTION SYSTEMS (Execute the AI's actions)
 // ============================================================================
 
-/// System that handles chasing the player
+/// System that handles chasing the AI's target
 pub fn chase_player_action_system(
-    player_query: Query<&Position, With<PlayerTag>>,
+    turn_queue: Res<TurnQueue>,
     mut current_map: ResMut<CurrentMap>,
-    mut ai_query: Query<(&Position, &mut TurnActor, &AIBehavior, &Name)>,
+    mut random: ResMut<Random>,
+    mut ai_query: Query<(
+        &Position,
+        &mut TurnActor,
+        &AIBehavior,
+        &AITarget,
+        &Name,
+        Option<&Movement>,
+        Option<&ViewShed>,
+        Option<&mut AIMemory>,
+        Option<&SquadOrders>,
+        Option<&SquadRole>,
+    )>,
     mut action_query: Query<(&Actor, &mut ActionState, &mut ChasePlayerAction)>,
 ) {
-    let Ok(player_pos) = player_query.single() else {
-        return;
-    };
+    let current_turn = turn_queue.current_time();
 
     for (Actor(actor_entity), mut action_state, mut chase_action) in action_query.iter_mut() {
-        let Ok((ai_pos, mut ai_actor, ai_behavior, ai_name)) = ai_query.get_mut(*actor_entity) else {
+        let Ok((
+            ai_pos,
+            mut ai_actor,
+            ai_behavior,
+            target,
+            ai_name,
+            movement,
+            view_shed,
+            mut memory,
+            squad_orders,
+            squad_role,
+        )) = ai_query.get_mut(*actor_entity)
+        else {
             warn!("Actor must have required components");
             continue;
         };
+        let movement_type = Movement::type_of(movement);
 
         if ai_actor.has_action() {
             continue;
         }
 
+        let Some(target_pos) = target.position else {
+            if matches!(*action_state, ActionState::Init | ActionState::Requested | ActionState::Executing) {
+                debug_ai!("{} has no one to chase", ai_name);
+                *action_state = ActionState::Failure;
+            }
+            continue;
+        };
+
         match *action_state {
             ActionState::Success | ActionState::Failure => {
                 debug_ai!("{} chase state: {:?}", ai_name, action_state);
@@ -168,8 +263,66 @@ pub fn chase_player_action_system(
                 debug_ai!("{} gonna start chasing!", ai_name);
                 *action_state = ActionState::Executing;
 
+                // Seed the chase with whatever the AI remembers of its target
+                if let Some(remembered) = memory.as_ref().and_then(|memory| memory.last_known_position) {
+                    chase_action.last_seen_pt = Some(remembered);
+                }
+
+                let Some(target_position) = determine_chase_target(
+                    &mut chase_action,
+                    *ai_pos,
+                    target_pos,
+                    ai_behavior,
+                    view_shed,
+                    memory.as_deref_mut(),
+                    current_turn,
+                    movement_type,
+                    &mut current_map,
+                    &mut random,
+                    ai_name,
+                ) else {
+                    debug_ai!("{} has nowhere to chase", ai_name);
+                    *action_state = ActionState::Failure;
+                    continue;
+                };
+
+                if let Some(shot) = shot_from_post(
+                    squad_orders,
+                    squad_role,
+                    *ai_pos,
+                    target_pos,
+                    target_position,
+                    &current_map,
+                ) {
+                    debug_ai!("{} fires from its post at {:?}", ai_name, target_pos);
+                    ai_actor.queue_action(shot);
+                    *action_state = ActionState::Success;
+                    continue;
+                }
+
+                let Some(target_position) = follow_squad_orders(
+                    &mut chase_action,
+                    squad_orders,
+                    *ai_pos,
+                    target_pos,
+                    target_position,
+                    movement_type,
+                    &mut current_map,
+                    ai_name,
+                ) else {
+                    ai_actor.queue_wait();
+                    continue;
+                };
+
                 // Generate initial path
-                if generate_chase_path(&mut chase_action, *ai_pos, *player_pos, &mut current_map, ai_name) {
+                if generate_chase_path(
+                    &mut chase_action,
+                    *ai_pos,
+                    target_position,
+                    movement_type,
+                    &mut current_map,
+                    ai_name,
+                ) {
                     // Use first step of path for immediate movement
                     if let Some(&next_pos) = chase_action.current_path.get(1) {
                         let direction = helpers::calculate_direction_to_target(ai_pos, &next_pos);
@@ -192,9 +345,9 @@ pub fn chase_player_action_system(
                     // Fallback to tactical direction calculation
                     debug_ai!("{} using tactical direction fallback", ai_name);
 
-                    let direction = helpers::calculate_tactical_direction_to_target(ai_pos, player_pos);
+                    let direction = helpers::calculate_tactical_direction_to_target(ai_pos, &target_position);
                     if let Some(dir) = direction {
-                        execute_chase_movement(&mut ai_actor, dir, *player_pos, ai_name);
+                        execute_chase_movement(&mut ai_actor, dir, target_position, ai_name);
                     } else {
                         debug_ai!("AI entity {:?} cannot find path to player, action failed", actor_entity);
                         *action_state = ActionState::Failure;
@@ -204,31 +357,81 @@ pub fn chase_player_action_system(
             ActionState::Executing => {
                 debug_ai!("{} executing chase!", ai_name);
 
-                // Check if we need to regenerate the path
-                if should_regenerate_chase_path(&chase_action, *ai_pos, *player_pos, &current_map) {
-                    debug_ai!("{} regenerating A* path due to changed conditions", ai_name);
-                    generate_chase_path(&mut chase_action, *ai_pos, *player_pos, &mut current_map, ai_name);
-                }
-
-                // Determine target position based on visibility and path availability
+                // Determine target position based on visibility, memory and path availability
                 let Some(target_position) = determine_chase_target(
                     &mut chase_action,
                     *ai_pos,
-                    *player_pos,
+                    target_pos,
                     ai_behavior,
+                    view_shed,
+                    memory.as_deref_mut(),
+                    current_turn,
+                    movement_type,
                     &mut current_map,
+                    &mut random,
                     ai_name,
                 ) else {
                     *action_state = ActionState::Failure;
                     continue;
                 };
 
+                if let Some(shot) = shot_from_post(
+                    squad_orders,
+                    squad_role,
+                    *ai_pos,
+                    target_pos,
+                    target_position,
+                    &current_map,
+                ) {
+                    debug_ai!("{} fires from its post at {:?}", ai_name, target_pos);
+                    ai_actor.queue_action(shot);
+                    *action_state = ActionState::Success;
+                    continue;
+                }
+
+                let Some(target_position) = follow_squad_orders(
+                    &mut chase_action,
+                    squad_orders,
+                    *ai_pos,
+                    target_pos,
+                    target_position,
+                    movement_type,
+                    &mut current_map,
+                    ai_name,
+                ) else {
+                    ai_actor.queue_wait();
+                    continue;
+                };
+
+                // Check if we need to regenerate the path
+                if should_regenerate_chase_path(
+                    &chase_action,
+                    *ai_pos,
+                    target_position,
+                    movement_type,
+                    &current_map,
+                ) {
+                    debug_ai!("{} regenerating A* path due to changed conditions", ai_name);
+                    generate_chase_path(
+                        &mut chase_action,
+                        *ai_pos,
+                        target_position,
+                        movement_type,
+                        &mut current_map,
+                        ai_name,
+                    );
+                }
+
                 // Always attempt to move toward target - bump-to-attack will handle occupied spaces
 
                 // Get next movement direction
-                let Some(direction) =
-                    get_next_chase_direction(&mut chase_action, *ai_pos, target_position, &current_map)
-                else {
+                let Some(direction) = get_next_chase_direction(
+                    &mut chase_action,
+                    *ai_pos,
+                    target_position,
+                    movement_type,
+                    &current_map,
+                ) else {
                     debug_ai!("AI entity {:?} cannot find path to player, action failed", actor_entity);
                     *action_state = ActionState::Failure;
                     continue;
@@ -256,29 +459,45 @@ fn execute_chase_movement(
     debug_ai!("{} chasing: moving {:?} towards {:?}", ai_name, direction, target_position);
 }
 
-/// Determine the target position for chasing based on visibility and stored paths
+/// Determine the target position for chasing based on visibility and stored paths.
+///
+/// A visible target is chased directly. Once out of sight the AI heads for the last known
+/// position from its [`AIMemory`], searches around it for [`SEARCH_DURATION`], then gives up
+/// and forgets the target. AIs without a memory give up as soon as they reach the spot.
 fn determine_chase_target(
     chase_action: &mut ChasePlayerAction,
     ai_pos: Position,
-    player_pos: Position,
+    target_pos: Position,
     ai_behavior: &AIBehavior,
+    view_shed: Option<&ViewShed>,
+    memory: Option<&mut AIMemory>,
+    current_turn: u64,
+    movement_type: MovementType,
     current_map: &mut CurrentMap,
+    random: &mut Random,
     ai_name: &str,
 ) -> Option<Position> {
-    let player_visible = FovMap::can_see_entity(ai_pos, ai_behavior.detection_range, player_pos, current_map);
+    let target_visible =
+        helpers::can_see_target(view_shed, ai_pos, ai_behavior.detection_range, target_pos, current_map);
 
-    if player_visible {
-        // Player is visible - update last seen position and potentially regenerate path
-        chase_action.last_seen_pt = Some(player_pos);
+    if target_visible {
+        // Target is visible - update last seen position and potentially regenerate path
+        chase_action.last_seen_pt = Some(target_pos);
+        chase_action.search_target = None;
+        if let Some(memory) = memory {
+            memory.remember(target_pos, current_turn);
+        }
 
         // If we don't have a current path or it's to a different target, regenerate
-        if !chase_action.generated_path || chase_action.target_when_path_generated != Some(player_pos) {
-            generate_chase_path(chase_action, ai_pos, player_pos, current_map, ai_name);
+        if !chase_action.generated_path || chase_action.target_when_path_generated != Some(target_pos) {
+            generate_chase_path(chase_action, ai_pos, target_pos, movement_type, current_map, ai_name);
         }
 
-        Some(player_pos)
-    } else {
-        // Player not visible - use last known position
+        return Some(target_pos);
+    }
+
+    // Target not visible - use last known position
+    let Some(memory) = memory else {
         let last_seen = chase_action.last_seen_pt?;
 
         // Check if we've reached the last seen position
@@ -286,15 +505,118 @@ fn determine_chase_target(
             return None; // Signal failure
         }
 
-        Some(last_seen)
+        return Some(last_seen);
+    };
+
+    // A fresh noise may have moved the last known position since the chase started
+    if let Some(remembered) = memory.last_known_position {
+        chase_action.last_seen_pt = Some(remembered);
+    }
+    let last_seen = chase_action.last_seen_pt?;
+
+    if !memory.is_searching() {
+        if last_seen != ai_pos {
+            return Some(last_seen);
+        }
+
+        debug_ai!("{} reached {:?} and starts searching", ai_name, last_seen);
+        memory.start_search(current_turn);
+    }
+
+    if memory.search_expired(current_turn, SEARCH_DURATION) {
+        debug_ai!("{} gave up searching for its target", ai_name);
+        memory.forget();
+        chase_action.last_seen_pt = None;
+        chase_action.search_target = None;
+        return None;
+    }
+
+    // Poke around near where the target vanished, picking a new spot each time one is reached
+    if chase_action.search_target.is_none_or(|spot| spot == ai_pos) {
+        chase_action.search_target = pick_search_spot(last_seen, ai_pos, movement_type, current_map, random);
     }
+
+    chase_action.search_target
+}
+
+/// The shot a ranged squad member takes once it holds its post with the target in sight
+fn shot_from_post(
+    squad_orders: Option<&SquadOrders>,
+    squad_role: Option<&SquadRole>,
+    ai_pos: Position,
+    target_pos: Position,
+    destination: Position,
+    map: &Map,
+) -> Option<ActionType> {
+    let (Some(SquadOrders::Keep(post)), Some(SquadRole::Ranged { range })) = (squad_orders, squad_role)
+    else {
+        return None;
+    };
+
+    (destination == target_pos && ai_pos == *post && FovMap::can_see_entity(ai_pos, *range, target_pos, map))
+        .then_some(ActionType::Shoot { target: target_pos, range: *range })
+}
+
+/// Swap the target's position for the spot the squad assigned while the target is in sight.
+///
+/// Returns `None` when the AI should hold where it is this turn.
+fn follow_squad_orders(
+    chase_action: &mut ChasePlayerAction,
+    squad_orders: Option<&SquadOrders>,
+    ai_pos: Position,
+    target_pos: Position,
+    destination: Position,
+    movement_type: MovementType,
+    current_map: &mut CurrentMap,
+    ai_name: &str,
+) -> Option<Position> {
+    // Searching and remembered positions are the AI's own business
+    let Some(orders) = squad_orders.filter(|_| destination == target_pos) else {
+        return Some(destination);
+    };
+
+    let Some(spot) = orders.destination(ai_pos, target_pos) else {
+        debug_ai!("{} holds position for its squad", ai_name);
+        return None;
+    };
+
+    if spot != target_pos && chase_action.target_when_path_generated != Some(spot) {
+        // Path to the spot without forgetting where the target actually is
+        let last_seen = chase_action.last_seen_pt;
+        generate_chase_path(chase_action, ai_pos, spot, movement_type, current_map, ai_name);
+        chase_action.last_seen_pt = last_seen;
+    }
+
+    Some(spot)
+}
+
+/// Pick a random reachable tile near `center` for a searching AI to check
+fn pick_search_spot(
+    center: Position,
+    ai_pos: Position,
+    movement_type: MovementType,
+    map: &CurrentMap,
+    random: &mut Random,
+) -> Option<Position> {
+    let mut spots = Vec::new();
+    for dy in -SEARCH_RADIUS..=SEARCH_RADIUS {
+        for dx in -SEARCH_RADIUS..=SEARCH_RADIUS {
+            let spot = Position::new(center.x() + dx, center.y() + dy);
+            if spot != ai_pos && map.is_passable(spot, movement_type) && map.get_actor(spot).is_none() {
+                spots.push(spot);
+            }
+        }
+    }
+
+    if spots.is_empty() { None } else { Some(spots[random.usize(0..spots.len())]) }
 }
 
 /// Check if the chase path should be regenerated based on current conditions
 fn should_regenerate_chase_path(
     chase_action: &ChasePlayerAction,
     current_ai_pos: Position,
-    current_player_pos: Position,
+    current_target_pos: Position,
+    movement_type: MovementType,
     map: &CurrentMap,
 ) -> bool {
     // No path exists
@@ -302,10 +624,10 @@ fn should_regenerate_chase_path(
         return true;
     }
 
-    // Player moved significantly from when path was generated
+    // Target moved significantly from when path was generated
     if let Some(old_target) = chase_action.target_when_path_generated {
-        let player_moved_distance = old_target.distance(&current_player_pos);
-        if player_moved_distance > 2.0 {
+        let target_moved_distance = old_target.distance(&current_target_pos);
+        if target_moved_distance > 2.0 {
             return true;
         }
     }
@@ -321,7 +643,7 @@ fn should_regenerate_chase_path(
     // Skip walkability check for the final step – bump-to-attack will resolve it
     if let Some(next_pos) = chase_action.current_path.get(chase_action.path_index + 1) {
         let is_final_step = chase_action.path_index + 2 >= chase_action.current_path.len();
-        if !is_final_step && !map.is_walkable(*next_pos) {
+        if !is_final_step && !map.is_passable(*next_pos, movement_type) {
             return true;
         }
     }
@@ -339,10 +661,13 @@ fn generate_chase_path(
     chase_action: &mut ChasePlayerAction,
     ai_pos: Position,
     target_pos: Position,
+    movement_type: MovementType,
     current_map: &mut CurrentMap,
     ai_name: &str,
 ) -> bool {
-    if let Some(path) = pathfinding::utils::find_path(ai_pos, target_pos, current_map, true) {
+    if let Some(path) =
+        pathfinding::utils::find_path_for(ai_pos, target_pos, current_map, movement_type, true)
+    {
         debug_ai!("{} generated A* path with {} steps", ai_name, path.len());
 
         chase_action.current_path = path;
@@ -368,10 +693,11 @@ fn get_next_chase_direction(
     chase_action: &mut ChasePlayerAction,
     ai_pos: Position,
     target_position: Position,
+    movement_type: MovementType,
     current_map: &CurrentMap,
 ) -> Option<Direction> {
     if chase_action.generated_path && !chase_action.current_path.is_empty() {
-        follow_stored_path(chase_action, ai_pos, current_map)
+        follow_stored_path(chase_action, ai_pos, movement_type, current_map)
     } else {
         helpers::calculate_tactical_direction_to_target(&ai_pos, &target_position)
     }
@@ -381,6 +707,7 @@ fn get_next_chase_direction(
 fn follow_stored_path(
     chase_action: &mut ChasePlayerAction,
     current_ai_pos: Position,
+    movement_type: MovementType,
     map: &CurrentMap,
 ) -> Option<Direction> {
     // Ensure we have a valid path
@@ -408,8 +735,8 @@ fn follow_stored_path(
     // Get the next step in the path
     let next_index = current_path_index + 1;
     if let Some(next_pos) = chase_action.current_path.get(next_index) {
-        // Check if the next position is walkable
-        if !map.is_walkable(*next_pos) {
+        // Check if the next position is passable for this mover
+        if !map.is_passable(*next_pos, movement_type) {
             // Path is blocked, need to regenerate
             return None;
         }
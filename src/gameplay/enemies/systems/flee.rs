@@ -4,63 +4,103 @@ use brtk::prelude::Direction;
 
 use crate::{
     core::{
-        components::{PlayerTag, Position},
+        components::{Awareness, Health, Movement, Position, ViewShed},
         pathfinding,
-        resources::{CurrentMap, FovMap, TurnQueue},
+        resources::{CurrentMap, TurnQueue},
     },
     debug_ai,
     gameplay::{
         enemies::{
-            components::{AIBehavior, FleeFromPlayerAction, FleeFromPlayerScorer},
+            components::{AIBehavior, AITarget, FleeFromPlayerAction, FleeFromPlayerScorer, LowHealthScorer},
             helpers,
         },
         turns::components::TurnActor,
     },
-    prelude::assets::AIBehaviorType,
+    prelude::assets::{AIBehaviorType, MovementType},
 };
 
-/// System that scores how much an AI wants to flee from the player
+/// System that scores how much an AI wants to flee from its target (the nearest hostile)
 pub fn flee_from_player_scorer_system(
     turn_queue: Res<TurnQueue>,
     current_map: Res<CurrentMap>,
-    player_query: Query<&Position, With<PlayerTag>>,
-    mut ai_query: Query<(&Position, &mut AIBehavior)>,
-    mut scorer_query: Query<(&Actor, &mut Score), With<FleeFromPlayerScorer>>,
+    mut ai_query: Query<(
+        &Position,
+        &mut AIBehavior,
+        &AITarget,
+        Option<&Awareness>,
+        Option<&ViewShed>,
+        Option<&Health>,
+    )>,
+    mut scorer_query: Query<(&Actor, &mut Score, &FleeFromPlayerScorer)>,
 ) {
-    let Ok(player_pos) = player_query.single() else {
-        return;
-    };
-
     let current_turn = turn_queue.current_time();
 
-    for (Actor(actor_entity), mut score) in scorer_query.iter_mut() {
-        let Ok((&ai_pos, mut ai_behavior)) = ai_query.get_mut(*actor_entity) else {
+    for (Actor(actor_entity), mut score, scorer) in scorer_query.iter_mut() {
+        let Ok((&ai_pos, mut ai_behavior, target, awareness, view_shed, health)) =
+            ai_query.get_mut(*actor_entity)
+        else {
             warn!("Actor must have required components");
             continue;
         };
 
-        let flee_score =
-            calculate_flee_score(&ai_pos, &mut ai_behavior, player_pos, current_turn, &current_map);
+        let Some(threat_pos) = target.position else {
+            score.set(0.0);
+            continue;
+        };
+
+        // Nothing to flee from until the AI has noticed the player
+        if target.is_player && awareness.is_some_and(|awareness| awareness.is_unaware()) {
+            score.set(0.0);
+            continue;
+        }
+
+        // With a health threshold anyone flees once hurt badly enough; otherwise only passive AIs do
+        let wants_to_flee = match scorer.health_threshold {
+            Some(threshold) => health.is_some_and(|health| health.percentage() <= threshold),
+            None => ai_behavior.behavior_type == AIBehaviorType::Passive,
+        };
+        if !wants_to_flee {
+            score.set(0.0);
+            continue;
+        }
+
+        let flee_score = calculate_flee_score(
+            &ai_pos,
+            &mut ai_behavior,
+            view_shed,
+            &threat_pos,
+            current_turn,
+            &current_map,
+        );
 
         score.set(flee_score);
     }
 }
 
+/// System that scores 1 for AIs whose health has fallen to their scorer's threshold
+pub fn low_health_scorer_system(
+    health_query: Query<&Health>,
+    mut scorer_query: Query<(&Actor, &mut Score, &LowHealthScorer)>,
+) {
+    for (Actor(actor_entity), mut score, scorer) in scorer_query.iter_mut() {
+        let hurt =
+            health_query.get(*actor_entity).is_ok_and(|health| health.percentage() <= scorer.threshold);
+        score.set(if hurt { 1.0 } else { 0.0 });
+    }
+}
+
 fn calculate_flee_score(
     ai_pos: &Position,
     ai_behavior: &mut AIBehavior,
-    player_pos: &Position,
+    view_shed: Option<&ViewShed>,
+    threat_pos: &Position,
     current_turn: u64,
     current_map: &CurrentMap,
 ) -> f32 {
-    if ai_behavior.behavior_type != AIBehaviorType::Passive {
-        return 0.0;
-    }
-
-    if FovMap::can_see_entity(*ai_pos, ai_behavior.detection_range, *player_pos, current_map) {
-        let distance = ai_pos.ai_detection_distance(player_pos);
+    if helpers::can_see_target(view_shed, *ai_pos, ai_behavior.detection_range, *threat_pos, current_map) {
+        let distance = ai_pos.ai_detection_distance(threat_pos);
         if distance <= ai_behavior.detection_range as f32 {
-            ai_behavior.update_player_sighting(*player_pos, current_turn);
+            ai_behavior.update_player_sighting(*threat_pos, current_turn);
 
             // Base flee score starts high when threat is visible
             let mut flee_score = 0.8;
@@ -92,31 +132,37 @@ fn calculate_flee_score(
             0.0
         }
     } else {
-        0.0 // Don't flee if player is not visible
+        0.0 // Don't flee if the threat is not visible
     }
 }
 
-/// System that handles fleeing from the player
+/// System that handles fleeing from the AI's target
 pub fn flee_from_player_action_system(
-    player_query: Query<&Position, With<PlayerTag>>,
     mut current_map: ResMut<CurrentMap>,
     mut action_query: Query<(&Actor, &mut ActionState, &mut FleeFromPlayerAction)>,
-    mut ai_query: Query<(&Position, &mut TurnActor, &AIBehavior, &Name)>,
+    mut ai_query: Query<(&Position, &mut TurnActor, &AIBehavior, &AITarget, &Name, Option<&Movement>)>,
 ) {
-    let Ok(player_pos) = player_query.single() else {
-        return;
-    };
-
     for (Actor(actor_entity), mut action_state, mut flee_action) in action_query.iter_mut() {
-        let Ok((ai_pos, mut ai_actor, _ai_behavior, ai_name)) = ai_query.get_mut(*actor_entity) else {
+        let Ok((ai_pos, mut ai_actor, _ai_behavior, target, ai_name, movement)) =
+            ai_query.get_mut(*actor_entity)
+        else {
             warn!("Actor must have required components");
             continue;
         };
+        let movement_type = Movement::type_of(movement);
 
         if ai_actor.has_action() {
             continue;
         }
 
+        let Some(threat_pos) = target.position.as_ref() else {
+            if matches!(*action_state, ActionState::Init | ActionState::Requested | ActionState::Executing) {
+                debug_ai!("{} has nothing left to flee from", ai_name);
+                *action_state = ActionState::Failure;
+            }
+            continue;
+        };
+
         match *action_state {
             ActionState::Success | ActionState::Failure => {
                 debug_ai!("{} flee state: {:?}", ai_name, action_state);
@@ -132,17 +178,21 @@ pub fn flee_from_player_action_system(
                 *action_state = ActionState::Executing;
 
                 // Generate escape path using enhanced pathfinding
-                if let Some(escape_target) = find_escape_destination(*ai_pos, *player_pos, &current_map) {
-                    if let Some(path) =
-                        pathfinding::utils::find_path(*ai_pos, escape_target, &mut current_map, true)
-                    {
+                if let Some(escape_target) = find_escape_destination(*ai_pos, *threat_pos, &current_map) {
+                    if let Some(path) = pathfinding::utils::find_path_for(
+                        *ai_pos,
+                        escape_target,
+                        &mut current_map,
+                        movement_type,
+                        true,
+                    ) {
                         debug_ai!("{} generated A* escape path with {} steps", ai_name, path.len());
 
                         // Store the complete escape path and tracking information
                         flee_action.escape_path = path;
                         flee_action.path_index = 0;
                         flee_action.escape_target = Some(escape_target);
-                        flee_action.threat_pos_when_path_generated = Some(*player_pos);
+                        flee_action.threat_pos_when_path_generated = Some(*threat_pos);
                         flee_action.ai_pos_when_path_generated = Some(*ai_pos);
 
                         // Use first step of path for immediate movement
@@ -169,8 +219,8 @@ pub fn flee_from_player_action_system(
                             "{} A* pathfinding to escape destination failed, using simple flee",
                             ai_name
                         );
-                        // Fallback to simple direction calculation away from player
-                        let direction = helpers::calculate_direction_away_from_target(ai_pos, player_pos);
+                        // Fallback to simple direction calculation away from the threat
+                        let direction = helpers::calculate_direction_away_from_target(ai_pos, threat_pos);
                         if let Some(dir) = direction {
                             execute_flee_movement(&mut ai_actor, dir, escape_target, ai_name);
                         } else {
@@ -190,19 +240,29 @@ pub fn flee_from_player_action_system(
                 debug_ai!("{} executing flee!", ai_name);
 
                 // Check if we need to regenerate the escape path
-                if should_regenerate_escape_path(&flee_action, *ai_pos, *player_pos, &current_map) {
+                if should_regenerate_escape_path(
+                    &flee_action,
+                    *ai_pos,
+                    *threat_pos,
+                    movement_type,
+                    &current_map,
+                ) {
                     debug_ai!("{} regenerating A* escape path due to changed conditions", ai_name);
 
                     if let Some(new_escape_target) =
-                        find_escape_destination(*ai_pos, *player_pos, &current_map)
+                        find_escape_destination(*ai_pos, *threat_pos, &current_map)
                     {
-                        if let Some(path) =
-                            pathfinding::utils::find_path(*ai_pos, new_escape_target, &mut current_map, true)
-                        {
+                        if let Some(path) = pathfinding::utils::find_path_for(
+                            *ai_pos,
+                            new_escape_target,
+                            &mut current_map,
+                            movement_type,
+                            true,
+                        ) {
                             flee_action.escape_path = path;
                             flee_action.path_index = 0;
                             flee_action.escape_target = Some(new_escape_target);
-                            flee_action.threat_pos_when_path_generated = Some(*player_pos);
+                            flee_action.threat_pos_when_path_generated = Some(*threat_pos);
                             flee_action.ai_pos_when_path_generated = Some(*ai_pos);
 
                             debug_ai!(
@@ -222,10 +282,10 @@ pub fn flee_from_player_action_system(
 
                 // Follow the stored A* path or calculate next move
                 let next_move_result = if !flee_action.escape_path.is_empty() {
-                    follow_stored_escape_path(&mut flee_action, *ai_pos, &current_map)
+                    follow_stored_escape_path(&mut flee_action, *ai_pos, movement_type, &current_map)
                 } else {
-                    // Fallback to simple direction calculation away from player
-                    helpers::calculate_direction_away_from_target(ai_pos, player_pos)
+                    // Fallback to simple direction calculation away from the threat
+                    helpers::calculate_direction_away_from_target(ai_pos, threat_pos)
                 };
 
                 // Execute the movement
@@ -316,6 +376,7 @@ fn should_regenerate_escape_path(
     flee_action: &FleeFromPlayerAction,
     current_ai_pos: Position,
     current_threat_pos: Position,
+    movement_type: MovementType,
     map: &CurrentMap,
 ) -> bool {
     // No path exists
@@ -343,7 +404,7 @@ fn should_regenerate_escape_path(
 
     // Current path step is blocked
     if let Some(next_pos) = flee_action.escape_path.get(flee_action.path_index + 1)
-        && !map.is_walkable(*next_pos)
+        && !map.is_passable(*next_pos, movement_type)
     {
         return true;
     }
@@ -360,6 +421,7 @@ fn should_regenerate_escape_path(
 fn follow_stored_escape_path(
     flee_action: &mut FleeFromPlayerAction,
     current_ai_pos: Position,
+    movement_type: MovementType,
     map: &CurrentMap,
 ) -> Option<Direction> {
     // Ensure we have a valid path
@@ -387,8 +449,8 @@ fn follow_stored_escape_path(
     // Get the next step in the path
     let next_index = current_path_index + 1;
     if let Some(next_pos) = flee_action.escape_path.get(next_index) {
-        // Check if the next position is walkable
-        if !map.is_walkable(*next_pos) {
+        // Check if the next position is passable for this mover
+        if !map.is_passable(*next_pos, movement_type) {
             // Path is blocked, need to regenerate
             return None;
         }
@@ -1,12 +1,26 @@
 pub mod attack;
+pub mod awareness;
 pub mod chase;
 pub mod flee;
 pub mod idle;
+pub mod patrol;
+pub mod provocation;
+pub mod scavenge;
+pub mod special_attack;
+pub mod squad;
+pub mod targeting;
 pub mod wander;
 
 // Re-export all the systems for easy access
 pub use attack::*;
+pub use awareness::*;
 pub use chase::*;
 pub use flee::*;
 pub use idle::*;
+pub use patrol::*;
+pub use provocation::*;
+pub use scavenge::*;
+pub use special_attack::*;
+pub use squad::*;
+pub use targeting::*;
 pub use wander::*;
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::components::Position,
+    debug_ai,
+    gameplay::{enemies::components::PatrolRoute, world::generation::GenConfig},
+};
+
+/// Rooms on a freshly assigned patrol route
+const PATROL_STOPS: usize = 3;
+
+// ============================================================================
+// PATROL SYSTEMS (Walking the beat)
+// ============================================================================
+
+/// System that hands newly spawned patrollers a route through the rooms around them.
+///
+/// Routes follow the level's patrol graph, room to room along the corridors. Patrollers that
+/// already carry a route keep it.
+pub fn assign_patrol_routes_system(
+    gen_config: Res<GenConfig>,
+    mut ai_query: Query<(&Position, &mut PatrolRoute, &Name), Added<PatrolRoute>>,
+) {
+    for (&position, mut route, name) in ai_query.iter_mut() {
+        if !route.is_empty() {
+            continue;
+        }
+
+        *route = PatrolRoute::new(gen_config.patrol_graph.route_from(position, PATROL_STOPS));
+        debug_ai!("{} patrols {:?}", name, route.waypoints);
+    }
+}
@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::{
+        components::{Faction, PlayerTag, Position, ViewShed},
+        events::DamageDealtEvent,
+        resources::{CurrentMap, TurnQueue},
+    },
+    debug_ai,
+    gameplay::{
+        enemies::{
+            components::{AIBehavior, Grudges},
+            helpers,
+        },
+        factions::FactionRegistry,
+    },
+};
+
+/// How long a grudge lasts after the last provocation (twenty standard turns)
+const GRUDGE_DURATION: u64 = 20_000;
+
+// ============================================================================
+// PROVOCATION SYSTEMS (Who has a score to settle)
+// ============================================================================
+
+/// System that turns damage into grudges against the attacker.
+///
+/// The victim always takes it personally. Allies of the victim who see the attack and are
+/// inclined to defend their own take up the grudge too.
+pub fn record_grudges_system(
+    turn_queue: Res<TurnQueue>,
+    current_map: Res<CurrentMap>,
+    factions: Res<FactionRegistry>,
+    mut damage_events: EventReader<DamageDealtEvent>,
+    faction_query: Query<(Option<&Faction>, Has<PlayerTag>)>,
+    mut ai_query: Query<(
+        Entity,
+        &Position,
+        &mut AIBehavior,
+        &mut Grudges,
+        Option<&Faction>,
+        Option<&ViewShed>,
+        &Name,
+    )>,
+) {
+    let until = turn_queue.current_time().saturating_add(GRUDGE_DURATION);
+    let faction_of =
+        |entity| faction_query.get(entity).ok().map(|(faction, is_player)| Faction::of(faction, is_player));
+
+    for event in damage_events.read() {
+        if event.attacker == event.target {
+            continue;
+        }
+
+        if let Ok((_, _, mut behavior, mut grudges, _, _, name)) = ai_query.get_mut(event.target) {
+            debug_ai!("{} holds a grudge against {:?}", name, event.attacker);
+            grudges.hold(event.attacker, until);
+            grudges.provoke(&mut behavior);
+        }
+
+        let (Some(victim_faction), Some(attacker_faction)) =
+            (faction_of(event.target), faction_of(event.attacker))
+        else {
+            continue;
+        };
+
+        for (entity, position, mut behavior, mut grudges, faction, view_shed, name) in ai_query.iter_mut() {
+            if entity == event.target || entity == event.attacker || !grudges.defends_allies {
+                continue;
+            }
+
+            // Only allies of the victim step in, and never against their own friends
+            let witness_faction = Faction::of(faction, false);
+            if !factions.is_friendly(witness_faction, victim_faction)
+                || factions.is_friendly(witness_faction, attacker_faction)
+            {
+                continue;
+            }
+
+            if !helpers::can_see_target(
+                view_shed,
+                *position,
+                behavior.detection_range,
+                event.position,
+                &current_map,
+            ) {
+                continue;
+            }
+
+            debug_ai!("{} saw an ally attacked and joins the fight against {:?}", name, event.attacker);
+            grudges.hold(event.attacker, until);
+            grudges.provoke(&mut behavior);
+        }
+    }
+}
+
+/// System that lets grudges expire, calming provoked AIs back to their usual behavior
+pub fn decay_grudges_system(
+    turn_queue: Res<TurnQueue>,
+    mut ai_query: Query<(&mut AIBehavior, &mut Grudges, &Name)>,
+) {
+    let now = turn_queue.current_time();
+
+    for (mut behavior, mut grudges, name) in ai_query.iter_mut() {
+        if grudges.decay(now, &mut behavior) {
+            debug_ai!("{} calms down", name);
+        }
+    }
+}
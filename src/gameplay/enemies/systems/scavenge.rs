@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::{
+    core::{
+        components::{Corpse, Movement, Position, ViewShed},
+        pathfinding,
+        resources::CurrentMap,
+        types::ActionType,
+    },
+    debug_ai,
+    gameplay::{
+        enemies::{
+            components::{AITarget, ScavengeAction, ScavengeScorer},
+            helpers,
+        },
+        turns::components::TurnActor,
+    },
+};
+
+/// Score for a corpse in view, enough to beat wandering but not a fight or a flight
+const SCAVENGE_SCORE: f32 = 0.6;
+
+// ============================================================================
+// SCAVENGE SYSTEMS (Nothing goes to waste)
+// ============================================================================
+
+/// The nearest corpse an AI at `ai_pos` can see
+fn nearest_visible_corpse<'a>(
+    ai_pos: Position,
+    view_shed: &ViewShed,
+    corpses: impl Iterator<Item = &'a Position>,
+) -> Option<Position> {
+    corpses
+        .filter(|position| **position == ai_pos || view_shed.can_see(**position))
+        .min_by_key(|position| position.distance_squared(&ai_pos))
+        .copied()
+}
+
+/// System that scores how much an AI wants to feed on a corpse it can see.
+///
+/// An AI with a target has better things to do.
+pub fn scavenge_scorer_system(
+    corpse_query: Query<&Position, With<Corpse>>,
+    ai_query: Query<(&Position, &ViewShed, Option<&AITarget>)>,
+    mut scorer_query: Query<(&Actor, &mut Score), With<ScavengeScorer>>,
+) {
+    for (Actor(actor_entity), mut score) in scorer_query.iter_mut() {
+        let Ok((&ai_pos, view_shed, target)) = ai_query.get(*actor_entity) else {
+            score.set(0.0);
+            continue;
+        };
+
+        let busy = target.is_some_and(|target| target.get().is_some());
+        let corpse_in_view = nearest_visible_corpse(ai_pos, view_shed, corpse_query.iter()).is_some();
+
+        score.set(if corpse_in_view && !busy { SCAVENGE_SCORE } else { 0.0 });
+    }
+}
+
+/// System that walks an AI to the nearest corpse in view and eats it once in reach
+pub fn scavenge_action_system(
+    mut current_map: ResMut<CurrentMap>,
+    corpse_query: Query<&Position, With<Corpse>>,
+    mut ai_query: Query<(&Position, &ViewShed, &mut TurnActor, &Name, Option<&Movement>)>,
+    mut action_query: Query<(&Actor, &mut ActionState), With<ScavengeAction>>,
+) {
+    for (Actor(actor_entity), mut action_state) in action_query.iter_mut() {
+        let Ok((&ai_pos, view_shed, mut ai_actor, ai_name, movement)) = ai_query.get_mut(*actor_entity)
+        else {
+            warn!("Actor must have required components");
+            continue;
+        };
+
+        if ai_actor.has_action() {
+            continue;
+        }
+
+        match *action_state {
+            ActionState::Success | ActionState::Failure => continue,
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+                continue;
+            }
+            ActionState::Init | ActionState::Requested | ActionState::Executing => {}
+        }
+
+        let Some(corpse_pos) = nearest_visible_corpse(ai_pos, view_shed, corpse_query.iter()) else {
+            *action_state = ActionState::Failure;
+            continue;
+        };
+
+        if ai_pos.pathfinding_distance(&corpse_pos) <= 1.0 {
+            debug_ai!("{} feeds on the corpse at {:?}", ai_name, corpse_pos);
+            ai_actor.queue_action(ActionType::Scavenge);
+            *action_state = ActionState::Success;
+            continue;
+        }
+
+        let next_step = pathfinding::utils::find_path_for(
+            ai_pos,
+            corpse_pos,
+            &mut current_map,
+            Movement::type_of(movement),
+            true,
+        )
+        .and_then(|path| path.get(1).copied());
+
+        match next_step.and_then(|step| helpers::calculate_direction_to_target(&ai_pos, &step)) {
+            Some(direction) => {
+                debug_ai!("{} heads {:?} for the corpse at {:?}", ai_name, direction, corpse_pos);
+                ai_actor.queue_move_delta(direction);
+                *action_state = ActionState::Success;
+            }
+            None => *action_state = ActionState::Failure,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{core::resources::Map, gameplay::world::components::TerrainType};
+
+    #[test]
+    fn test_scavenger_picks_nearest_corpse_in_view() {
+        let map = Map::new_with_terrain((20, 20), TerrainType::FLOOR);
+        let ai_pos = Position::new(5, 5);
+        let mut view_shed = ViewShed::default();
+        view_shed.update(&map, ai_pos, 4);
+
+        let corpses = [Position::new(8, 5), Position::new(6, 6), Position::new(15, 15)];
+        assert_eq!(nearest_visible_corpse(ai_pos, &view_shed, corpses.iter()), Some(Position::new(6, 6)));
+
+        let out_of_sight = [Position::new(15, 15)];
+        assert_eq!(nearest_visible_corpse(ai_pos, &view_shed, out_of_sight.iter()), None);
+    }
+}
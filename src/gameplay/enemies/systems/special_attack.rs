@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::{
+    core::{
+        actions::attack_area,
+        components::{Awareness, Faction, Movement, PlayerTag, Position, SpecialAttacks},
+        resources::CurrentMap,
+        types::ActionType,
+    },
+    debug_ai,
+    gameplay::{
+        enemies::components::{AITarget, SpecialAttackAction, SpecialAttackScorer},
+        factions::FactionRegistry,
+        turns::components::TurnActor,
+    },
+};
+
+/// Score for a special attack that only catches the AI's target
+const SINGLE_TARGET_SCORE: f32 = 0.8;
+/// Extra score for each foe caught beside the target
+const EXTRA_TARGET_SCORE: f32 = 0.1;
+
+// ============================================================================
+// SPECIAL ATTACK SYSTEMS (Big moves, used when they pay off)
+// ============================================================================
+
+/// System that scores the best ready special attack against the AI's target.
+///
+/// An attack is only worth it if it reaches the target, catches at least its minimum number of
+/// foes and spares everyone else. The more foes caught the higher the score. The chosen attack
+/// is lined up on [`SpecialAttacks::planned`] for the action to carry out.
+pub fn special_attack_scorer_system(
+    current_map: Res<CurrentMap>,
+    factions: Res<FactionRegistry>,
+    faction_query: Query<(Option<&Faction>, Has<PlayerTag>)>,
+    mut ai_query: Query<(
+        &Position,
+        &AITarget,
+        &mut SpecialAttacks,
+        Option<&Faction>,
+        Option<&Movement>,
+        Option<&Awareness>,
+    )>,
+    mut scorer_query: Query<(&Actor, &mut Score), With<SpecialAttackScorer>>,
+) {
+    for (Actor(actor_entity), mut score) in scorer_query.iter_mut() {
+        let Ok((&ai_pos, target, mut special_attacks, faction, movement, awareness)) =
+            ai_query.get_mut(*actor_entity)
+        else {
+            score.set(0.0);
+            continue;
+        };
+
+        special_attacks.planned = None;
+        score.set(0.0);
+
+        let Some((target_entity, target_pos)) = target.get() else {
+            continue;
+        };
+
+        // Only AIs that are sure of the player commit to a big move
+        if target.is_player && awareness.is_some_and(|awareness| !awareness.is_alert()) {
+            continue;
+        }
+
+        let ai_faction = Faction::of(faction, false);
+        let is_foe = |entity| {
+            entity == target_entity
+                || faction_query.get(entity).is_ok_and(|(faction, is_player)| {
+                    factions.is_hostile(ai_faction, Faction::of(faction, is_player))
+                })
+        };
+
+        let mut best: Option<(f32, u8)> = None;
+        for (index, attack) in special_attacks.iter() {
+            if !special_attacks.is_ready(index) {
+                continue;
+            }
+
+            let area = attack_area(
+                attack.shape,
+                ai_pos,
+                target_pos,
+                &current_map,
+                *actor_entity,
+                Movement::type_of(movement),
+            );
+            let caught: Vec<Entity> =
+                area.tiles.iter().filter_map(|tile| current_map.get_actor(*tile)).collect();
+
+            if !caught.contains(&target_entity) || !caught.iter().all(|entity| is_foe(*entity)) {
+                continue;
+            }
+            if (caught.len() as u32) < attack.min_targets() {
+                continue;
+            }
+
+            let attack_score =
+                (SINGLE_TARGET_SCORE + EXTRA_TARGET_SCORE * (caught.len() - 1) as f32).min(1.0);
+            if best.is_none_or(|(best_score, _)| attack_score > best_score) {
+                best = Some((attack_score, index));
+            }
+        }
+
+        if let Some((attack_score, index)) = best {
+            special_attacks.planned = Some((index, target_pos));
+            score.set(attack_score);
+        }
+    }
+}
+
+/// System that queues the special attack the scorer lined up
+pub fn special_attack_action_system(
+    mut ai_query: Query<(&mut TurnActor, &SpecialAttacks, &Name)>,
+    mut action_query: Query<(&Actor, &mut ActionState), With<SpecialAttackAction>>,
+) {
+    for (Actor(actor_entity), mut action_state) in action_query.iter_mut() {
+        let Ok((mut ai_actor, special_attacks, ai_name)) = ai_query.get_mut(*actor_entity) else {
+            warn!("Actor must have required components");
+            continue;
+        };
+
+        if ai_actor.has_action() {
+            continue;
+        }
+
+        match *action_state {
+            ActionState::Success | ActionState::Failure => continue,
+            ActionState::Cancelled => {
+                debug_ai!("{} held back its special attack", ai_name);
+                *action_state = ActionState::Failure;
+                continue;
+            }
+            ActionState::Init | ActionState::Requested | ActionState::Executing => {}
+        }
+
+        let Some((index, target)) = special_attacks.planned else {
+            *action_state = ActionState::Failure;
+            continue;
+        };
+
+        if let Some(attack) = special_attacks.get(index) {
+            debug_ai!("{} unleashes {} at {:?}", ai_name, attack.name, target);
+        }
+        ai_actor.queue_action(ActionType::SpecialAttack { index, target });
+        *action_state = ActionState::Success;
+    }
+}
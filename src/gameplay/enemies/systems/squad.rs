@@ -0,0 +1,230 @@
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+
+use crate::{
+    core::{
+        components::{Movement, Position},
+        pathfinding,
+        resources::{CurrentMap, FovMap, Map, TurnQueue},
+    },
+    debug_ai,
+    gameplay::enemies::components::{AIBehavior, AITarget, SquadOrders, SquadRole, chebyshev_distance},
+    prelude::assets::{AIBehaviorType, MovementType},
+};
+
+/// An AI taking part in a squad plan
+#[derive(Debug, Clone, Copy)]
+struct Member {
+    entity: Entity,
+    position: Position,
+    role: SquadRole,
+    movement_type: MovementType,
+}
+
+// ============================================================================
+// SQUAD SYSTEMS (Fight as a group)
+// ============================================================================
+
+/// System that coordinates hostile AIs sharing a target.
+///
+/// Melee members are spread over distinct tiles around the target and ranged members over a ring
+/// at their preferred range. Melee members left without a tile wait at the mouth of the corridor
+/// on their way in, rather than queueing up inside it. Orders are redrawn when game time moves on
+/// or when targets, membership or positions change, which can happen while time stands still (a
+/// first sighting, arrivals on a new level).
+pub fn assign_squad_orders_system(
+    turn_queue: Res<TurnQueue>,
+    mut planned_at: Local<Option<u64>>,
+    q_changed: Query<(), Or<(Changed<AITarget>, Changed<Position>, Added<SquadOrders>)>>,
+    mut removed_members: RemovedComponents<SquadOrders>,
+    mut current_map: ResMut<CurrentMap>,
+    mut ai_query: Query<(
+        Entity,
+        &Position,
+        &AIBehavior,
+        &AITarget,
+        Option<&SquadRole>,
+        Option<&Movement>,
+        &mut SquadOrders,
+    )>,
+) {
+    let current_time = turn_queue.current_time();
+    let time_moved = planned_at.replace(current_time) != Some(current_time);
+    let members_left = removed_members.read().count() > 0;
+    if !time_moved && !members_left && q_changed.is_empty() {
+        return;
+    }
+
+    let mut squads: HashMap<Entity, (Position, Vec<Member>)> = HashMap::default();
+    for (entity, &position, behavior, target, role, movement, _) in ai_query.iter() {
+        let Some((target_entity, target_pos)) = target.get() else {
+            continue;
+        };
+        if behavior.behavior_type != AIBehaviorType::Hostile {
+            continue;
+        }
+
+        squads.entry(target_entity).or_insert_with(|| (target_pos, Vec::new())).1.push(Member {
+            entity,
+            position,
+            role: role.copied().unwrap_or_default(),
+            movement_type: Movement::type_of(movement),
+        });
+    }
+
+    let mut orders = HashMap::default();
+    for (target_pos, members) in squads.values_mut() {
+        plan_squad(*target_pos, members, &mut current_map, &mut orders);
+    }
+
+    for (entity, .., mut squad_orders) in ai_query.iter_mut() {
+        squad_orders.set_if_neq(orders.get(&entity).copied().unwrap_or_default());
+    }
+}
+
+/// Hand out orders to every member of one squad
+fn plan_squad(
+    target_pos: Position,
+    members: &mut [Member],
+    map: &mut CurrentMap,
+    orders: &mut HashMap<Entity, SquadOrders>,
+) {
+    // Closest first, so the front line gets the tiles next to the target
+    members.sort_by_key(|member| member.position.distance_squared(&target_pos));
+
+    let solo = members.len() == 1;
+    let mut claimed = HashSet::new();
+
+    for member in members.iter() {
+        let order = match member.role {
+            SquadRole::Ranged { range } => {
+                let in_sight = FovMap::compute_temporary_fov(target_pos, range, map);
+                let tile =
+                    nearest_free_tile(member, &ring_tiles(target_pos, range as i32), &claimed, map, |tile| {
+                        in_sight.get_visible_set().contains(&(tile.x(), tile.y()))
+                    });
+                tile.map_or(SquadOrders::None, SquadOrders::Keep)
+            }
+            // A lone fighter needs no plan
+            SquadRole::Melee if solo => SquadOrders::None,
+            SquadRole::Melee => {
+                match nearest_free_tile(member, &ring_tiles(target_pos, 1), &claimed, map, |_| true) {
+                    Some(tile) => SquadOrders::Attack(tile),
+                    None => SquadOrders::Hold(hold_position(member, target_pos, &claimed, map)),
+                }
+            }
+        };
+
+        if let SquadOrders::Attack(tile) | SquadOrders::Keep(tile) | SquadOrders::Hold(tile) = order {
+            claimed.insert(tile);
+        }
+        debug_ai!("Squad member {:?} ordered to {:?}", member.entity, order);
+        orders.insert(member.entity, order);
+    }
+}
+
+/// Tiles exactly `range` steps from `center`
+fn ring_tiles(center: Position, range: i32) -> Vec<Position> {
+    let mut tiles = Vec::new();
+    for dy in -range..=range {
+        for dx in -range..=range {
+            if dx.abs().max(dy.abs()) == range {
+                tiles.push(Position::new(center.x() + dx, center.y() + dy));
+            }
+        }
+    }
+    tiles
+}
+
+/// The unclaimed tile closest to the member that it can stand on
+fn nearest_free_tile(
+    member: &Member,
+    tiles: &[Position],
+    claimed: &HashSet<Position>,
+    map: &Map,
+    accept: impl Fn(Position) -> bool,
+) -> Option<Position> {
+    tiles
+        .iter()
+        .copied()
+        .filter(|tile| !claimed.contains(tile) && map.is_passable(*tile, member.movement_type))
+        .filter(|tile| map.get_actor(*tile).is_none_or(|actor| actor == member.entity))
+        .filter(|tile| accept(*tile))
+        .min_by_key(|tile| tile.distance_squared(&member.position))
+}
+
+/// Where a member with no room at the front should wait.
+///
+/// That is the last open tile before its path into the fight enters a corridor, or two steps
+/// short of the target if the way in is open. It stays put if that spot is taken.
+fn hold_position(
+    member: &Member,
+    target_pos: Position,
+    claimed: &HashSet<Position>,
+    map: &mut CurrentMap,
+) -> Position {
+    let Some(path) =
+        pathfinding::utils::find_path_for(member.position, target_pos, map, member.movement_type, true)
+    else {
+        return member.position;
+    };
+
+    let spot = match path.iter().position(|tile| is_corridor(map, *tile, member.movement_type)) {
+        Some(index) => path[index.saturating_sub(1)],
+        None => path
+            .iter()
+            .copied()
+            .take_while(|tile| chebyshev_distance(*tile, target_pos) >= 2)
+            .last()
+            .unwrap_or(member.position),
+    };
+
+    let free = map.get_actor(spot).is_none_or(|actor| actor == member.entity);
+    if free && !claimed.contains(&spot) { spot } else { member.position }
+}
+
+/// Whether a tile is a one-wide passage, walled in on both sides
+pub fn is_corridor(map: &Map, position: Position, movement_type: MovementType) -> bool {
+    if !map.is_passable(position, movement_type) {
+        return false;
+    }
+
+    let open = |dx, dy| map.is_passable(Position::new(position.x() + dx, position.y() + dy), movement_type);
+    let (north, south, east, west) = (open(0, -1), open(0, 1), open(1, 0), open(-1, 0));
+
+    (north && south && !east && !west) || (east && west && !north && !south)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::world::components::TerrainType;
+
+    #[test]
+    fn test_corridor_detection() {
+        let mut map = Map::new((10, 10));
+        for x in 2..8 {
+            map.set_terrain(Position::new(x, 5), TerrainType::FLOOR);
+        }
+
+        assert!(is_corridor(&map, Position::new(4, 5), MovementType::Walking));
+        assert!(!is_corridor(&map, Position::new(4, 4), MovementType::Walking));
+
+        // Opening one side makes it a room edge, not a corridor
+        map.set_terrain(Position::new(4, 4), TerrainType::FLOOR);
+        assert!(!is_corridor(&map, Position::new(4, 5), MovementType::Walking));
+    }
+
+    #[test]
+    fn test_ring_tiles() {
+        assert_eq!(ring_tiles(Position::new(5, 5), 1).len(), 8);
+        assert_eq!(ring_tiles(Position::new(5, 5), 2).len(), 16);
+        assert!(
+            ring_tiles(Position::new(5, 5), 3)
+                .iter()
+                .all(|tile| chebyshev_distance(*tile, Position::new(5, 5)) == 3)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+
+use crate::{
+    core::{
+        components::{AITag, DeadTag, Faction, PlayerTag, Position, ViewShed},
+        resources::{CurrentMap, TurnQueue},
+    },
+    debug_ai,
+    gameplay::{
+        enemies::{
+            components::{AIBehavior, AITarget, Grudges},
+            helpers,
+        },
+        factions::FactionRegistry,
+    },
+};
+
+// ============================================================================
+// TARGETING SYSTEMS (Decide who the AI cares about)
+// ============================================================================
+
+/// System that points every AI at the nearest hostile actor it can see.
+///
+/// Actors the AI holds a grudge against count as hostile whatever their faction, and are
+/// preferred over other hostiles. When no hostile is in view, an AI that is hostile to the player
+/// keeps the player as its target so memory and hearing can still lead it there.
+pub fn select_target_system(
+    current_map: Res<CurrentMap>,
+    turn_queue: Res<TurnQueue>,
+    factions: Res<FactionRegistry>,
+    actor_query: Query<
+        (Entity, &Position, Option<&Faction>, Has<PlayerTag>),
+        (Or<(With<PlayerTag>, With<AITag>)>, Without<DeadTag>),
+    >,
+    mut ai_query: Query<(
+        Entity,
+        &Position,
+        &AIBehavior,
+        Option<&Faction>,
+        Option<&ViewShed>,
+        Option<&Grudges>,
+        &mut AITarget,
+    )>,
+) {
+    let now = turn_queue.current_time();
+
+    for (ai_entity, &ai_pos, ai_behavior, ai_faction, view_shed, grudges, mut target) in ai_query.iter_mut() {
+        let ai_faction = Faction::of(ai_faction, false);
+
+        let mut nearest: Option<((bool, f32), AITarget)> = None;
+        let mut player: Option<AITarget> = None;
+
+        for (entity, &position, faction, is_player) in actor_query.iter() {
+            if entity == ai_entity {
+                continue;
+            }
+
+            let has_grudge = grudges.is_some_and(|grudges| grudges.holds_against(entity, now));
+            if !has_grudge && !factions.is_hostile(ai_faction, Faction::of(faction, is_player)) {
+                continue;
+            }
+
+            let candidate = AITarget::new(entity, position, is_player);
+            if is_player {
+                player = Some(candidate);
+            }
+
+            if !helpers::can_see_target(
+                view_shed,
+                ai_pos,
+                ai_behavior.detection_range,
+                position,
+                &current_map,
+            ) {
+                continue;
+            }
+
+            // Grudges come first, then the closest
+            let rank = (!has_grudge, ai_pos.fov_range_distance(&position));
+            if nearest.is_none_or(|(best, _)| rank < best) {
+                nearest = Some((rank, candidate));
+            }
+        }
+
+        let new_target = nearest.map(|(_, candidate)| candidate).or(player).unwrap_or_default();
+        if new_target.entity != target.entity {
+            debug_ai!("AI {:?} now targets {:?}", ai_entity, new_target.entity);
+        }
+        // Only a new target (or the old one moving) counts as a change, which squads replan on
+        target.set_if_neq(new_target);
+    }
+}
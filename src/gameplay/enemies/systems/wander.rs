@@ -4,19 +4,19 @@ use brtk::{prelude::Direction, random::Random};
 
 use crate::{
     core::{
-        components::{PlayerTag, Position},
+        components::{Movement, Position, ViewShed},
         pathfinding,
-        resources::{CurrentMap, FovMap, TurnQueue},
+        resources::{CurrentMap, TurnQueue},
     },
     debug_ai,
     gameplay::{
         enemies::{
-            components::{AIBehavior, WanderAction, WanderScorer, WanderType},
+            components::{AIBehavior, AITarget, PatrolRoute, WanderAction, WanderScorer, WanderType},
             helpers,
         },
         turns::components::TurnActor,
     },
-    prelude::assets::AIBehaviorType,
+    prelude::assets::{AIBehaviorType, MovementType},
     warn_ai,
 };
 
@@ -28,24 +28,25 @@ use crate::{
 pub fn wander_scorer_system(
     turn_queue: Res<TurnQueue>,
     current_map: Res<CurrentMap>,
-    player_query: Query<&Position, With<PlayerTag>>,
-    mut ai_query: Query<(&Position, &mut AIBehavior)>,
+    mut ai_query: Query<(&Position, &mut AIBehavior, &AITarget, Option<&ViewShed>)>,
     mut scorer_query: Query<(&Actor, &mut Score), With<WanderScorer>>,
 ) {
-    let Ok(player_pos) = player_query.single() else {
-        return;
-    };
-
     let current_turn = turn_queue.current_time();
 
     for (Actor(actor_entity), mut score) in scorer_query.iter_mut() {
-        let Ok((&ai_pos, mut ai_behavior)) = ai_query.get_mut(*actor_entity) else {
+        let Ok((&ai_pos, mut ai_behavior, target, view_shed)) = ai_query.get_mut(*actor_entity) else {
             warn_ai!("Actor must have required components");
             continue;
         };
 
-        let wander_score =
-            calculate_wander_score(&ai_pos, &mut ai_behavior, player_pos, current_turn, &current_map);
+        let wander_score = calculate_wander_score(
+            &ai_pos,
+            &mut ai_behavior,
+            view_shed,
+            target.position,
+            current_turn,
+            &current_map,
+        );
 
         score.set(wander_score);
     }
@@ -54,7 +55,8 @@ pub fn wander_scorer_system(
 fn calculate_wander_score(
     ai_pos: &Position,
     ai_behavior: &mut AIBehavior,
-    player_pos: &Position,
+    view_shed: Option<&ViewShed>,
+    target_pos: Option<Position>,
     current_turn: u64,
     current_map: &CurrentMap,
 ) -> f32 {
@@ -65,10 +67,14 @@ fn calculate_wander_score(
         AIBehaviorType::Passive => 0.3, // Passive entities wander moderately
     };
 
-    // If player is visible, reduce wander score (other actions should take priority)
-    if FovMap::can_see_entity(*ai_pos, ai_behavior.detection_range, *player_pos, current_map) {
-        ai_behavior.update_player_sighting(*player_pos, current_turn);
-        base_score * 0.1 // Very low priority when player is visible
+    let visible_target = target_pos.filter(|target_pos| {
+        helpers::can_see_target(view_shed, *ai_pos, ai_behavior.detection_range, *target_pos, current_map)
+    });
+
+    // If a target is visible, reduce wander score (other actions should take priority)
+    if let Some(target_pos) = visible_target {
+        ai_behavior.update_player_sighting(target_pos, current_turn);
+        base_score * 0.1 // Very low priority when a target is visible
     } else if ai_behavior.should_switch_to_wander(current_turn) {
         // If enough time has passed since last player sighting, increase wander score
         base_score * 1.5
@@ -86,16 +92,18 @@ pub fn wander_action_system(
     turn_queue: Res<TurnQueue>,
     mut random: ResMut<Random>,
     mut current_map: ResMut<CurrentMap>,
-    mut ai_query: Query<(&Position, &mut TurnActor, &Name)>,
+    mut ai_query: Query<(&Position, &mut TurnActor, &Name, Option<&Movement>, Option<&mut PatrolRoute>)>,
     mut action_query: Query<(&Actor, &mut ActionState, &mut WanderAction)>,
 ) {
     let current_turn = turn_queue.current_time();
 
     for (Actor(actor_entity), mut action_state, mut wander_action) in action_query.iter_mut() {
-        let Ok((ai_pos, mut ai_actor, ai_name)) = ai_query.get_mut(*actor_entity) else {
+        let Ok((ai_pos, mut ai_actor, ai_name, movement, mut patrol_route)) = ai_query.get_mut(*actor_entity)
+        else {
             warn_ai!("Actor must have required components");
             continue;
         };
+        let movement_type = Movement::type_of(movement);
 
         if ai_actor.has_action() {
             continue;
@@ -115,8 +123,14 @@ pub fn wander_action_system(
                 debug_ai!("{} gonna start wandering!", ai_name);
                 *action_state = ActionState::Executing;
 
+                // Back from whatever interrupted it, a guard rejoins its beat where it is closest
+                if let Some(route) = patrol_route.as_deref_mut() {
+                    route.resume(*ai_pos);
+                }
+
                 let target_position = select_wander_target(
                     &mut wander_action,
+                    patrol_route.as_deref_mut(),
                     *ai_pos,
                     &current_map,
                     current_turn,
@@ -125,8 +139,13 @@ pub fn wander_action_system(
 
                 if let Some(target) = target_position {
                     // Generate A* path to wander target
-                    if let Some(path) = pathfinding::utils::find_path(*ai_pos, target, &mut current_map, true)
-                    {
+                    if let Some(path) = pathfinding::utils::find_path_for(
+                        *ai_pos,
+                        target,
+                        &mut current_map,
+                        movement_type,
+                        true,
+                    ) {
                         debug_ai!("{} generated A* wander path with {} steps", ai_name, path.len());
 
                         // Store the complete path and tracking information
@@ -179,20 +198,31 @@ pub fn wander_action_system(
                 debug_ai!("{} executing wander!", ai_name);
 
                 // Check if we need to regenerate the wander path or select new target
-                if should_regenerate_wander_path(&wander_action, *ai_pos, &current_map, current_turn) {
+                if should_regenerate_wander_path(
+                    &wander_action,
+                    *ai_pos,
+                    movement_type,
+                    &current_map,
+                    current_turn,
+                ) {
                     debug_ai!("{} regenerating wander path or selecting new target", ai_name);
 
                     let new_target = select_wander_target(
                         &mut wander_action,
+                        patrol_route.as_deref_mut(),
                         *ai_pos,
                         &current_map,
                         current_turn,
                         &mut random,
                     );
                     if let Some(target) = new_target {
-                        if let Some(path) =
-                            pathfinding::utils::find_path(*ai_pos, target, &mut current_map, true)
-                        {
+                        if let Some(path) = pathfinding::utils::find_path_for(
+                            *ai_pos,
+                            target,
+                            &mut current_map,
+                            movement_type,
+                            true,
+                        ) {
                             wander_action.current_path = path;
                             wander_action.path_index = 0;
                             wander_action.current_target = Some(target);
@@ -223,7 +253,7 @@ pub fn wander_action_system(
 
                 // Get next movement direction
                 let next_move_result = if !wander_action.current_path.is_empty() {
-                    follow_stored_wander_path(&mut wander_action, *ai_pos, &current_map)
+                    follow_stored_wander_path(&mut wander_action, *ai_pos, movement_type, &current_map)
                 } else {
                     helpers::calculate_direction_to_target(ai_pos, &target_position)
                 };
@@ -260,6 +290,7 @@ fn execute_wander_movement(
 /// Select an appropriate wander target based on the wander type
 fn select_wander_target(
     wander_action: &mut WanderAction,
+    patrol_route: Option<&mut PatrolRoute>,
     ai_pos: Position,
     map: &CurrentMap,
     current_turn: u64,
@@ -268,7 +299,7 @@ fn select_wander_target(
     match wander_action.wander_type {
         WanderType::Random => select_random_wander_target(ai_pos, map, random),
         WanderType::AreaWander => select_area_wander_target(wander_action, ai_pos, map, random),
-        WanderType::Patrol => select_patrol_target(wander_action, ai_pos, map, random),
+        WanderType::Patrol => select_patrol_target(wander_action, patrol_route, ai_pos, map, random),
         WanderType::Explore => select_exploration_target(ai_pos, map, random),
     }
     .inspect(|_| {
@@ -329,15 +360,21 @@ fn select_area_wander_target(
     select_random_wander_target(ai_pos, map, random)
 }
 
-/// Select the next patrol point in the patrol route
+/// Select the next patrol point, from the AI's own route if it was given one
 fn select_patrol_target(
     wander_action: &mut WanderAction,
+    patrol_route: Option<&mut PatrolRoute>,
     ai_pos: Position,
-    _map: &CurrentMap,
-    _random: &mut Random,
+    map: &CurrentMap,
+    random: &mut Random,
 ) -> Option<Position> {
+    if let Some(route) = patrol_route.filter(|route| !route.is_empty()) {
+        return route.next_waypoint(ai_pos);
+    }
+
+    // Without a route, stay near the post
     if wander_action.patrol_points.is_empty() {
-        return None;
+        return select_area_wander_target(wander_action, ai_pos, map, random);
     }
 
     // Check if we've reached the current patrol point
@@ -378,6 +415,7 @@ fn select_exploration_target(ai_pos: Position, map: &CurrentMap, random: &mut Ra
 fn should_regenerate_wander_path(
     wander_action: &WanderAction,
     current_ai_pos: Position,
+    movement_type: MovementType,
     map: &CurrentMap,
     current_turn: u64,
 ) -> bool {
@@ -410,7 +448,7 @@ fn should_regenerate_wander_path(
 
     // Current path step is blocked
     if let Some(next_pos) = wander_action.current_path.get(wander_action.path_index + 1)
-        && !map.is_walkable(*next_pos)
+        && !map.is_passable(*next_pos, movement_type)
     {
         debug_ai!("current path step is blocked");
         return true;
@@ -429,6 +467,7 @@ fn should_regenerate_wander_path(
 fn follow_stored_wander_path(
     wander_action: &mut WanderAction,
     current_ai_pos: Position,
+    movement_type: MovementType,
     map: &CurrentMap,
 ) -> Option<Direction> {
     // Ensure we have a valid path
@@ -456,8 +495,8 @@ fn follow_stored_wander_path(
     // Get the next step in the path
     let next_index = current_path_index + 1;
     if let Some(next_pos) = wander_action.current_path.get(next_index) {
-        // Check if the next position is walkable
-        if !map.is_walkable(*next_pos) {
+        // Check if the next position is passable for this mover
+        if !map.is_passable(*next_pos, movement_type) {
             // Path is blocked, need to regenerate
             return None;
         }
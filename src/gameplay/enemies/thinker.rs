@@ -0,0 +1,155 @@
+//! Building big-brain thinkers from entity definitions
+//!
+//! Every scorer and action named in [`ThinkerData`] maps onto one of the AI components. Weighted
+//! choices multiply their scorer by a fixed score, so weights only ever scale a score down.
+
+use big_brain::prelude::*;
+use echos_assets::entities::{ActionData, ChoiceData, PickerData, ScorerData, ThinkerData, WanderKind};
+
+use crate::{
+    core::components::Position,
+    gameplay::enemies::components::{
+        AttackAction, ChasePlayerAction, ChasePlayerScorer, FleeFromPlayerAction, FleeFromPlayerScorer,
+        IdleAction, LowHealthScorer, ScavengeAction, ScavengeScorer, SpecialAttackAction,
+        SpecialAttackScorer, WanderAction, WanderArea, WanderScorer, WanderType,
+    },
+};
+
+/// Build the thinker described by `data` for an AI whose home is `home`
+pub fn build_thinker(data: &ThinkerData, home: Position) -> ThinkerBuilder {
+    let mut builder = Thinker::build();
+
+    if let Some(label) = &data.label {
+        builder = builder.label(label.clone());
+    }
+
+    builder = match data.picker {
+        PickerData::FirstToScore { threshold } => builder.picker(FirstToScore { threshold }),
+        PickerData::HighestToScore { threshold } => builder.picker(HighestToScore { threshold }),
+        PickerData::Highest => builder.picker(Highest),
+    };
+
+    for choice in &data.choices {
+        builder = add_choice(builder, choice, home);
+    }
+
+    match &data.otherwise {
+        Some(action) => add_otherwise(builder, action, home),
+        None => builder,
+    }
+}
+
+fn add_choice(builder: ThinkerBuilder, choice: &ChoiceData, home: Position) -> ThinkerBuilder {
+    match choice.scorer {
+        ScorerData::Chase => add_weighted(builder, ChasePlayerScorer, choice, home),
+        ScorerData::Flee { health_threshold } => {
+            add_weighted(builder, FleeFromPlayerScorer { health_threshold }, choice, home)
+        }
+        ScorerData::Wander => add_weighted(builder, WanderScorer, choice, home),
+        ScorerData::LowHealth { threshold } => {
+            add_weighted(builder, LowHealthScorer { threshold }, choice, home)
+        }
+        ScorerData::SpecialAttack => add_weighted(builder, SpecialAttackScorer, choice, home),
+        ScorerData::Scavenge => add_weighted(builder, ScavengeScorer, choice, home),
+        ScorerData::Fixed(score) => add_weighted(builder, FixedScore::build(score), choice, home),
+    }
+}
+
+fn add_weighted(
+    builder: ThinkerBuilder,
+    scorer: impl ScorerBuilder + 'static,
+    choice: &ChoiceData,
+    home: Position,
+) -> ThinkerBuilder {
+    match choice.weight {
+        Some(weight) => add_when(
+            builder,
+            ProductOfScorers::build(0.0).push(scorer).push(FixedScore::build(weight)),
+            &choice.action,
+            home,
+        ),
+        None => add_when(builder, scorer, &choice.action, home),
+    }
+}
+
+fn add_when(
+    builder: ThinkerBuilder,
+    scorer: impl ScorerBuilder + 'static,
+    action: &ActionData,
+    home: Position,
+) -> ThinkerBuilder {
+    match action {
+        ActionData::ChaseAndAttack => {
+            builder.when(scorer, Steps::build().step(ChasePlayerAction::default()).step(AttackAction))
+        }
+        ActionData::Chase => builder.when(scorer, ChasePlayerAction::default()),
+        ActionData::Attack => builder.when(scorer, AttackAction),
+        ActionData::Flee => builder.when(scorer, FleeFromPlayerAction::default()),
+        ActionData::Wander { wander_type, radius } => {
+            builder.when(scorer, wander_action(*wander_type, *radius, home))
+        }
+        ActionData::SpecialAttack => builder.when(scorer, SpecialAttackAction),
+        ActionData::Scavenge => builder.when(scorer, ScavengeAction),
+        ActionData::Idle => builder.when(scorer, IdleAction),
+    }
+}
+
+fn add_otherwise(builder: ThinkerBuilder, action: &ActionData, home: Position) -> ThinkerBuilder {
+    match action {
+        ActionData::ChaseAndAttack => {
+            builder.otherwise(Steps::build().step(ChasePlayerAction::default()).step(AttackAction))
+        }
+        ActionData::Chase => builder.otherwise(ChasePlayerAction::default()),
+        ActionData::Attack => builder.otherwise(AttackAction),
+        ActionData::Flee => builder.otherwise(FleeFromPlayerAction::default()),
+        ActionData::Wander { wander_type, radius } => {
+            builder.otherwise(wander_action(*wander_type, *radius, home))
+        }
+        ActionData::SpecialAttack => builder.otherwise(SpecialAttackAction),
+        ActionData::Scavenge => builder.otherwise(ScavengeAction),
+        ActionData::Idle => builder.otherwise(IdleAction),
+    }
+}
+
+/// Wander action for a wander kind, kept within `radius` of home when one is given
+pub fn wander_action(kind: WanderKind, radius: Option<u32>, home: Position) -> WanderAction {
+    let wander_type = match kind {
+        WanderKind::Random => WanderType::Random,
+        WanderKind::Area => WanderType::AreaWander,
+        WanderKind::Patrol => WanderType::Patrol,
+        WanderKind::Explore => WanderType::Explore,
+    };
+
+    WanderAction {
+        wander_type,
+        wander_area: radius.map(|radius| WanderArea { center: home, radius }),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use echos_assets::entities::AIBehaviorType;
+
+    use super::*;
+
+    #[test]
+    fn test_wander_action_stays_near_home() {
+        let home = Position::new(4, 7);
+
+        let area = wander_action(WanderKind::Area, Some(5), home);
+        assert_eq!(area.wander_type, WanderType::AreaWander);
+        assert_eq!(area.wander_area.map(|area| (area.center, area.radius)), Some((home, 5)));
+
+        let random = wander_action(WanderKind::Random, None, home);
+        assert_eq!(random.wander_type, WanderType::Random);
+        assert!(random.wander_area.is_none());
+    }
+
+    #[test]
+    fn test_builds_every_stock_thinker() {
+        for behavior_type in [AIBehaviorType::Hostile, AIBehaviorType::Passive, AIBehaviorType::Neutral] {
+            build_thinker(&ThinkerData::for_behavior(behavior_type), Position::new(0, 0));
+        }
+    }
+}
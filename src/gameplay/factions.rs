@@ -0,0 +1,188 @@
+//! Faction registry
+//!
+//! Faction names and the relationship matrix come from the [`FactionTable`] asset and live in the
+//! [`FactionRegistry`] resource. Built-in factions keep fixed ids; extra factions in the table get
+//! new ids when it is installed.
+
+use bevy::{asset::AssetEvent, prelude::*};
+use echos_assets::factions::{FactionDefinitions, FactionRelation, FactionTable, Stance};
+use std::collections::HashMap;
+
+use crate::core::components::Faction;
+
+/// Names of the built-in factions, in id order. These must match the [`Faction`] constants.
+const BUILTIN_FACTIONS: [&str; 4] = ["player", "monsters", "critters", "wanderers"];
+
+/// Faction ids and the stances between them
+#[derive(Resource, Debug, Clone)]
+pub struct FactionRegistry {
+    names: Vec<String>,
+    ids: HashMap<String, u16>,
+    stances: HashMap<(u16, u16), Stance>,
+    default_stance: Stance,
+}
+
+impl Default for FactionRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            stances: HashMap::new(),
+            default_stance: Stance::Neutral,
+        };
+        registry.install(&fallback_table());
+        registry
+    }
+}
+
+impl FactionRegistry {
+    /// Find a faction id by name
+    pub fn id_of(&self, name: &str) -> Option<Faction> { self.ids.get(name).copied().map(Faction) }
+
+    /// Name of a faction (unknown ids resolve to the monster faction)
+    pub fn name(&self, faction: Faction) -> &str {
+        self.names.get(faction.0 as usize).unwrap_or(&self.names[Faction::MONSTERS.0 as usize])
+    }
+
+    /// Number of registered factions
+    pub fn len(&self) -> usize { self.names.len() }
+
+    /// Whether the registry has no factions (never true once built-ins are seeded)
+    pub fn is_empty(&self) -> bool { self.names.is_empty() }
+
+    /// How faction `a` regards faction `b`
+    pub fn stance(&self, a: Faction, b: Faction) -> Stance {
+        match self.stances.get(&Self::key(a.0, b.0)) {
+            Some(stance) => *stance,
+            None if a == b => Stance::Friendly,
+            None => self.default_stance,
+        }
+    }
+
+    pub fn is_hostile(&self, a: Faction, b: Faction) -> bool { self.stance(a, b) == Stance::Hostile }
+
+    pub fn is_friendly(&self, a: Faction, b: Faction) -> bool { self.stance(a, b) == Stance::Friendly }
+
+    /// Merge a loaded table's factions and replace the relationship matrix with its relations
+    pub fn install(&mut self, table: &FactionTable) {
+        for name in &table.factions {
+            self.insert(name);
+        }
+
+        self.stances.clear();
+        for relation in &table.relations {
+            let (Some(a), Some(b)) = (self.ids.get(&relation.a), self.ids.get(&relation.b)) else {
+                warn!("Faction relation between unknown factions '{}' and '{}'", relation.a, relation.b);
+                continue;
+            };
+            self.stances.insert(Self::key(*a, *b), relation.stance);
+        }
+
+        self.default_stance = table.default_stance;
+    }
+
+    fn insert(&mut self, name: &str) {
+        if !self.ids.contains_key(name) {
+            let id = self.names.len() as u16;
+            self.ids.insert(name.to_string(), id);
+            self.names.push(name.to_string());
+        }
+    }
+
+    /// Relations are symmetric, so store each pair once
+    fn key(a: u16, b: u16) -> (u16, u16) { (a.min(b), a.max(b)) }
+}
+
+/// The built-in factions, with monsters set against the player so a game without a faction table
+/// still has enemies
+fn fallback_table() -> FactionTable {
+    FactionTable {
+        factions: BUILTIN_FACTIONS.into_iter().map(String::from).collect(),
+        relations: vec![FactionRelation::new("monsters", "player", Stance::Hostile)],
+        default_stance: Stance::Neutral,
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Install the loaded faction table before anything is spawned
+pub fn apply_faction_definitions(
+    mut registry: ResMut<FactionRegistry>,
+    faction_definitions: Option<Res<FactionDefinitions>>,
+    tables: Res<Assets<FactionTable>>,
+) {
+    let Some(table) = faction_definitions.and_then(|definitions| tables.get(&definitions.table)) else {
+        error!("Faction table not loaded, only monsters will be hostile to the player");
+        return;
+    };
+
+    if let Err(errors) = table.validate() {
+        for error in errors {
+            warn!("Faction table: {error}");
+        }
+    }
+
+    for name in BUILTIN_FACTIONS {
+        if !table.factions.iter().any(|faction| faction == name) {
+            error!("Faction table is missing built-in faction '{name}'");
+        }
+    }
+
+    registry.install(table);
+    info!("Loaded {} factions", registry.len());
+}
+
+/// Re-install the faction table when it changes on disk
+pub fn reload_faction_definitions(
+    mut events: EventReader<AssetEvent<FactionTable>>,
+    tables: Res<Assets<FactionTable>>,
+    mut registry: ResMut<FactionRegistry>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event
+            && let Some(table) = tables.get(*id)
+        {
+            registry.install(table);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_ids_match_constants() {
+        let registry = FactionRegistry::default();
+
+        assert_eq!(registry.id_of("player"), Some(Faction::PLAYER));
+        assert_eq!(registry.id_of("monsters"), Some(Faction::MONSTERS));
+        assert_eq!(registry.id_of("critters"), Some(Faction::CRITTERS));
+        assert_eq!(registry.id_of("wanderers"), Some(Faction::WANDERERS));
+    }
+
+    #[test]
+    fn test_install_extends_and_replaces_relations() {
+        let mut registry = FactionRegistry::default();
+        let table = FactionTable {
+            factions: vec!["player".into(), "monsters".into(), "guards".into()],
+            relations: vec![
+                FactionRelation::new("guards", "player", Stance::Hostile),
+                FactionRelation::new("guards", "guards", Stance::Hostile),
+            ],
+            default_stance: Stance::Neutral,
+        };
+        registry.install(&table);
+
+        let guards = registry.id_of("guards").unwrap();
+        assert_eq!(registry.stance(guards, Faction::PLAYER), Stance::Hostile);
+        assert_eq!(registry.stance(Faction::PLAYER, guards), Stance::Hostile);
+        assert_eq!(registry.stance(guards, guards), Stance::Hostile);
+
+        // The fallback relation was replaced by the table's
+        assert_eq!(registry.stance(Faction::MONSTERS, Faction::PLAYER), Stance::Neutral);
+        assert_eq!(registry.stance(Faction::MONSTERS, Faction::MONSTERS), Stance::Friendly);
+    }
+}
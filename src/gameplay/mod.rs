@@ -1,11 +1,26 @@
 use bevy::{platform::collections::HashSet, prelude::*};
 
 pub mod enemies;
+pub mod factions;
 pub mod player;
 pub mod turns;
 pub mod world;
 
-use crate::{core::states::GameState, debug_turns, rendering::screens::ScreenState, ui};
+use brtk::random::Random;
+
+use crate::{
+    core::{
+        commands::SpawnEntityCommands,
+        components::{PlayerTag, Position},
+        events::DescendEvent,
+        resources::{CurrentMap, SpawnPoint},
+        states::GameState,
+    },
+    debug_turns,
+    gameplay::world::components::TerrainType,
+    rendering::screens::ScreenState,
+    ui,
+};
 
 /// System sets for organizing gameplay systems
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
@@ -40,11 +55,45 @@ pub fn plugin(app: &mut App) {
     // Add initialization systems
     app.add_systems(
         OnEnter(ScreenState::Gameplay),
-        (ui::systems::spawn_camera, world::systems::spawn_map, spawn_initial_entities, start_first_turn)
+        (
+            ui::systems::spawn_camera,
+            world::terrain::apply_terrain_definitions,
+            factions::apply_faction_definitions,
+            world::systems::prepare_level,
+            world::systems::spawn_map,
+            world::spawn_table::build_spawn_table,
+            world::scaling::apply_scaling_definitions,
+            player::progression::apply_progression_definitions,
+            player::hunger::apply_hunger_definitions,
+            spawn_initial_entities,
+            start_first_turn,
+        )
             .chain()
             .in_set(GameplaySystemSet::Initialization),
     );
 
+    // Taking the stairs down swaps the level out from under the player
+    app.add_systems(
+        Update,
+        (
+            world::systems::leave_level,
+            world::systems::prepare_level,
+            world::systems::spawn_map,
+            world::spawn_table::build_spawn_table,
+            world::scaling::apply_scaling_definitions,
+            arrive_on_level,
+        )
+            .chain()
+            .run_if(on_event::<DescendEvent>)
+            .in_set(GameplaySystemSet::WorldUpdate),
+    );
+
+    // How factions regard each other; filled from the faction table when gameplay starts
+    app.init_resource::<factions::FactionRegistry>();
+
+    // Hot-reload faction definitions
+    app.add_systems(Update, factions::reload_faction_definitions);
+
     // Add gameplay plugins
     app.add_plugins((player::plugin, enemies::plugin, turns::plugin, world::plugin));
 }
@@ -52,11 +101,11 @@ pub fn plugin(app: &mut App) {
 /// System to spawn initial entities when gameplay starts
 fn spawn_initial_entities(
     mut commands: Commands,
-    current_map: Res<crate::core::resources::CurrentMap>,
-    spawn_point: Option<Res<crate::core::resources::SpawnPoint>>,
+    current_map: Res<CurrentMap>,
+    gen_config: Res<world::generation::GenConfig>,
+    spawn_point: Option<Res<SpawnPoint>>,
+    mut shared_rng: ResMut<Random>,
 ) {
-    use crate::core::{commands::SpawnEntityCommands, components::Position};
-
     // Spawn player
     let player_position = spawn_point
         .and_then(|sp| sp.player_spawn)
@@ -67,10 +116,53 @@ fn spawn_initial_entities(
         });
 
     commands.spawn_player(player_position);
+    stock_rooms(&mut commands, &current_map, &gen_config, player_position, &mut shared_rng);
+}
+
+/// System that puts the player on the up stairs of a freshly built level
+fn arrive_on_level(
+    mut commands: Commands,
+    current_map: Res<CurrentMap>,
+    gen_config: Res<world::generation::GenConfig>,
+    player: Single<Entity, With<PlayerTag>>,
+    mut shared_rng: ResMut<Random>,
+) {
+    let arrival = current_map
+        .tiles
+        .position_iter()
+        .map(|(x, y)| Position::new(x, y))
+        .find(|position| current_map.get_terrain(*position) == Some(TerrainType::STAIRS_UP))
+        .or_else(|| current_map.get_random_walkable_position())
+        .unwrap_or_else(|| {
+            warn!("No valid arrival point found for player, using default position");
+            Position::new(0, 0)
+        });
+
+    // Re-inserting Position runs its hooks, which place the player on the new map
+    commands.entity(*player).insert(arrival);
+    stock_rooms(&mut commands, &current_map, &gen_config, arrival, &mut shared_rng);
 
-    let mut occupied: HashSet<Position> = HashSet::from([player_position]);
-    if let Some(enemy_pos) = current_map.get_random_walkable_position().filter(|p| occupied.insert(*p)) {
-        commands.spawn_ai("hostile_guard", enemy_pos);
+    info!("You arrive at depth {}.", gen_config.depth);
+}
+
+/// Stock every room but the player's from the spawn table, up to its budget
+fn stock_rooms(
+    commands: &mut Commands,
+    current_map: &CurrentMap,
+    gen_config: &world::generation::GenConfig,
+    player_position: Position,
+    shared_rng: &mut Random,
+) {
+    let occupied: HashSet<Position> = HashSet::from([player_position]);
+    let mut rng = shared_rng.to_fastrand();
+    for position in world::spawn_table::room_spawn_positions(
+        &gen_config.rooms,
+        gen_config.depth,
+        current_map,
+        &occupied,
+        &mut rng,
+    ) {
+        commands.spawn_random_enemy(position);
     }
 }
 
@@ -17,6 +17,24 @@ pub enum PlayerAction {
 
     // Actions
     Wait,
+    Ping,
+    Look,
+    Search,
+    PickUp,
+    /// Pick up even what the player can't carry without being overloaded
+    PickUpOverloading,
+    Eat,
+    LightTorch,
+    /// Set the torch in hand down, still burning
+    DropTorch,
+    /// Take the stairs down
+    Descend,
+    /// Use the first ready special attack on the nearest foe in sight
+    SpecialAttack,
+    /// Open or close a door next to the player
+    ToggleDoor,
+    /// Held with a direction, dig into the rock that way instead of walking
+    Dig,
 }
 
 impl PlayerAction {
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use brtk::random::Random;
+use echos_assets::{classes::ClassDefinition, entities::StatsData};
+
+use crate::core::components::Stats;
+
+/// Longest name the creation screen takes
+pub const MAX_NAME_LENGTH: usize = 20;
+
+/// The character put together on the creation screen, applied over the player definition when
+/// the player spawns
+#[derive(Resource, Debug, Clone, Default)]
+pub struct NewCharacter {
+    /// Name typed in by the player
+    pub name: String,
+    /// The class picked; without one the player definition is used as it is
+    pub class: Option<ClassDefinition>,
+    /// Stats rolled with the class's dice, in place of its own
+    pub rolled_stats: Option<StatsData>,
+}
+
+impl NewCharacter {
+    /// Pick a class, dropping any stats rolled for the last one
+    pub fn choose_class(&mut self, class: &ClassDefinition) {
+        self.class = Some(class.clone());
+        self.rolled_stats = None;
+    }
+
+    /// Roll new stats with the chosen class's dice
+    pub fn reroll(&mut self, random: &mut Random) {
+        self.rolled_stats = self.class.as_ref().map(|class| class.roll_stats(random));
+    }
+
+    /// The stats the character starts with, if a class has been picked
+    pub fn stats(&self) -> Option<Stats> {
+        self.rolled_stats.as_ref().or(self.class.as_ref().map(|class| &class.stats)).map(Stats::from)
+    }
+
+    /// Type a character onto the end of the name
+    pub fn push_to_name(&mut self, text: &str) {
+        for character in text.chars().filter(|character| !character.is_control()) {
+            if self.name.chars().count() >= MAX_NAME_LENGTH {
+                break;
+            }
+            self.name.push(character);
+        }
+    }
+
+    /// Erase the last character of the name
+    pub fn pop_from_name(&mut self) { self.name.pop(); }
+
+    /// How the character is described in game, like "Mira the Rogue"
+    pub fn description(&self) -> Option<String> {
+        let name = self.name.trim();
+        match (name.is_empty(), &self.class) {
+            (true, _) => None,
+            (false, Some(class)) => Some(format!("{name} the {}", class.name)),
+            (false, None) => Some(name.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reroll_replaces_class_stats_until_class_changes() {
+        let warrior = ClassDefinition::new("Warrior", "Tough", StatsData::warrior());
+        let mut character = NewCharacter::default();
+        assert!(character.stats().is_none());
+
+        character.choose_class(&warrior);
+        assert_eq!(character.stats(), Some(Stats::from(&StatsData::warrior())));
+
+        character.reroll(&mut Random::new(3));
+        assert!(character.rolled_stats.is_some());
+
+        character.choose_class(&warrior);
+        assert_eq!(character.stats(), Some(Stats::from(&StatsData::warrior())));
+    }
+
+    #[test]
+    fn test_name_goes_into_description() {
+        let mut character = NewCharacter::default();
+        assert_eq!(character.description(), None);
+
+        character.push_to_name("Mira\n");
+        character.choose_class(&ClassDefinition::new("Rogue", "Quick", StatsData::rogue()));
+        assert_eq!(character.description().as_deref(), Some("Mira the Rogue"));
+
+        character.push_to_name(&"a".repeat(40));
+        assert_eq!(character.name.chars().count(), MAX_NAME_LENGTH);
+        character.pop_from_name();
+        assert_eq!(character.name.chars().count(), MAX_NAME_LENGTH - 1);
+    }
+}
@@ -3,3 +3,9 @@ use bevy::prelude::*;
 /// Component that indicates the player is waiting for input
 #[derive(Component, Debug, Default)]
 pub struct AwaitingInput;
+
+/// What the player made out the last time they looked around, shown on the HUD until they act
+#[derive(Resource, Debug, Default)]
+pub struct ExamineReport {
+    pub lines: Vec<String>,
+}
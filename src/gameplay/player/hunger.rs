@@ -0,0 +1,127 @@
+use bevy::{asset::AssetEvent, prelude::*};
+use echos_assets::survival::{HungerSettings, HungerStage, SurvivalDefinitions};
+
+use crate::core::{
+    components::{Health, PlayerTag, Position, Satiety, StatModifiers},
+    events::EntityDeathEvent,
+    resources::GameDifficulty,
+    types::ActionType,
+};
+
+/// How the food clock runs this game
+#[derive(Resource, Debug, Clone, Default, Deref)]
+pub struct HungerRules(pub HungerSettings);
+
+impl HungerRules {
+    /// Whether anyone goes hungry at `difficulty`
+    pub fn is_active(&self, difficulty: &GameDifficulty) -> bool { self.is_enabled(difficulty.0) }
+}
+
+/// Run an entity's food clock for an action that took `d_time`, dealing starvation damage once it
+/// has nothing left to burn
+pub fn exert(world: &mut World, entity: Entity, action_type: ActionType, d_time: u64) {
+    if !world.resource::<HungerRules>().is_active(world.resource::<GameDifficulty>()) {
+        return;
+    }
+
+    let turns = d_time as f32 / 1000.0;
+    let damage = world.resource_scope(|world, rules: Mut<HungerRules>| {
+        let mut satiety = world.get_mut::<Satiety>(entity)?;
+        satiety.drain(rules.drain(action_type.name(), turns));
+
+        let damage_per_turn = rules.stage_for(satiety.current)?.damage_per_turn?;
+        Some(satiety.starve(damage_per_turn * turns))
+    });
+    let Some(damage) = damage.filter(|damage| *damage > 0) else {
+        return;
+    };
+
+    let Some(mut health) = world.get_mut::<Health>(entity).filter(|health| health.is_alive()) else {
+        return;
+    };
+    health.take_damage(damage);
+    let starved = health.is_dead();
+
+    if world.get::<PlayerTag>(entity).is_some() {
+        info!("You are starving! You lose {damage} health.");
+    }
+
+    if starved && let Some(position) = world.get::<Position>(entity).copied() {
+        world.send_event(EntityDeathEvent { entity, position, killer: None });
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Run the food clock by the loaded hunger settings
+pub fn apply_hunger_definitions(
+    mut rules: ResMut<HungerRules>,
+    survival_definitions: Option<Res<SurvivalDefinitions>>,
+    settings: Res<Assets<HungerSettings>>,
+) {
+    let Some(hunger) = survival_definitions.and_then(|definitions| settings.get(&definitions.hunger)) else {
+        warn!("Hunger settings not loaded, falling back to a food clock with no stages");
+        return;
+    };
+
+    if let Err(errors) = hunger.validate() {
+        for error in errors {
+            warn!("Hunger settings: {error}");
+        }
+    }
+
+    rules.0 = hunger.clone();
+}
+
+/// Pick up hunger setting changes on disk straight away
+pub fn reload_hunger_definitions(
+    mut events: EventReader<AssetEvent<HungerSettings>>,
+    settings: Res<Assets<HungerSettings>>,
+    mut rules: ResMut<HungerRules>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event
+            && let Some(hunger) = settings.get(*id)
+        {
+            rules.0 = hunger.clone();
+        }
+    }
+}
+
+/// System that moves characters between hunger stages as they go hungry or eat, applying each
+/// stage's stat changes. The player is told how they feel.
+pub fn update_hunger_stage(
+    rules: Res<HungerRules>,
+    mut query: Query<(&mut Satiety, &mut StatModifiers, Has<PlayerTag>)>,
+) {
+    for (mut satiety, mut modifiers, is_player) in &mut query {
+        if !satiety.is_changed() && !rules.is_changed() {
+            continue;
+        }
+
+        // Only write when something's different, so the component doesn't flag itself as changed
+        let max_satiety = rules.max_satiety();
+        if satiety.max == max_satiety && satiety.stage_in(&rules.0) == satiety.stage {
+            continue;
+        }
+        if satiety.max != max_satiety {
+            satiety.set_max(max_satiety);
+        }
+
+        let previous = satiety.stage;
+        let Some(stage) = satiety.settle_stage(&rules.0, &mut modifiers) else {
+            continue;
+        };
+        if is_player {
+            match stage {
+                HungerStage::Satiated => info!("You feel satiated."),
+                HungerStage::Hungry if previous > stage => info!("You feel less weak, but still hungry."),
+                HungerStage::Hungry => info!("You are getting hungry."),
+                HungerStage::Weak => info!("You feel weak from hunger."),
+                HungerStage::Fainting => info!("You are fainting from hunger!"),
+            }
+        }
+    }
+}
@@ -2,8 +2,11 @@ use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 
 pub mod actions;
+pub mod character;
 pub mod components;
 pub mod events;
+pub mod hunger;
+pub mod progression;
 pub mod systems;
 
 use crate::{core::states::GameState, rendering::screens::ScreenState};
@@ -12,15 +15,39 @@ use crate::{core::states::GameState, rendering::screens::ScreenState};
 pub fn plugin(app: &mut App) {
     app.add_plugins(InputManagerPlugin::<actions::PlayerAction>::default());
 
+    app.init_resource::<character::NewCharacter>();
+    app.init_resource::<progression::Progression>();
+    app.init_resource::<hunger::HungerRules>();
+    app.init_resource::<components::ExamineReport>();
+
     // Add player events
     app.add_event::<events::PlayerMoved>().add_event::<events::PlayerDied>();
 
     // Add player systems with proper system set organization
     app.add_systems(
         Update,
-        systems::player_input_system
+        (systems::player_input_system, systems::examine_system)
             .run_if(in_state(GameState::GatherActions))
             .run_if(in_state(ScreenState::Gameplay))
             .in_set(super::GameplaySystemSet::ActionGathering),
     );
+
+    // Kills pay out experience, read off the victim before it is laid out as a corpse
+    app.add_systems(
+        Update,
+        progression::award_experience_system
+            .before(crate::core::systems::combat::handle_entity_death)
+            .run_if(in_state(ScreenState::Gameplay))
+            .in_set(super::GameplaySystemSet::ActionProcessing),
+    );
+    app.add_systems(Update, progression::reload_progression_definitions);
+
+    // Hunger stages follow satiety as it drains and fills
+    app.add_systems(
+        Update,
+        hunger::update_hunger_stage
+            .run_if(in_state(ScreenState::Gameplay))
+            .in_set(super::GameplaySystemSet::ActionProcessing),
+    );
+    app.add_systems(Update, hunger::reload_hunger_definitions);
 }
@@ -0,0 +1,79 @@
+use bevy::{asset::AssetEvent, prelude::*};
+use echos_assets::progression::{ProgressionDefinitions, ProgressionTable};
+
+use crate::core::{
+    components::{Experience, ExperienceValue},
+    events::EntityDeathEvent,
+};
+
+/// How characters level up this game
+#[derive(Resource, Debug, Clone, Default, Deref)]
+pub struct Progression(pub ProgressionTable);
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Level characters up with the loaded progression table
+pub fn apply_progression_definitions(
+    mut progression: ResMut<Progression>,
+    progression_definitions: Option<Res<ProgressionDefinitions>>,
+    tables: Res<Assets<ProgressionTable>>,
+) {
+    let Some(table) = progression_definitions.and_then(|definitions| tables.get(&definitions.table)) else {
+        warn!("Progression table not loaded, falling back to the default level curve");
+        return;
+    };
+
+    if let Err(errors) = table.validate() {
+        for error in errors {
+            warn!("Progression table: {error}");
+        }
+    }
+
+    progression.0 = table.clone();
+}
+
+/// Pick up progression table changes on disk for the next experience earned
+pub fn reload_progression_definitions(
+    mut events: EventReader<AssetEvent<ProgressionTable>>,
+    tables: Res<Assets<ProgressionTable>>,
+    mut progression: ResMut<Progression>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event
+            && let Some(table) = tables.get(*id)
+        {
+            progression.0 = table.clone();
+        }
+    }
+}
+
+/// System that awards the victim's experience to its killer, if the killer can level up
+pub fn award_experience_system(
+    mut death_events: EventReader<EntityDeathEvent>,
+    progression: Res<Progression>,
+    victim_query: Query<&ExperienceValue>,
+    mut killer_query: Query<(Option<&Name>, &mut Experience)>,
+) {
+    for death_event in death_events.read() {
+        let Some(killer) = death_event.killer else {
+            continue;
+        };
+        let (Ok(value), Ok((name, mut experience))) =
+            (victim_query.get(death_event.entity), killer_query.get_mut(killer))
+        else {
+            continue;
+        };
+
+        let name = name.map_or("Something", Name::as_str);
+        let levels = experience.gain(value.0, &progression);
+        info!("{} earned {} experience", name, value.0);
+        if levels > 0 {
+            info!(
+                "{} reached level {}! {} stat points to spend",
+                name, experience.level, experience.unspent_points
+            );
+        }
+    }
+}
@@ -3,13 +3,25 @@ use leafwing_input_manager::prelude::*;
 use std::time::Duration;
 
 use crate::{
-    core::{states::GameState, types::ActionType},
+    core::{
+        actions::RATIONS,
+        components::{AITag, DeadTag, Description, Faction, Inventory, Position, SpecialAttacks},
+        resources::{CurrentMap, FovMap, TurnQueue},
+        states::GameState,
+        types::ActionType,
+    },
     debug_turns,
     gameplay::{
-        player::{actions::PlayerAction, components::AwaitingInput},
+        enemies::components::{AIBehavior, Grudges},
+        factions::FactionRegistry,
+        player::{
+            actions::PlayerAction,
+            components::{AwaitingInput, ExamineReport},
+        },
         turns::components::TurnActor,
+        world::components::Feature,
     },
-    prelude::core::PlayerTag,
+    prelude::{assets::AIBehaviorType, core::PlayerTag},
 };
 
 // ============================================================================
@@ -33,7 +45,23 @@ pub fn player_input_system(
 
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
-    player_query: Single<(Entity, &ActionState<PlayerAction>, &mut TurnActor), With<PlayerTag>>,
+    mut examine_report: ResMut<ExamineReport>,
+    fov_map: Res<FovMap>,
+    current_map: Res<CurrentMap>,
+    factions: Res<FactionRegistry>,
+    player_query: Single<
+        (
+            Entity,
+            &ActionState<PlayerAction>,
+            &mut TurnActor,
+            &Position,
+            Option<&Faction>,
+            Option<&Inventory>,
+            Option<&SpecialAttacks>,
+        ),
+        With<PlayerTag>,
+    >,
+    foe_query: Query<(&Position, Option<&Faction>), (With<AITag>, Without<DeadTag>)>,
 ) {
     // Tick timer until duration is met.
     if !timer.finished() {
@@ -41,14 +69,87 @@ pub fn player_input_system(
     }
 
     let mut action: Option<ActionType> = None;
-    let (entity, action_state, mut p_actor) = player_query.into_inner();
+    let (entity, action_state, mut p_actor, &player_pos, player_faction, inventory, special_attacks) =
+        player_query.into_inner();
 
     // Actions
     if action_state.just_pressed(&PlayerAction::Wait) {
         action = Some(ActionType::Wait);
     }
 
-    // Movement
+    if action_state.just_pressed(&PlayerAction::Ping) {
+        action = Some(ActionType::Ping);
+    }
+
+    if action_state.just_pressed(&PlayerAction::Search) {
+        action = Some(ActionType::Search);
+    }
+
+    if action_state.just_pressed(&PlayerAction::PickUp) {
+        action = Some(ActionType::PickUp { allow_overload: false });
+    }
+
+    if action_state.just_pressed(&PlayerAction::PickUpOverloading) {
+        action = Some(ActionType::PickUp { allow_overload: true });
+    }
+
+    if action_state.just_pressed(&PlayerAction::LightTorch) {
+        action = Some(ActionType::LightTorch);
+    }
+
+    if action_state.just_pressed(&PlayerAction::DropTorch) {
+        action = Some(ActionType::DropTorch);
+    }
+
+    if action_state.just_pressed(&PlayerAction::Descend) {
+        action = Some(ActionType::Descend);
+    }
+
+    if action_state.just_pressed(&PlayerAction::Eat) {
+        match inventory.and_then(|inventory| inventory.find_item(RATIONS)) {
+            Some(slot) => action = Some(ActionType::UseItem { slot }),
+            None => info!("You have nothing to eat."),
+        }
+    }
+
+    if action_state.just_pressed(&PlayerAction::SpecialAttack) {
+        let ready =
+            special_attacks.and_then(|attacks| attacks.iter().find(|(index, _)| attacks.is_ready(*index)));
+        let player_faction = Faction::of(player_faction, true);
+        let nearest_foe = foe_query
+            .iter()
+            .filter(|(position, faction)| {
+                fov_map.is_visible(**position)
+                    && factions.is_hostile(player_faction, Faction::of(*faction, false))
+            })
+            .map(|(position, _)| *position)
+            .min_by_key(|position| position.distance_squared(&player_pos));
+
+        match (special_attacks, ready, nearest_foe) {
+            (None, ..) => info!("You know no special attacks."),
+            (Some(_), None, _) => info!("Your special attacks are not ready yet."),
+            (Some(_), Some(_), None) => info!("There is no foe in sight."),
+            (Some(_), Some((index, _)), Some(target)) => {
+                action = Some(ActionType::SpecialAttack { index, target })
+            }
+        }
+    }
+
+    if action_state.just_pressed(&PlayerAction::ToggleDoor) {
+        let door = PlayerAction::DIRECTIONS
+            .iter()
+            .filter_map(|input_direction| input_direction.direction())
+            .map(|direction| player_pos + direction.coord())
+            .find(|position| matches!(current_map.get_feature_kind(*position), Some(Feature::Door { .. })));
+
+        match door {
+            Some(position) => action = Some(ActionType::ToggleDoor(position)),
+            None => info!("There is no door here."),
+        }
+    }
+
+    // Movement, or digging that way while Dig is held
+    let digging = action_state.pressed(&PlayerAction::Dig);
     for input_direction in PlayerAction::DIRECTIONS {
         if (action_state.just_pressed(&input_direction)
             || (action_state.pressed(&input_direction)
@@ -57,7 +158,11 @@ pub fn player_input_system(
             && let Some(direction) = input_direction.direction()
         {
             timer.reset();
-            action = Some(ActionType::MoveDelta(direction));
+            action = Some(if digging {
+                ActionType::Dig(player_pos + direction.coord())
+            } else {
+                ActionType::MoveDelta(direction)
+            });
         }
     }
 
@@ -69,7 +174,77 @@ pub fn player_input_system(
 
         commands.entity(entity).remove::<AwaitingInput>();
 
+        // Acting moves things along, so what was seen before no longer holds
+        if !examine_report.lines.is_empty() {
+            examine_report.lines.clear();
+        }
+
         // After player action is gathered, move to ProcessTurns to execute all actions in order
         next_state.set(GameState::ProcessTurns);
     }
 }
+
+// ============================================================================
+// EXAMINE SYSTEMS
+// ============================================================================
+
+/// System that describes every actor in view when the player looks around, on the HUD and in the
+/// log. Looking is free.
+pub fn examine_system(
+    turn_queue: Res<TurnQueue>,
+    fov_map: Res<FovMap>,
+    mut examine_report: ResMut<ExamineReport>,
+    player_query: Single<(Entity, &ActionState<PlayerAction>), With<PlayerTag>>,
+    ai_query: Query<
+        (&Name, &Position, Option<&Description>, &AIBehavior, Option<&Grudges>),
+        (With<AITag>, Without<DeadTag>),
+    >,
+) {
+    let (player, action_state) = player_query.into_inner();
+    if !action_state.just_pressed(&PlayerAction::Look) {
+        return;
+    }
+
+    let now = turn_queue.current_time();
+    let mut lines: Vec<String> = ai_query
+        .iter()
+        .filter(|(_, position, ..)| fov_map.is_visible(**position))
+        .map(|(name, _, description, behavior, grudges)| {
+            examine_text(name, description, behavior, grudges, player, now)
+        })
+        .collect();
+
+    if lines.is_empty() {
+        lines.push("You see nothing of interest.".to_string());
+    }
+    for line in &lines {
+        info!("{line}");
+    }
+
+    examine_report.lines = lines;
+}
+
+/// One line describing an actor and how it is disposed toward the player
+pub fn examine_text(
+    name: &str,
+    description: Option<&Description>,
+    behavior: &AIBehavior,
+    grudges: Option<&Grudges>,
+    player: Entity,
+    now: u64,
+) -> String {
+    let disposition = match grudges {
+        Some(grudges) if grudges.holds_against(player, now) => "enraged at you",
+        Some(grudges) if grudges.is_provoked(now) => "provoked",
+        _ => match behavior.behavior_type {
+            AIBehaviorType::Hostile => "hostile",
+            AIBehaviorType::Passive => "skittish",
+            AIBehaviorType::Neutral => "indifferent",
+        },
+    };
+
+    match description {
+        Some(description) => format!("{name} ({disposition}): {}", description.0),
+        None => format!("{name} ({disposition})"),
+    }
+}
@@ -9,10 +9,13 @@ use crate::core::{
     types::{ActionType, ActionTypeWrapper, GameAction},
 };
 
+/// Speed at which actions take exactly their base time
+pub const BASE_SPEED: u32 = 1000;
+
 /// Component for entities that participate in the turn-based system
 #[derive(Component, Debug)]
 pub struct TurnActor {
-    /// Base speed for calculating action times
+    /// Speed relative to [`BASE_SPEED`]; faster actors get through their actions sooner
     pub speed: u32,
     /// Whether this actor is alive and should participate in turns
     pub alive: bool,
@@ -73,6 +76,9 @@ impl TurnActor {
     /// Set new base speed
     pub fn set_speed(&mut self, speed: u32) { self.speed = speed; }
 
+    /// Scale an action's duration by how fast this actor is
+    pub fn pace(&self, duration: u64) -> u64 { duration * BASE_SPEED as u64 / self.speed.max(1) as u64 }
+
     /// Get number of queued actions
     pub fn action_count(&self) -> usize { self.actions.len() }
 
@@ -89,9 +95,7 @@ impl From<&TurnActorData> for TurnActor {
 }
 
 impl Default for TurnActor {
-    fn default() -> Self {
-        Self::new(100) // Default speed of 100
-    }
+    fn default() -> Self { Self::new(BASE_SPEED) }
 }
 
 // Convenience methods for common action types
@@ -120,3 +124,33 @@ impl TurnActor {
         self.queue_action(ActionType::MoveDelta(direction))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        components::{BaseAttributes, Stats},
+        resources::TurnQueue,
+        types::MOVE_DELTA_TIME,
+    };
+
+    #[test]
+    fn test_agile_actor_acts_sooner() {
+        let base =
+            BaseAttributes { max_health: 100, carry_capacity: 150.0, speed: BASE_SPEED, view_radius: 8 };
+        let plodder = TurnActor::new(base.derive(&Stats::balanced(10), None).speed);
+        let mut agile_stats = Stats::balanced(10);
+        agile_stats.agility = 16;
+        let sprinter = TurnActor::new(base.derive(&agile_stats, None).speed);
+
+        assert_eq!(plodder.pace(MOVE_DELTA_TIME), MOVE_DELTA_TIME);
+        assert!(sprinter.pace(MOVE_DELTA_TIME) < MOVE_DELTA_TIME);
+
+        let (slow, fast) = (Entity::from_raw(1), Entity::from_raw(2));
+        let mut turn_queue = TurnQueue::default();
+        turn_queue.schedule_turn(slow, plodder.pace(MOVE_DELTA_TIME));
+        turn_queue.schedule_turn(fast, sprinter.pace(MOVE_DELTA_TIME));
+
+        assert_eq!(turn_queue.get_next_actor().map(|(entity, _)| entity), Some(fast));
+    }
+}
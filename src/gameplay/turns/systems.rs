@@ -4,13 +4,16 @@ use bevy::{ecs::system::SystemState, prelude::*};
 
 use crate::{
     core::{
-        components::PlayerTag,
+        components::{DeadTag, Encumbrance, PlayerTag, SpecialAttacks},
         resources::TurnQueue,
         states::GameState,
         types::{ActionTypeWrapper, GameAction, GameError},
     },
     debug_turns,
-    gameplay::{player::components::AwaitingInput, turns::components::TurnActor},
+    gameplay::{
+        player::{components::AwaitingInput, hunger},
+        turns::components::TurnActor,
+    },
 };
 
 const MAX_ITERATIONS: u32 = 1000;
@@ -46,6 +49,12 @@ pub fn process_turns(world: &mut World) {
                 break;
             }
 
+            // The dead stay in the world as corpses until the queue next sheds them
+            if world.get::<DeadTag>(entity).is_some() {
+                debug_turns!("Skipping the turn of dead entity {entity:?}");
+                continue;
+            }
+
             let (is_player, action_opt);
             {
                 // Borrow world only for this inner scope
@@ -81,10 +90,25 @@ pub fn process_turns(world: &mut World) {
             };
 
             // Process the action using the new trait-based system
+            let action_type = action.action_type();
             match execute_action(world, entity, action) {
                 Ok(d_time) => {
                     debug_turns!("Action executed took {d_time}ms");
 
+                    // Special attacks cool down with every action their owner takes
+                    if let Some(mut special_attacks) = world.get_mut::<SpecialAttacks>(entity) {
+                        special_attacks.tick();
+                    }
+
+                    // Quick actors get through the same action in less time
+                    let d_time = world.get::<TurnActor>(entity).map_or(d_time, |actor| actor.pace(d_time));
+
+                    // A heavy load slows everything down
+                    let d_time = world.get::<Encumbrance>(entity).map_or(d_time, |load| load.slow(d_time));
+
+                    // Time spent working burns through food
+                    hunger::exert(world, entity, action_type, d_time);
+
                     // Defensive check – keep the queue healthy
                     let clamped = d_time.min(60_000); // 60 s upper bound (example)
                     turn_queue.schedule_turn(entity, time.saturating_add(clamped));
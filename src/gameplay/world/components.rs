@@ -1,6 +1,17 @@
-use bevy::prelude::*;
-
-use crate::core::components::Description;
+use bevy::{
+    ecs::{component::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use echos_assets::{entities::MovementType, terrain::TerrainLightData};
+
+use crate::{
+    core::{
+        components::{Description, Position},
+        pathfinding,
+        resources::CurrentMap,
+    },
+    gameplay::world::terrain::with_terrain_registry,
+};
 
 /// Represents the different types of underground environments
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
@@ -9,109 +20,186 @@ pub enum UndergroundType {
     Cave,
 }
 
-#[derive(Component, Reflect, Default, PartialEq, Eq, Clone, Debug, Copy)]
+/// Terrain id into the data-driven terrain registry
+///
+/// Properties come from `assets/terrain/*.terrain.ron`; the constants below are the built-in
+/// terrain that generation code refers to directly.
+#[derive(Component, Reflect, Default, PartialEq, Eq, Hash, Clone, Debug, Copy)]
 #[reflect(Component)]
 #[require(Description)]
-pub enum TerrainType {
-    #[default]
-    Floor,
-    Wall,
-
-    // Underground tiles
-    // MineWall,
-    // MineFloor,
-    // CaveWall,
-    // CaveFloor,
-
-    // Ore types
-    // IronOre,
-    // CopperOre,
-    // SonoriteOre,       // Resonant ore
-    // GlimmerstoneOre,   // Luminous ore
-    // WhisperingIronOre, // Exotic/Cursed ore
-
-    // Special features
-    // Door,
+pub struct TerrainType(pub u16);
+
+impl TerrainType {
+    pub const FLOOR: Self = Self(0);
+    pub const WALL: Self = Self(1);
+    pub const STAIRS_UP: Self = Self(2);
+    pub const STAIRS_DOWN: Self = Self(3);
+    pub const RUBBLE: Self = Self(4);
+    pub const SHALLOW_WATER: Self = Self(5);
+    pub const DEEP_WATER: Self = Self(6);
+    pub const CHASM: Self = Self(7);
+    pub const ORE_VEIN: Self = Self(8);
+
+    /// Look up a terrain type by its definition name
+    pub fn from_name(name: &str) -> Option<Self> { with_terrain_registry(|registry| registry.id_of(name)) }
+
+    /// Definition name of this terrain
+    pub fn name(self) -> String { with_terrain_registry(|registry| registry.get(self).name.clone()) }
+
+    /// ASCII glyph of this terrain
+    pub fn glyph(self) -> char { with_terrain_registry(|registry| registry.get(self).glyph) }
+
+    pub fn texture_index(self) -> u32 { with_terrain_registry(|registry| registry.get(self).atlas_index) }
+
+    pub fn description(&self) -> String {
+        with_terrain_registry(|registry| registry.get(*self).description.clone())
+    }
+
+    /// Returns true if this terrain type blocks vision (walls, etc.)
+    pub fn blocks_vision(&self) -> bool { with_terrain_registry(|registry| registry.get(*self).opaque) }
+
+    /// Returns true if this tile type is walkable
+    pub fn is_walkable(self) -> bool { self.is_passable(MovementType::Walking) }
+
+    /// Returns true if an entity with the given movement type can enter this tile
+    pub fn is_passable(self, movement_type: MovementType) -> bool {
+        self.movement_cost(movement_type).is_some()
+    }
+
+    /// Returns true if any kind of mover can stand on this tile
+    pub fn is_traversable(self) -> bool {
+        [MovementType::Walking, MovementType::Flying, MovementType::Swimming, MovementType::Burrowing]
+            .into_iter()
+            .any(|movement_type| self.is_passable(movement_type))
+    }
+
+    /// Cost multiplier for entering this tile, or `None` if the movement type can't enter it
+    pub fn movement_cost(self, movement_type: MovementType) -> Option<u32> {
+        with_terrain_registry(|registry| registry.get(self).movement_cost(movement_type))
+    }
+
+    /// Light emitted by this tile, if any
+    pub fn light(self) -> Option<TerrainLightData> {
+        with_terrain_registry(|registry| registry.get(self).light)
+    }
+
+    /// Returns true if this tile type is mineable
+    pub fn is_mineable(self) -> bool { with_terrain_registry(|registry| registry.get(self).mineable) }
+
+    /// Returns true if this tile type is an ore
+    pub fn is_ore(self) -> bool { with_terrain_registry(|registry| registry.get(self).ore) }
+}
+
+/// Fixed feature occupying a tile's feature layer.
+///
+/// The `CurrentMap` feature layer follows the component: inserting it (or re-inserting it with a
+/// new state, like a door swinging open) places it at the entity's [`Position`], removing it clears
+/// the tile.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+#[require(Description, Position)]
+#[component(on_insert = sync_feature_on_map, on_remove = clear_feature_from_map)]
+pub enum Feature {
+    Door { open: bool },
+    Trap { armed: bool },
+    Altar,
     StairsUp,
     StairsDown,
-    // UnstableWall,
 }
 
-impl TerrainType {
-    pub fn texture_index(self) -> u32 {
+impl Feature {
+    pub fn description(&self) -> String {
         match self {
-            // Surface tiles
-            TerrainType::Wall => 206,
-            TerrainType::Floor => 515,
-
-            // Underground tiles
-            // TerrainType::MineWall => 2,
-            // TerrainType::MineFloor => 3,
-            // TerrainType::CaveWall => 4,
-            // TerrainType::CaveFloor => 5,
-
-            // Ore types
-            // TerrainType::IronOre => 6,
-            // TerrainType::CopperOre => 7,
-            // TerrainType::SonoriteOre => 8,
-            // TerrainType::GlimmerstoneOre => 9,
-            // TerrainType::WhisperingIronOre => 10,
-
-            // Special features
-            // TerrainType::Door => 11,
-            TerrainType::StairsUp => 127,
-            TerrainType::StairsDown => 126,
-            // TerrainType::UnstableWall => 14,
+            Feature::Door { open: true } => "An open door".to_string(),
+            Feature::Door { open: false } => "A closed door".to_string(),
+            Feature::Trap { armed: true } => "A trap".to_string(),
+            Feature::Trap { armed: false } => "A sprung trap".to_string(),
+            Feature::Altar => "An altar".to_string(),
+            Feature::StairsUp => "Stairs leading up".to_string(),
+            Feature::StairsDown => "Stairs leading down".to_string(),
         }
     }
 
-    pub fn description(&self) -> String {
-        match self {
-            TerrainType::Floor => "Floor".to_string(),
-            TerrainType::Wall => "Wall".to_string(),
-
-            // TerrainType::MineWall => "Mine Wall".to_string(),
-            // TerrainType::MineFloor => "Mine Floor".to_string(),
-            // TerrainType::CaveWall => "Cave Wall".to_string(),
-            // TerrainType::CaveFloor => "Cave Floor".to_string(),
-
-            // TerrainType::IronOre => "Iron Ore".to_string(),
-            // TerrainType::CopperOre => "Copper Ore".to_string(),
-            // TerrainType::SonoriteOre => "Sonorite Ore".to_string(),
-            // TerrainType::GlimmerstoneOre => "Glimmerstone Ore".to_string(),
-            // TerrainType::WhisperingIronOre => "Whispering Iron Ore".to_string(),
-
-            // TerrainType::Door => "Door".to_string(),
-            TerrainType::StairsUp => "Stairs leading up".to_string(),
-            TerrainType::StairsDown => "Stairs leading down".to_string(),
-            // TerrainType::UnstableWall => "Unstable Wall".to_string(),
-        }
+    /// Returns true if this feature stops movement (closed doors)
+    pub fn blocks_movement(&self) -> bool { matches!(self, Feature::Door { open: false }) }
+
+    /// Returns true if this feature blocks vision (closed doors)
+    pub fn blocks_vision(&self) -> bool { matches!(self, Feature::Door { open: false }) }
+}
+
+/// Hook: place a feature on the map at its current position, with its current state
+fn sync_feature_on_map(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let (Some(position), Some(feature)) =
+        (world.get::<Position>(entity).copied(), world.get::<Feature>(entity).copied())
+    else {
+        return;
+    };
+
+    let Some(mut current_map) = world.get_resource_mut::<CurrentMap>() else {
+        return;
+    };
+
+    let revision = current_map.terrain_revision();
+    if let Err(e) = current_map.place_feature(position, entity, feature) {
+        warn!("Failed to place feature {entity} at {position:?} on map: {e}");
     }
 
-    /// Returns true if this terrain type blocks vision (walls, etc.)
-    pub fn blocks_vision(&self) -> bool {
-        match self {
-            TerrainType::Wall => true,
-            // TerrainType::Door => true, // Closed doors block vision
-            _ => false,
+    // Paths through a door that just closed (or around one that just opened) are stale
+    if current_map.terrain_revision() != revision {
+        pathfinding::utils::clear_pathfinding_cache();
+    }
+}
+
+/// Hook: drop a feature from the map's feature layer
+fn clear_feature_from_map(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if let Some(mut current_map) = world.get_resource_mut::<CurrentMap>() {
+        let revision = current_map.terrain_revision();
+        current_map.remove_feature(entity);
+        if current_map.terrain_revision() != revision {
+            pathfinding::utils::clear_pathfinding_cache();
         }
     }
+}
 
-    /// Returns true if this tile type is walkable
-    pub fn is_walkable(self) -> bool {
+/// Cosmetic mark on a tile's decoration layer
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+#[require(Description, Position)]
+#[component(on_insert = sync_decoration_on_map, on_remove = clear_decoration_from_map)]
+pub enum Decoration {
+    Blood,
+    Rubble,
+    Scorch,
+}
+
+impl Decoration {
+    pub fn description(&self) -> String {
         match self {
-            TerrainType::Floor
-            // | TerrainType::MineFloor
-            // | TerrainType::CaveFloor
-            | TerrainType::StairsUp
-            | TerrainType::StairsDown => true,
-            _ => false,
+            Decoration::Blood => "A pool of blood".to_string(),
+            Decoration::Rubble => "Scattered rubble".to_string(),
+            Decoration::Scorch => "A scorch mark".to_string(),
         }
     }
+}
 
-    /// Returns true if this tile type is mineable
-    pub fn is_mineable(self) -> bool { false }
+/// Hook: place a decoration on the map at its current position
+fn sync_decoration_on_map(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let Some(position) = world.get::<Position>(entity).copied() else {
+        return;
+    };
 
-    /// Returns true if this tile type is an ore
-    pub fn is_ore(self) -> bool { false }
+    let Some(mut current_map) = world.get_resource_mut::<CurrentMap>() else {
+        return;
+    };
+
+    if let Err(e) = current_map.place_decoration(position, entity) {
+        warn!("Failed to place decoration {entity} at {position:?} on map: {e}");
+    }
+}
+
+/// Hook: drop a decoration from the map's decoration layer
+fn clear_decoration_from_map(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if let Some(mut current_map) = world.get_resource_mut::<CurrentMap>() {
+        current_map.remove_decoration(entity);
+    }
 }
@@ -3,11 +3,22 @@ use bevy_ecs_tilemap::prelude::*;
 use brtk::grid::Grid;
 
 use crate::{
-    core::{components::Description, constants::ModelConstants},
-    gameplay::world::components::{TerrainType, UndergroundType},
+    core::{
+        components::{Description, Position},
+        constants::ModelConstants,
+    },
+    gameplay::world::{
+        components::{Decoration, Feature, TerrainType, UndergroundType},
+        terrain::with_terrain_registry,
+    },
 };
 
-use super::Room;
+use super::{PatrolGraph, Room};
+
+/// Chance that a doorway into a room gets a door
+const DOOR_CHANCE: f32 = 0.5;
+/// Chance, per point of hazard density, that a room floor tile is marked with old rubble or blood
+const FLOOR_MARK_CHANCE: f32 = 0.1;
 
 /// Configuration for level generation based on depth
 #[derive(Debug, Clone, Reflect, Resource)]
@@ -19,6 +30,14 @@ pub struct GenConfig {
 
     // Room generation
     pub rooms: Vec<Room>,
+    /// Rooms whose acoustics carry sound further
+    pub echo_chambers: Vec<Room>,
+    /// Room centers joined by the corridors between them, for guards to patrol
+    pub patrol_graph: PatrolGraph,
+    /// Doors and altars to place once the terrain is down
+    pub features: Vec<(Position, Feature)>,
+    /// Cosmetic marks to scatter on the floor
+    pub decorations: Vec<(Position, Decoration)>,
     pub room_count: (usize, usize), // min and max rooms
     pub room_size: (usize, usize),  // min and max room size
 
@@ -38,6 +57,10 @@ impl Default for GenConfig {
             ore_density: 0.3,
             room_size: (4, 8),
             rooms: Vec::new(),
+            echo_chambers: Vec::new(),
+            patrol_graph: PatrolGraph::default(),
+            features: Vec::new(),
+            decorations: Vec::new(),
             room_count: (3, 6),
             hazard_density: 0.1,
             echo_chamber_chance: 0.1,
@@ -51,6 +74,22 @@ impl Default for GenConfig {
 }
 
 impl GenConfig {
+    /// Create a dungeon generator for a depth, using that depth's map dimensions
+    pub fn for_depth(depth: usize) -> Self {
+        let (width, height) = Self::map_size_for_depth(depth);
+        Self::new(depth, width, height)
+    }
+
+    /// Map dimensions for a depth - deeper levels sprawl further
+    pub fn map_size_for_depth(depth: usize) -> (u32, u32) {
+        match depth {
+            d if d <= 3 => (ModelConstants::MAP_WIDTH, ModelConstants::MAP_HEIGHT),
+            d if d <= 7 => (64, 32),
+            d if d <= 12 => (80, 40),
+            _ => (96, 48),
+        }
+    }
+
     /// Create a new dungeon generator with the specified dimensions and default settings
     pub fn new(depth: usize, width: u32, height: u32) -> Self {
         let underground_type = match depth {
@@ -95,7 +134,7 @@ impl GenConfig {
     /// Returns a Grid<TerrainType> representing the completed dungeon
     pub fn generate(&mut self, rng: &mut fastrand::Rng) -> Grid<TerrainType> {
         // Initialize grid with walls
-        let mut grid = Grid::new_fill((self.width, self.height), TerrainType::Wall);
+        let mut grid = Grid::new_fill((self.width, self.height), TerrainType::WALL);
 
         // Clear existing rooms
         self.rooms.clear();
@@ -109,16 +148,18 @@ impl GenConfig {
         }
 
         // Connect rooms
+        let mut corridors = Vec::new();
         if !self.rooms.is_empty() {
-            let mut rooms = self.rooms.clone();
+            let mut order: Vec<usize> = (0..self.rooms.len()).collect();
             // Sort rooms for consistent corridor generation
-            rooms.sort_by_key(|room| room.center().0 + room.center().1);
+            order.sort_by_key(|&i| self.rooms[i].center().0 + self.rooms[i].center().1);
 
             // Connect each room to the next one
-            for i in 0..rooms.len() - 1 {
-                let from = rooms[i].center();
-                let to = rooms[i + 1].center();
+            for pair in order.windows(2) {
+                let from = self.rooms[pair[0]].center();
+                let to = self.rooms[pair[1]].center();
                 self.carve_corridor(&mut grid, from, to);
+                corridors.push((pair[0], pair[1]));
             }
         }
 
@@ -127,6 +168,20 @@ impl GenConfig {
             self.place_stairs(&mut grid, rng);
         }
 
+        // Scatter difficult terrain
+        self.place_terrain_features(&mut grid, rng);
+
+        // Doors, altars and floor marks
+        self.place_room_features(&grid, rng);
+
+        // Rooms joined by corridors make the patrol graph
+        let waypoints = self.rooms.iter().map(|room| Self::patrol_waypoint(&grid, room)).collect();
+        self.patrol_graph = PatrolGraph::new(waypoints, corridors);
+
+        // Pick the rooms that echo
+        self.echo_chambers =
+            self.rooms.iter().filter(|_| rng.f32() < self.echo_chamber_chance).copied().collect();
+
         // Find player spawn point
         if let Some(player_spawn_point) = self.find_valid_position(&grid) {
             self.player_spawn_point = Some(player_spawn_point);
@@ -174,7 +229,7 @@ impl GenConfig {
     fn carve_room(&self, grid: &mut Grid<TerrainType>, room: &Room) {
         for (x, y) in room.positions() {
             if let Some(cell) = grid.get_mut((x, y)) {
-                *cell = TerrainType::Floor;
+                *cell = TerrainType::FLOOR;
             }
         }
     }
@@ -194,7 +249,7 @@ impl GenConfig {
                 x += (to.0 - x).signum();
                 let position = (x, y);
                 if grid.in_bounds(position) {
-                    grid[position] = TerrainType::Floor;
+                    grid[position] = TerrainType::FLOOR;
                 }
             }
 
@@ -202,7 +257,7 @@ impl GenConfig {
                 y += (to.1 - y).signum();
                 let position = (x, y);
                 if grid.in_bounds(position) {
-                    grid[position] = TerrainType::Floor;
+                    grid[position] = TerrainType::FLOOR;
                 }
             }
         } else {
@@ -211,7 +266,7 @@ impl GenConfig {
                 y += (to.1 - y).signum();
                 let position = (x, y);
                 if grid.in_bounds(position) {
-                    grid[position] = TerrainType::Floor;
+                    grid[position] = TerrainType::FLOOR;
                 }
             }
 
@@ -219,7 +274,7 @@ impl GenConfig {
                 x += (to.0 - x).signum();
                 let position = (x, y);
                 if grid.in_bounds(position) {
-                    grid[position] = TerrainType::Floor;
+                    grid[position] = TerrainType::FLOOR;
                 }
             }
         }
@@ -248,14 +303,14 @@ impl GenConfig {
         let mut up_stair_candidates = Vec::new();
         let first_room = &self.rooms[first_room_idx];
         for (x, y) in first_room.inner_positions() {
-            if grid.in_bounds((x, y)) && grid[(x, y)] == TerrainType::Floor {
+            if grid.in_bounds((x, y)) && grid[(x, y)] == TerrainType::FLOOR {
                 // Check if position is away from walls (has mostly floor neighbors)
                 let floor_neighbors = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)]
                     .iter()
                     .filter(|&(dx, dy)| {
                         let nx = x + dx;
                         let ny = y + dy;
-                        grid.in_bounds((nx, ny)) && grid[(nx, ny)] == TerrainType::Floor
+                        grid.in_bounds((nx, ny)) && grid[(nx, ny)] == TerrainType::FLOOR
                     })
                     .count();
 
@@ -270,14 +325,14 @@ impl GenConfig {
         let mut down_stair_candidates = Vec::new();
         let last_room = &self.rooms[last_room_idx];
         for (x, y) in last_room.inner_positions() {
-            if grid.in_bounds((x, y)) && grid[(x, y)] == TerrainType::Floor {
+            if grid.in_bounds((x, y)) && grid[(x, y)] == TerrainType::FLOOR {
                 // Check if position is away from walls (has mostly floor neighbors)
                 let floor_neighbors = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)]
                     .iter()
                     .filter(|&(dx, dy)| {
                         let nx = x + dx;
                         let ny = y + dy;
-                        grid.in_bounds((nx, ny)) && grid[(nx, ny)] == TerrainType::Floor
+                        grid.in_bounds((nx, ny)) && grid[(nx, ny)] == TerrainType::FLOOR
                     })
                     .count();
 
@@ -292,13 +347,141 @@ impl GenConfig {
         if !up_stair_candidates.is_empty() {
             let (x, y) = up_stair_candidates[rng.usize(0..up_stair_candidates.len())];
             // Convert to i32 for grid indexing
-            grid[(x as i32, y as i32)] = TerrainType::StairsUp;
+            grid[(x as i32, y as i32)] = TerrainType::STAIRS_UP;
         }
 
         if !down_stair_candidates.is_empty() {
             let (x, y) = down_stair_candidates[rng.usize(0..down_stair_candidates.len())];
             // Convert to i32 for grid indexing
-            grid[(x as i32, y as i32)] = TerrainType::StairsDown;
+            grid[(x as i32, y as i32)] = TerrainType::STAIRS_DOWN;
+        }
+    }
+
+    /// Scatter difficult terrain (rubble, ore veins, water pools and chasms) inside rooms
+    ///
+    /// Pools and chasms are kept two tiles away from room edges so walkers can always skirt around
+    /// them, while flyers and swimmers get shortcuts.
+    fn place_terrain_features(&self, grid: &mut Grid<TerrainType>, rng: &mut fastrand::Rng) {
+        let rubble_chance = self.hazard_density * 0.3;
+        let ore_chance = self.ore_density * 0.1;
+
+        // Any terrain flagged as ore in the terrain table can show up as a vein: loose ores on room
+        // floors, solid ores embedded in the surrounding rock
+        let (floor_ores, wall_ores): (Vec<_>, Vec<_>) =
+            with_terrain_registry(|registry| registry.ores()).into_iter().partition(|ore| ore.is_walkable());
+
+        for room in &self.rooms {
+            for (x, y) in room.inner_positions() {
+                if grid[(x, y)] != TerrainType::FLOOR {
+                    continue;
+                }
+
+                let roll = rng.f32();
+                if roll < rubble_chance {
+                    grid[(x, y)] = TerrainType::RUBBLE;
+                } else if roll < rubble_chance + ore_chance && !floor_ores.is_empty() {
+                    grid[(x, y)] = floor_ores[rng.usize(..floor_ores.len())];
+                }
+            }
+
+            if !wall_ores.is_empty() {
+                let outer = Room::new(room.x - 1, room.y - 1, room.width + 2, room.height + 2);
+                for (x, y) in outer.border_positions() {
+                    if grid.in_bounds((x, y)) && grid[(x, y)] == TerrainType::WALL && rng.f32() < ore_chance {
+                        grid[(x, y)] = wall_ores[rng.usize(..wall_ores.len())];
+                    }
+                }
+            }
+
+            // Pools and chasms need a room big enough to walk around them
+            if room.width < 6 || room.height < 6 {
+                continue;
+            }
+
+            let feature = if rng.f32() < self.special_feature_chance * 4.0 {
+                Some(TerrainType::CHASM)
+            } else if rng.f32() < self.hazard_density * 2.0 {
+                Some(TerrainType::DEEP_WATER)
+            } else {
+                None
+            };
+
+            let Some(feature) = feature else {
+                continue;
+            };
+
+            let (min_x, max_x) = (room.x + 2, room.x + room.width - 3);
+            let (min_y, max_y) = (room.y + 2, room.y + room.height - 3);
+            for x in min_x..=max_x {
+                for y in min_y..=max_y {
+                    if matches!(grid[(x, y)], TerrainType::STAIRS_UP | TerrainType::STAIRS_DOWN) {
+                        continue;
+                    }
+
+                    // Pools get a shallow rim around a deep center
+                    let on_rim = x == min_x || x == max_x || y == min_y || y == max_y;
+                    grid[(x, y)] = match feature {
+                        TerrainType::DEEP_WATER if on_rim => TerrainType::SHALLOW_WATER,
+                        _ => feature,
+                    };
+                }
+            }
+        }
+    }
+
+    /// Hang doors in doorways, set altars in some rooms and scatter marks on room floors
+    ///
+    /// Doors start open so nothing is walled in; anyone can close them behind themselves.
+    fn place_room_features(&mut self, grid: &Grid<TerrainType>, rng: &mut fastrand::Rng) {
+        self.features.clear();
+        self.decorations.clear();
+
+        let is_wall = |x: i32, y: i32| grid.get((x, y)).is_none_or(|terrain| !terrain.is_traversable());
+
+        for room in &self.rooms {
+            // A doorway is a corridor tile just outside the room, squeezed between two walls
+            let outer = Room::new(room.x - 1, room.y - 1, room.width + 2, room.height + 2);
+            for (x, y) in outer.border_positions() {
+                let on_side = x == outer.x || x == outer.x + outer.width - 1;
+                let on_end = y == outer.y || y == outer.y + outer.height - 1;
+                if on_side == on_end || grid.get((x, y)) != Some(&TerrainType::FLOOR) {
+                    continue;
+                }
+
+                let squeezed = if on_side {
+                    is_wall(x, y - 1) && is_wall(x, y + 1)
+                } else {
+                    is_wall(x - 1, y) && is_wall(x + 1, y)
+                };
+                let position = Position::new(x, y);
+                if squeezed
+                    && rng.f32() < DOOR_CHANCE
+                    && !self.features.iter().any(|(placed, _)| *placed == position)
+                {
+                    self.features.push((position, Feature::Door { open: true }));
+                }
+            }
+
+            let center = Position::from(room.center());
+            if grid.get(room.center()) == Some(&TerrainType::FLOOR) && rng.f32() < self.special_feature_chance
+            {
+                self.features.push((center, Feature::Altar));
+            }
+
+            for (x, y) in room.inner_positions() {
+                let position = Position::new(x, y);
+                if grid.get((x, y)) != Some(&TerrainType::FLOOR) || position == center {
+                    continue;
+                }
+
+                let roll = rng.f32();
+                let mark_chance = self.hazard_density * FLOOR_MARK_CHANCE;
+                if roll < mark_chance {
+                    self.decorations.push((position, Decoration::Rubble));
+                } else if roll < mark_chance * 1.5 {
+                    self.decorations.push((position, Decoration::Blood));
+                }
+            }
         }
     }
 
@@ -333,6 +516,16 @@ impl GenConfig {
         tile_storage
     }
 
+    /// The walkable tile nearest a room's center, since pools and chasms can swallow the center
+    fn patrol_waypoint(grid: &Grid<TerrainType>, room: &Room) -> Position {
+        let center = Position::from(room.center());
+        room.positions()
+            .map(Position::from)
+            .filter(|position| grid.get((*position).into()).is_some_and(|terrain| terrain.is_walkable()))
+            .min_by_key(|position| position.distance_squared(&center))
+            .unwrap_or(center)
+    }
+
     // Helper function to find a valid floor position
     fn find_valid_position(&self, grid: &Grid<TerrainType>) -> Option<(u32, u32)> {
         let mut rng = fastrand::Rng::new();
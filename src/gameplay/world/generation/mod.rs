@@ -3,3 +3,6 @@ pub use gen_config::*;
 
 mod room;
 pub use room::*;
+
+mod patrol;
+pub use patrol::*;
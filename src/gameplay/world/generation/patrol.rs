@@ -0,0 +1,93 @@
+use std::cmp::Reverse;
+
+use bevy::reflect::Reflect;
+
+use crate::core::components::Position;
+
+/// Waypoints worth patrolling between, one per room, joined where a corridor links two rooms
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+pub struct PatrolGraph {
+    pub nodes: Vec<Position>,
+    /// Pairs of node indices joined by a corridor
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl PatrolGraph {
+    pub fn new(nodes: Vec<Position>, edges: Vec<(usize, usize)>) -> Self { Self { nodes, edges } }
+
+    pub fn is_empty(&self) -> bool { self.nodes.is_empty() }
+
+    /// Nodes one corridor away from `node`
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges.iter().filter_map(move |&(a, b)| match node {
+            _ if a == node => Some(b),
+            _ if b == node => Some(a),
+            _ => None,
+        })
+    }
+
+    /// The node closest to a position
+    pub fn nearest_node(&self, position: Position) -> Option<usize> {
+        (0..self.nodes.len()).min_by_key(|&node| self.nodes[node].distance_squared(&position))
+    }
+
+    /// A patrol route of up to `stops` rooms starting from the one nearest `start`.
+    ///
+    /// The route heads out along the corridors and then retraces its steps, so walking it in a
+    /// loop never cuts through rock. It is empty if there is nowhere to go.
+    pub fn route_from(&self, start: Position, stops: usize) -> Vec<Position> {
+        let Some(mut node) = self.nearest_node(start) else {
+            return Vec::new();
+        };
+
+        let mut outward = vec![node];
+        while outward.len() < stops {
+            // Head for the best connected room, so the route doesn't run into a dead end early
+            let Some(next) = self
+                .neighbors(node)
+                .filter(|next| !outward.contains(next))
+                .max_by_key(|&next| (self.neighbors(next).count(), Reverse(next)))
+            else {
+                break;
+            };
+            outward.push(next);
+            node = next;
+        }
+
+        if outward.len() < 2 {
+            return Vec::new();
+        }
+
+        // Back the way it came, stopping short of the start, where the loop picks up again
+        let inward = outward[1..outward.len() - 1].iter().rev().copied();
+        outward.iter().copied().chain(inward).map(|node| self.nodes[node]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain() -> PatrolGraph {
+        let nodes = (0..4).map(|i| Position::new(i * 10, 5)).collect();
+        PatrolGraph::new(nodes, vec![(0, 1), (1, 2), (2, 3)])
+    }
+
+    #[test]
+    fn test_route_heads_out_and_back() {
+        let route = chain().route_from(Position::new(11, 4), 3);
+
+        assert_eq!(
+            route,
+            vec![Position::new(10, 5), Position::new(20, 5), Position::new(30, 5), Position::new(20, 5)]
+        );
+    }
+
+    #[test]
+    fn test_route_needs_somewhere_to_go() {
+        let single = PatrolGraph::new(vec![Position::new(3, 3)], Vec::new());
+
+        assert!(single.route_from(Position::new(3, 3), 4).is_empty());
+        assert!(PatrolGraph::default().route_from(Position::new(0, 0), 4).is_empty());
+    }
+}
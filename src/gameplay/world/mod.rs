@@ -2,13 +2,34 @@ use bevy::prelude::*;
 
 pub mod components;
 pub mod generation;
+pub mod scaling;
+pub mod spawn_table;
 pub mod spawning;
 pub mod systems;
+pub mod terrain;
 
 /// World plugin that handles map generation and world management
 pub fn plugin(app: &mut App) {
     // Register world components
-    app.register_type::<components::TerrainType>().register_type::<components::UndergroundType>();
+    app.register_type::<components::TerrainType>()
+        .register_type::<components::UndergroundType>()
+        .register_type::<components::Feature>()
+        .register_type::<components::Decoration>();
+
+    // Level generation settings; the map is sized from these when a level is built
+    app.init_resource::<generation::GenConfig>();
+
+    // Enemies that spawn at the current depth; rebuilt whenever a level is
+    app.init_resource::<spawn_table::SpawnTable>();
+
+    // How much tougher enemies get at the current depth
+    app.init_resource::<scaling::MonsterScaling>();
+
+    // Hot-reload terrain definitions
+    app.add_systems(Update, terrain::reload_terrain_definitions);
+
+    // Hot-reload monster scaling
+    app.add_systems(Update, scaling::reload_scaling_definitions);
 
     // Add world systems
     // Note: spawn systems are typically called from state transitions, not Update
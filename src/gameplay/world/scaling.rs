@@ -0,0 +1,68 @@
+use bevy::{asset::AssetEvent, prelude::*};
+use echos_assets::{
+    entities::EntityDefinition,
+    scaling::{EliteVariant, ScalingDefinitions, ScalingTable},
+};
+
+use crate::gameplay::world::generation::GenConfig;
+
+/// How enemies spawned on the current level are scaled
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MonsterScaling {
+    pub depth: usize,
+    pub table: ScalingTable,
+}
+
+impl MonsterScaling {
+    /// Roll which elite variant, if any, the next enemy becomes
+    pub fn roll_variant(&self, rng: &mut fastrand::Rng) -> Option<&EliteVariant> {
+        self.table.roll_variant(self.depth as u32, rng)
+    }
+
+    /// A definition scaled for the current depth and dressed up as a variant
+    pub fn scale(&self, definition: &EntityDefinition, variant: Option<&EliteVariant>) -> EntityDefinition {
+        self.table.scale(definition, self.depth as u32, variant)
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Scale enemies for the level about to be generated with the loaded scaling table
+pub fn apply_scaling_definitions(
+    gen_config: Res<GenConfig>,
+    mut monster_scaling: ResMut<MonsterScaling>,
+    scaling_definitions: Option<Res<ScalingDefinitions>>,
+    tables: Res<Assets<ScalingTable>>,
+) {
+    monster_scaling.depth = gen_config.depth;
+
+    let Some(table) = scaling_definitions.and_then(|definitions| tables.get(&definitions.table)) else {
+        warn!("Scaling table not loaded, enemies won't scale with depth");
+        return;
+    };
+
+    if let Err(errors) = table.validate() {
+        for error in errors {
+            warn!("Scaling table: {error}");
+        }
+    }
+
+    monster_scaling.table = table.clone();
+}
+
+/// Pick up scaling table changes on disk for the next enemies spawned
+pub fn reload_scaling_definitions(
+    mut events: EventReader<AssetEvent<ScalingTable>>,
+    tables: Res<Assets<ScalingTable>>,
+    mut monster_scaling: ResMut<MonsterScaling>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event
+            && let Some(table) = tables.get(*id)
+        {
+            monster_scaling.table = table.clone();
+        }
+    }
+}
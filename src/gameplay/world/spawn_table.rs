@@ -0,0 +1,288 @@
+use std::fmt::Write;
+
+use bevy::{platform::collections::HashSet, prelude::*};
+use echos_assets::entities::{EntityDefinition, EntityDefinitions};
+
+use crate::{
+    core::{components::Position, resources::Map},
+    gameplay::world::generation::{GenConfig, Room},
+};
+
+/// Chance a roll on the spawn table turns up something from deeper down
+pub const OUT_OF_DEPTH_CHANCE: f32 = 0.05;
+/// How many levels below its home an out-of-depth spawn can wander up from
+pub const OUT_OF_DEPTH_REACH: u32 = 3;
+
+/// Room floor tiles per enemy living in it
+const TILES_PER_ENEMY: i32 = 20;
+/// Most enemies a single room is stocked with
+const MAX_ROOM_POPULATION: usize = 4;
+
+/// An enemy that can be rolled on a spawn table
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct SpawnEntry {
+    /// Definition name, as passed to `spawn_ai`
+    pub name: String,
+    pub weight: f32,
+    pub level_range: (u32, u32),
+}
+
+/// The enemies that spawn at one depth, weighted by their `spawn_weight`
+#[derive(Debug, Clone, Default, PartialEq, Resource, Reflect)]
+pub struct SpawnTable {
+    pub depth: usize,
+    /// Enemies whose `level_range` covers this depth
+    pub native: Vec<SpawnEntry>,
+    /// Enemies from the next few levels down, rolled only rarely
+    pub out_of_depth: Vec<SpawnEntry>,
+}
+
+impl SpawnTable {
+    /// Build the table for a depth from named enemy definitions
+    pub fn for_depth<'a>(
+        depth: usize,
+        definitions: impl IntoIterator<Item = (&'a str, &'a EntityDefinition)>,
+    ) -> Self {
+        let mut table = Self { depth, ..Default::default() };
+        let level = depth as u32;
+
+        for (name, definition) in definitions {
+            if definition.spawn_weight() <= 0.0 {
+                continue;
+            }
+
+            let entry = SpawnEntry {
+                name: name.to_string(),
+                weight: definition.spawn_weight(),
+                level_range: definition.level_range(),
+            };
+
+            let (min, _) = entry.level_range;
+            if definition.spawns_at(level) {
+                table.native.push(entry);
+            } else if min > level && min - level <= OUT_OF_DEPTH_REACH {
+                table.out_of_depth.push(entry);
+            }
+        }
+
+        // Asset maps iterate in no particular order; keep rolls and dumps repeatable
+        table.native.sort_by(|a, b| a.name.cmp(&b.name));
+        table.out_of_depth.sort_by(|a, b| a.name.cmp(&b.name));
+        table
+    }
+
+    /// Build the table for a depth from every loaded enemy definition
+    pub fn from_definitions(
+        depth: usize,
+        entity_definitions: &EntityDefinitions,
+        assets: &Assets<EntityDefinition>,
+    ) -> Self {
+        Self::for_depth(depth, entity_definitions.loaded_enemies(assets))
+    }
+
+    pub fn is_empty(&self) -> bool { self.native.is_empty() && self.out_of_depth.is_empty() }
+
+    /// Roll an enemy to spawn
+    pub fn sample(&self, rng: &mut fastrand::Rng) -> Option<&str> {
+        let out_of_depth = self.native.is_empty() || rng.f32() < OUT_OF_DEPTH_CHANCE;
+        let entries =
+            if out_of_depth && !self.out_of_depth.is_empty() { &self.out_of_depth } else { &self.native };
+
+        let mut roll = rng.f32() * total_weight(entries);
+        for entry in entries {
+            if roll < entry.weight {
+                return Some(&entry.name);
+            }
+            roll -= entry.weight;
+        }
+
+        // Rounding can carry the roll past the last entry
+        entries.last().map(|entry| entry.name.as_str())
+    }
+
+    /// The chance a single roll spawns the named enemy
+    pub fn chance(&self, name: &str) -> f32 {
+        let (native_share, out_of_depth_share) = match (self.native.is_empty(), self.out_of_depth.is_empty())
+        {
+            (false, false) => (1.0 - OUT_OF_DEPTH_CHANCE, OUT_OF_DEPTH_CHANCE),
+            (false, true) => (1.0, 0.0),
+            (true, _) => (0.0, 1.0),
+        };
+
+        let share = |entries: &[SpawnEntry], share: f32| {
+            entries
+                .iter()
+                .find(|entry| entry.name == name)
+                .map_or(0.0, |entry| share * entry.weight / total_weight(entries))
+        };
+
+        share(&self.native, native_share) + share(&self.out_of_depth, out_of_depth_share)
+    }
+
+    /// A readable dump of the table, one enemy per line with its odds
+    pub fn describe(&self) -> String {
+        let mut dump = format!("Spawn table for depth {}", self.depth);
+        if self.is_empty() {
+            dump.push_str("\n  (nothing spawns here)");
+        }
+
+        for (entries, note) in [(&self.native, ""), (&self.out_of_depth, " (out of depth)")] {
+            for entry in entries {
+                let (min, max) = entry.level_range;
+                let levels = if max == u32::MAX { format!("{min}+") } else { format!("{min}-{max}") };
+                let _ = write!(
+                    dump,
+                    "\n  {:<20} weight {:>5.2}  levels {:<6} {:>5.1}%{note}",
+                    entry.name,
+                    entry.weight,
+                    levels,
+                    self.chance(&entry.name) * 100.0,
+                );
+            }
+        }
+
+        dump
+    }
+}
+
+fn total_weight(entries: &[SpawnEntry]) -> f32 { entries.iter().map(|entry| entry.weight).sum() }
+
+/// How many enemies a room is stocked with: bigger rooms and deeper levels hold more, up to a cap
+pub fn room_budget(room: &Room, depth: usize) -> usize {
+    let area = room.width * room.height;
+    ((area / TILES_PER_ENEMY) as usize + depth / 4).min(MAX_ROOM_POPULATION)
+}
+
+/// Where to put each room's enemies, up to its budget.
+///
+/// Rooms holding an occupied tile (the player's, to begin with) are left empty so nobody wakes up
+/// next to a monster.
+pub fn room_spawn_positions(
+    rooms: &[Room],
+    depth: usize,
+    map: &Map,
+    occupied: &HashSet<Position>,
+    rng: &mut fastrand::Rng,
+) -> Vec<Position> {
+    let mut positions = Vec::new();
+
+    for room in rooms {
+        if occupied.iter().any(|position| room.contains((position.x(), position.y()))) {
+            continue;
+        }
+
+        let mut free: Vec<Position> = room
+            .positions()
+            .map(Position::from)
+            .filter(|tile| map.is_walkable(*tile) && map.get_actor(*tile).is_none())
+            .collect();
+        rng.shuffle(&mut free);
+
+        free.truncate(room_budget(room, depth));
+        positions.extend(free);
+    }
+
+    positions
+}
+
+/// Rebuild the spawn table for the level about to be generated
+pub fn build_spawn_table(
+    mut commands: Commands,
+    gen_config: Res<GenConfig>,
+    entity_definitions: Option<Res<EntityDefinitions>>,
+    assets: Option<Res<Assets<EntityDefinition>>>,
+) {
+    let table = match (entity_definitions, assets) {
+        (Some(entity_definitions), Some(assets)) => {
+            SpawnTable::from_definitions(gen_config.depth, &entity_definitions, &assets)
+        }
+        _ => {
+            warn!("Entity definitions not available, depth {} will spawn no enemies", gen_config.depth);
+            SpawnTable { depth: gen_config.depth, ..Default::default() }
+        }
+    };
+
+    info!("{}", table.describe());
+    commands.insert_resource(table);
+}
+
+#[cfg(test)]
+mod tests {
+    use echos_assets::entities::EntityComponents;
+
+    use super::*;
+    use crate::gameplay::world::components::TerrainType;
+
+    fn enemy(weight: f32, min: u32, max: u32) -> EntityDefinition {
+        EntityDefinition::new("Enemy", "").with_components(
+            EntityComponents::new().as_ai().with_spawn_weight(weight).with_level_range(min, max),
+        )
+    }
+
+    fn table(depth: usize) -> SpawnTable {
+        let (critter, guard, berserker, dragon) =
+            (enemy(2.0, 1, 3), enemy(1.0, 1, 5), enemy(0.5, 3, 8), enemy(1.0, 12, 20));
+        SpawnTable::for_depth(
+            depth,
+            [("critter", &critter), ("guard", &guard), ("berserker", &berserker), ("dragon", &dragon)],
+        )
+    }
+
+    #[test]
+    fn test_table_filters_by_depth() {
+        let shallow = table(1);
+        let names: Vec<&str> = shallow.native.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, vec!["critter", "guard"]);
+        assert_eq!(shallow.out_of_depth.len(), 1);
+        assert_eq!(shallow.out_of_depth[0].name, "berserker");
+
+        let deep = table(6);
+        let names: Vec<&str> = deep.native.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, vec!["berserker"]);
+        assert!(deep.out_of_depth.is_empty());
+    }
+
+    #[test]
+    fn test_chances_follow_weights() {
+        let shallow = table(1);
+        let native_share = 1.0 - OUT_OF_DEPTH_CHANCE;
+
+        assert!((shallow.chance("critter") - native_share * 2.0 / 3.0).abs() < 1e-5);
+        assert!((shallow.chance("guard") - native_share / 3.0).abs() < 1e-5);
+        assert!((shallow.chance("berserker") - OUT_OF_DEPTH_CHANCE).abs() < 1e-5);
+        assert_eq!(shallow.chance("dragon"), 0.0);
+    }
+
+    #[test]
+    fn test_sample_stays_in_table() {
+        let shallow = table(1);
+        let mut rng = fastrand::Rng::with_seed(7);
+
+        for _ in 0..200 {
+            let name = shallow.sample(&mut rng).unwrap();
+            assert!(["critter", "guard", "berserker"].contains(&name));
+        }
+
+        assert!(table(30).sample(&mut rng).is_none());
+    }
+
+    #[test]
+    fn test_player_room_stays_empty() {
+        let map = Map::new_with_terrain((30, 10), TerrainType::FLOOR);
+        let (home, lair) = (Room::new(1, 1, 5, 8), Room::new(10, 1, 8, 8));
+        let occupied = HashSet::from([Position::new(3, 3)]);
+
+        let positions =
+            room_spawn_positions(&[home, lair], 1, &map, &occupied, &mut fastrand::Rng::with_seed(3));
+
+        assert_eq!(positions.len(), room_budget(&lair, 1));
+        assert!(positions.iter().all(|position| lair.contains((position.x(), position.y()))));
+    }
+
+    #[test]
+    fn test_room_budget_is_capped() {
+        assert_eq!(room_budget(&Room::new(0, 0, 4, 4), 1), 0);
+        assert_eq!(room_budget(&Room::new(0, 0, 5, 8), 1), 2);
+        assert_eq!(room_budget(&Room::new(0, 0, 8, 8), 20), MAX_ROOM_POPULATION);
+    }
+}
@@ -1,23 +1,27 @@
 use bevy::prelude::*;
-use big_brain::prelude::*;
-use echos_assets::entities::{AIBehaviorType, EntityDefinition, EntityDefinitions};
+use echos_assets::entities::{EntityDefinition, EntityDefinitions};
 
 use crate::{
     core::{
+        actions::light_torch,
         bundles::{EnemyBundle, PlayerBundle},
         components::{
-            Description, FieldOfView, Health, Inventory, InventoryItem, Position, Stats, light::Light,
+            BaseAttributes, Description, Equipment, Faction, FieldOfView, Health, Inventory, InventoryItem,
+            LightRadius, LootTable, Movement, OccupiesTile, Position, SpecialAttacks, Stats, TORCH_RANGE,
+            light::Light,
         },
         resources::{CurrentMap, TurnQueue},
     },
     gameplay::{
-        enemies::components::{
-            ChasePlayerAction, ChasePlayerScorer, FleeFromPlayerAction, FleeFromPlayerScorer, IdleAction,
-            WanderAction, WanderScorer,
+        enemies::{
+            components::{Elite, Grudges, PatrolRoute, SquadRole},
+            thinker::build_thinker,
         },
+        factions::FactionRegistry,
+        player::character::NewCharacter,
         turns::components::TurnActor,
+        world::{scaling::MonsterScaling, spawn_table::SpawnTable},
     },
-    prelude::gameplay::enemies::AttackAction,
     rendering::components::TileSprite,
 };
 
@@ -32,11 +36,13 @@ impl EntitySpawnConfig {
     fn ai() -> Self { Self { default_view_radius: 6, default_turn_speed: 1000 } }
 }
 
-/// Spawn a player entity from definition data
+/// Spawn a player entity from definition data, with the choices made at character creation
+/// applied over it
 pub fn spawn_player_from_definition(
     mut commands: Commands,
     entity_definitions: &EntityDefinitions,
     assets: &Assets<EntityDefinition>,
+    character: &NewCharacter,
     position: Position,
     current_map: &mut CurrentMap,
     turn_queue: &mut TurnQueue,
@@ -49,12 +55,15 @@ pub fn spawn_player_from_definition(
     // Add common components using helper function
     let config = EntitySpawnConfig::player();
     add_common_components(&mut entity_commands, definition, &config);
-
-    // Add a light source to the player (torch-like)
-    entity_commands.insert(Light {
-        range: 8,
-        color: Color::srgb(1.0, 0.9, 0.7), // Warm torch light
-        falloff: 1.5,
+    apply_new_character(&mut entity_commands, definition, &config, character);
+
+    // The player sets out with one of their torches already burning
+    let light_radius = character.class.as_ref().and_then(|class| class.light_radius);
+    entity_commands.insert(LightRadius(light_radius.unwrap_or(TORCH_RANGE)));
+    entity_commands.queue(|mut entity: EntityWorldMut| {
+        if light_torch(&mut entity).is_err() {
+            warn!("Player has no torch to light and sets out in the dark");
+        }
     });
 
     let player_id = entity_commands.id();
@@ -68,44 +77,67 @@ pub fn spawn_ai_from_definition(
     commands: Commands,
     entity_definitions: &EntityDefinitions,
     assets: &Assets<EntityDefinition>,
+    monster_scaling: &MonsterScaling,
     ai_name: &str,
     position: Position,
     current_map: &mut CurrentMap,
     turn_queue: &mut TurnQueue,
+    rng: &mut fastrand::Rng,
 ) -> Result<Entity, String> {
     let ai_handle = entity_definitions
         .get_by_name(ai_name)
         .ok_or_else(|| format!("AI definition '{ai_name}' not found"))?;
 
     let definition = assets.get(ai_handle).ok_or_else(|| format!("AI definition '{ai_name}' not loaded"))?;
-    spawn_ai_entity(commands, definition, position, current_map, turn_queue)
+    spawn_ai_entity(commands, definition, monster_scaling, position, current_map, turn_queue, rng)
 }
 
-/// Spawn a random AI entity from available definitions
+/// Spawn a random AI entity, rolled on the spawn table for the current depth
 pub fn spawn_random_ai_from_definition(
     commands: Commands,
     entity_definitions: &EntityDefinitions,
     assets: &Assets<EntityDefinition>,
+    spawn_table: &SpawnTable,
+    monster_scaling: &MonsterScaling,
     position: Position,
     current_map: &mut CurrentMap,
     turn_queue: &mut TurnQueue,
+    rng: &mut fastrand::Rng,
 ) -> Result<Entity, String> {
-    let random_handle = entity_definitions.get_random_enemy().ok_or("No enemy definitions available")?;
-    let definition = assets.get(random_handle).ok_or("Random enemy definition not loaded")?;
-    spawn_ai_entity(commands, definition, position, current_map, turn_queue)
+    let ai_name =
+        spawn_table.sample(rng).ok_or_else(|| format!("No enemies spawn at depth {}", spawn_table.depth))?;
+    spawn_ai_from_definition(
+        commands,
+        entity_definitions,
+        assets,
+        monster_scaling,
+        ai_name,
+        position,
+        current_map,
+        turn_queue,
+        rng,
+    )
 }
 
-/// Helper function to spawn an AI entity from a definition
+/// Helper function to spawn an AI entity from a definition, scaled for the current depth
 fn spawn_ai_entity(
     mut commands: Commands,
-    definition: &EntityDefinition,
+    base_definition: &EntityDefinition,
+    monster_scaling: &MonsterScaling,
     position: Position,
     current_map: &mut CurrentMap,
     turn_queue: &mut TurnQueue,
+    rng: &mut fastrand::Rng,
 ) -> Result<Entity, String> {
+    let variant = monster_scaling.roll_variant(rng);
+    let definition = &monster_scaling.scale(base_definition, variant);
+
     // Get AI behavior type directly from entity definition
     let behavior_type = definition.ai_behavior_type();
-    let enemy_bundle = EnemyBundle::new(&definition.name, &definition.description, position, behavior_type);
+    let mut enemy_bundle =
+        EnemyBundle::new(&definition.name, &definition.description, position, behavior_type);
+    enemy_bundle.grudges = Grudges::new(definition.defends_allies());
+    enemy_bundle.squad_role = SquadRole::for_range(definition.preferred_range());
     let mut entity_commands = commands.spawn(enemy_bundle);
 
     // Add common components using helper function
@@ -114,11 +146,31 @@ fn spawn_ai_entity(
     // Add common components
     add_common_components(&mut entity_commands, definition, &config);
 
-    // Add big-brain AI components based on behavior type
-    add_big_brain_components(&mut entity_commands, behavior_type);
+    // Add the big-brain thinker the definition describes
+    let thinker = definition.thinker();
+    entity_commands.insert(build_thinker(&thinker, position));
+
+    // Patrollers are handed a route through the level once they're in it
+    if thinker.patrols() {
+        entity_commands.insert(PatrolRoute::default());
+    }
+
+    if !definition.special_attacks().is_empty() {
+        entity_commands.insert(SpecialAttacks::new(definition.special_attacks().to_vec()));
+    }
+
+    if let Some(variant) = variant {
+        entity_commands.insert(Elite::new(&variant.prefix));
+    }
+
+    if let Some(loot) = definition.loot() {
+        entity_commands.insert(LootTable::new(loot.clone()));
+    }
+
+    entity_commands.insert(ExperienceValue(definition.experience()));
 
-    // Add light sources to certain AI entities
-    match definition.name.as_str() {
+    // Add light sources to certain AI entities, by their name before any variant prefix
+    match base_definition.name.as_str() {
         "Hostile Guard" => {
             // Guards carry lanterns
             entity_commands.insert(Light {
@@ -160,15 +212,33 @@ fn add_common_components(
     definition: &EntityDefinition,
     config: &EntitySpawnConfig,
 ) {
+    // Stats are counted on top of these once the entity is in the world
+    let base = base_attributes(definition, config);
+    entity_commands.insert(base);
+
     // Add TurnActor component
-    entity_commands.insert(TurnActor::new(
-        definition.components.turn_actor.as_ref().map(|data| data.speed).unwrap_or(config.default_turn_speed),
-    ));
+    entity_commands.insert(TurnActor::new(base.speed));
 
     // Add FieldOfView component
-    entity_commands.insert(FieldOfView::new(
-        definition.components.field_of_view.as_ref().map(|data| data.0).unwrap_or(config.default_view_radius),
-    ));
+    entity_commands.insert(FieldOfView::new(base.view_radius));
+
+    // Add Movement component
+    entity_commands.insert(Movement::new(definition.movement_type()));
+
+    // Keep the map's actor index in step with this entity's Position
+    entity_commands.insert(OccupiesTile);
+
+    // Add Faction component, looked up in the registry once the entity is in the world
+    let (faction_name, name, is_player) =
+        (definition.faction().to_string(), definition.name.clone(), definition.is_player());
+    entity_commands.queue(move |mut entity: EntityWorldMut| {
+        let faction =
+            entity.world().resource::<FactionRegistry>().id_of(&faction_name).unwrap_or_else(|| {
+                warn!("Unknown faction '{faction_name}' for '{name}'");
+                Faction::of(None, is_player)
+            });
+        entity.insert(faction);
+    });
 
     // Add Health component
     if let Some(health_data) = &definition.components.health {
@@ -180,14 +250,7 @@ fn add_common_components(
 
     // Add Stats component
     if let Some(stats_data) = &definition.components.stats {
-        entity_commands.insert(Stats {
-            strength: stats_data.strength,
-            defense: stats_data.defense,
-            intelligence: stats_data.intelligence,
-            agility: stats_data.agility,
-            vitality: stats_data.vitality,
-            luck: stats_data.luck,
-        });
+        entity_commands.insert(Stats::from(stats_data));
     } else {
         // Default balanced stats if not specified
         entity_commands.insert(Stats::balanced(10));
@@ -200,17 +263,9 @@ fn add_common_components(
         // Add starting items if specified
         if let Some(starting_items) = &inventory_data.starting_items {
             for (item_id, quantity) in starting_items {
-                // Create a basic inventory item (in a real game, you'd look up item definitions)
-                let item = InventoryItem::new(
-                    item_id.clone(),
-                    item_id.clone(), // Use ID as name for now
-                    *quantity,
-                    99,                     // Default max stack
-                    1.0,                    // Default weight
-                    format!("A {item_id}"), // Default description
-                );
-
-                if let Err(e) = inventory.add_item(item) {
+                let item = InventoryItem::basic(item_id, *quantity);
+
+                if let Err(e) = inventory.add_item(item, None) {
                     warn!("Failed to add starting item '{}' to inventory: {:?}", item_id, e);
                 }
             }
@@ -229,11 +284,68 @@ fn add_common_components(
         entity_commands.insert(TileSprite {
             tile_coords: sprite_data.tile_coords,
             tile_size: sprite_data.tile_size.unwrap_or((12.0, 12.0)).into(),
-            ..Default::default()
+            tint: sprite_data.tint.map(|(r, g, b, a)| Color::srgba(r, g, b, a)),
         });
     }
 }
 
+/// Helper function to work out the base values an entity's derived attributes are built from
+fn base_attributes(definition: &EntityDefinition, config: &EntitySpawnConfig) -> BaseAttributes {
+    let components = &definition.components;
+    BaseAttributes {
+        max_health: components.health.as_ref().map_or(100, |health_data| health_data.max),
+        carry_capacity: components.inventory.as_ref().map_or(0.0, |inventory_data| inventory_data.max_weight),
+        speed: components.turn_actor.as_ref().map_or(config.default_turn_speed, |data| data.speed),
+        view_radius: components.field_of_view.as_ref().map_or(config.default_view_radius, |data| data.0),
+    }
+}
+
+/// Helper function to apply the creation screen's name and class over the player definition
+fn apply_new_character(
+    entity_commands: &mut EntityCommands,
+    definition: &EntityDefinition,
+    config: &EntitySpawnConfig,
+    character: &NewCharacter,
+) {
+    if let Some(description) = character.description() {
+        entity_commands.insert(Description::new(description));
+    }
+
+    let Some(class) = &character.class else {
+        return;
+    };
+
+    if let Some(stats) = character.stats() {
+        entity_commands.insert(stats);
+    }
+
+    if let Some(max_health) = class.max_health {
+        entity_commands.insert((
+            Health::new(max_health),
+            BaseAttributes { max_health, ..base_attributes(definition, config) },
+        ));
+    }
+
+    // The class's kit takes the place of the definition's starting items
+    let (max_slots, max_weight) = definition
+        .components
+        .inventory
+        .as_ref()
+        .map_or((30, 150.0), |inventory_data| (inventory_data.max_slots, inventory_data.max_weight));
+    let mut inventory = Inventory::new(max_slots, max_weight);
+    let equipment = Equipment::basic(&class.equipment);
+    for (item_id, quantity) in &class.starting_items {
+        if let Err(e) = inventory.add_item(InventoryItem::basic(item_id, *quantity), Some(&equipment)) {
+            warn!("Failed to add {} starting item '{}' to inventory: {:?}", class.name, item_id, e);
+        }
+    }
+    entity_commands.insert((inventory, equipment));
+
+    if !class.abilities.is_empty() {
+        entity_commands.insert(SpecialAttacks::new(class.abilities.clone()));
+    }
+}
+
 /// Helper function to handle turn queue scheduling and map placement
 fn finalize_entity_spawn(
     entity_id: Entity,
@@ -252,56 +364,3 @@ fn finalize_entity_spawn(
 
     Ok(entity_id)
 }
-
-/// Add big-brain components to an AI entity based on its behavior type
-fn add_big_brain_components(entity_commands: &mut EntityCommands, behavior_type: AIBehaviorType) {
-    match behavior_type {
-        AIBehaviorType::Hostile => {
-            let thinker = Thinker::build()
-                .label("Hostile")
-                .picker(FirstToScore { threshold: 0.7 })
-                .when(ChasePlayerScorer, Steps::build().step(ChasePlayerAction::default()).step(AttackAction))
-                .otherwise(WanderAction::default());
-
-            entity_commands.insert((
-                thinker,
-                ChasePlayerScorer,
-                ChasePlayerAction::default(),
-                WanderScorer,
-                WanderAction::default(),
-            ));
-        }
-        AIBehaviorType::Passive => {
-            // Passive enemies prioritize survival and flee from threats
-            // Lower threshold (0.5) means they're more reactive to danger
-            // They will flee when threatened, otherwise wander peacefully
-            let thinker = Thinker::build()
-                .label("Passive")
-                .picker(FirstToScore { threshold: 0.5 })
-                .when(FleeFromPlayerScorer, FleeFromPlayerAction::default())
-                .when(WanderScorer, WanderAction::default())
-                .otherwise(IdleAction);
-
-            entity_commands.insert((
-                thinker,
-                FleeFromPlayerScorer,
-                WanderScorer,
-                FleeFromPlayerAction::default(),
-                WanderAction::default(),
-                IdleAction,
-            ));
-        }
-        AIBehaviorType::Neutral => {
-            // Neutral enemies are peaceful and just go about their business
-            // Very low threshold (0.3) means they're content to just wander
-            // They ignore the player completely and focus on their own activities
-            let thinker = Thinker::build()
-                .label("Neutral")
-                .picker(FirstToScore { threshold: 0.3 })
-                .when(WanderScorer, WanderAction::default())
-                .otherwise(IdleAction);
-
-            entity_commands.insert((thinker, WanderScorer, WanderAction::default(), IdleAction));
-        }
-    }
-}
@@ -3,23 +3,74 @@ use bevy_ecs_tilemap::prelude::*;
 use brtk::{grid::Grid, random::Random};
 
 use crate::{
-    core::{components::Position, constants::ModelConstants, resources::CurrentMap},
-    gameplay::world::{components::TerrainType, generation::GenConfig},
-    rendering::resources::TextureAssets,
+    core::{
+        components::{Description, PlayerTag, Position},
+        constants::ModelConstants,
+        pathfinding,
+        resources::{CurrentMap, FovMap, LightMap, Map, NoiseMap},
+    },
+    gameplay::world::{
+        components::{Decoration, Feature, TerrainType},
+        generation::GenConfig,
+    },
+    rendering::{RenderingConstants, components::TileSprite, resources::TextureAssets},
 };
 
 // ============================================================================
 // MAP SPAWNING SYSTEMS
 // ============================================================================
 
+/// Clear out the level the player is leaving and go one level deeper. Everything on the map goes
+/// with it; only the player carries over.
+pub fn leave_level(
+    mut commands: Commands,
+    mut gen_config: ResMut<GenConfig>,
+    q_level: Query<Entity, (With<Position>, Without<PlayerTag>)>,
+) {
+    for entity in &q_level {
+        commands.entity(entity).despawn();
+    }
+
+    gen_config.depth += 1;
+}
+
+/// Set up generation for the current depth, rebuild the map-sized resources to its dimensions and
+/// tear down the tilemap of the previous level
+pub fn prepare_level(
+    mut commands: Commands,
+    mut gen_config: ResMut<GenConfig>,
+    mut current_map: ResMut<CurrentMap>,
+    mut fov_map: ResMut<FovMap>,
+    mut light_map: ResMut<LightMap>,
+    mut noise_map: ResMut<NoiseMap>,
+    q_tilemaps: Query<(Entity, &TileStorage)>,
+) {
+    for (tilemap_entity, tile_storage) in &q_tilemaps {
+        for tile_entity in tile_storage.iter().flatten() {
+            commands.entity(*tile_entity).despawn();
+        }
+        commands.entity(tilemap_entity).despawn();
+    }
+
+    *gen_config = GenConfig::for_depth(gen_config.depth);
+
+    let (width, height) = (gen_config.width, gen_config.height);
+    *current_map = CurrentMap(Map::new((width, height)));
+    *fov_map = FovMap::new(width as usize, height as usize);
+    light_map.clear();
+    noise_map.clear();
+
+    // Cached paths belong to the previous layout
+    pathfinding::utils::clear_pathfinding_cache();
+}
+
 /// Generate terrain and tile entities using the provided RNG
 fn generate_terrain_and_tiles(
     commands: &mut Commands,
     rng: &mut fastrand::Rng,
     tilemap_entity: Entity,
+    generator: &mut GenConfig,
 ) -> (Grid<TerrainType>, TileStorage) {
-    let mut generator = GenConfig::new(1, ModelConstants::MAP_WIDTH, ModelConstants::MAP_HEIGHT);
-
     // Generate terrain types
     let terrain_grid = generator.generate(rng);
 
@@ -30,11 +81,12 @@ fn generate_terrain_and_tiles(
     (terrain_grid, tile_storage)
 }
 
-/// Update the current map with generated terrain and tile entities
+/// Update the current map with generated terrain, tile entities and echo chambers
 fn update_current_map_with_terrain(
     current_map: &mut CurrentMap,
     terrain_grid: &Grid<TerrainType>,
     tile_storage: &TileStorage,
+    gen_config: &GenConfig,
 ) {
     for x in 0..current_map.size.0 {
         for y in 0..current_map.size.1 {
@@ -50,6 +102,13 @@ fn update_current_map_with_terrain(
             }
         }
     }
+
+    // Mark echo chambers so noise carries further inside them
+    for room in &gen_config.echo_chambers {
+        for (x, y) in room.positions() {
+            current_map.set_echo_chamber(Position::new(x, y), true);
+        }
+    }
 }
 
 /// Create and insert the TilemapBundle with rendering components
@@ -83,6 +142,7 @@ pub fn spawn_map(
     mut current_map: ResMut<CurrentMap>,
     texture_assets: Res<TextureAssets>,
     mut shared_rng: ResMut<Random>,
+    mut gen_config: ResMut<GenConfig>,
 ) {
     // Use the shared RNG resource for better randomness distribution
     let mut rng = shared_rng.to_fastrand();
@@ -91,10 +151,11 @@ pub fn spawn_map(
     let tilemap_entity = commands.spawn_empty().id();
 
     // Generate terrain and tile entities
-    let (terrain_grid, tile_storage) = generate_terrain_and_tiles(&mut commands, &mut rng, tilemap_entity);
+    let (terrain_grid, tile_storage) =
+        generate_terrain_and_tiles(&mut commands, &mut rng, tilemap_entity, &mut gen_config);
 
     // Update our map with the generated terrain and tile entities
-    update_current_map_with_terrain(&mut current_map, &terrain_grid, &tile_storage);
+    update_current_map_with_terrain(&mut current_map, &terrain_grid, &tile_storage, &gen_config);
 
     // Set up rendering components and insert the TilemapBundle
     setup_tilemap_rendering(
@@ -107,4 +168,54 @@ pub fn spawn_map(
 
     // Update the tile_storage in our map (for rendering compatibility)
     current_map.tile_storage = tile_storage;
+
+    // Doors, altars and floor marks place themselves on the map as they spawn
+    for (position, feature) in &gen_config.features {
+        commands.spawn(map_feature(*feature, *position));
+    }
+    for (position, decoration) in &gen_config.decorations {
+        commands.spawn(map_decoration(*decoration, *position));
+    }
+}
+
+/// A fixed feature standing on the map, ready to spawn
+pub fn map_feature(feature: Feature, position: Position) -> impl Bundle {
+    (
+        Name::new(feature.description()),
+        Description::new(feature.description()),
+        feature_sprite(feature),
+        position,
+        feature,
+    )
+}
+
+/// A cosmetic mark on the floor, ready to spawn
+pub fn map_decoration(decoration: Decoration, position: Position) -> impl Bundle {
+    let coords = match decoration {
+        Decoration::Blood => RenderingConstants::BLOOD_SPRITE,
+        Decoration::Rubble => RenderingConstants::RUBBLE_SPRITE,
+        Decoration::Scorch => RenderingConstants::SCORCH_SPRITE,
+    };
+
+    (
+        Name::new(decoration.description()),
+        Description::new(decoration.description()),
+        TileSprite::new(coords, Vec2::splat(RenderingConstants::TILE_SIZE)),
+        position,
+        decoration,
+    )
+}
+
+/// Sprite showing a feature in its current state
+pub fn feature_sprite(feature: Feature) -> TileSprite {
+    let coords = match feature {
+        Feature::Door { open: true } => RenderingConstants::DOOR_OPEN_SPRITE,
+        Feature::Door { open: false } => RenderingConstants::DOOR_CLOSED_SPRITE,
+        Feature::Trap { .. } => RenderingConstants::TRAP_SPRITE,
+        Feature::Altar => RenderingConstants::ALTAR_SPRITE,
+        Feature::StairsUp => RenderingConstants::STAIRS_UP_SPRITE,
+        Feature::StairsDown => RenderingConstants::STAIRS_DOWN_SPRITE,
+    };
+
+    TileSprite::new(coords, Vec2::splat(RenderingConstants::TILE_SIZE))
 }
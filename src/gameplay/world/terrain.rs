@@ -0,0 +1,252 @@
+//! Terrain registry
+//!
+//! All terrain properties come from the [`TerrainTable`] asset. The registry is global (like the
+//! pathfinding manager) so [`TerrainType`] can answer property queries from FOV and pathfinding
+//! providers that have no world access. Built-in terrain keeps fixed ids so code can refer to it
+//! by constant; any extra entries in the table get new ids when the table is installed.
+
+use bevy::{asset::AssetEvent, prelude::*};
+use bevy_ecs_tilemap::tiles::TileTextureIndex;
+use echos_assets::{
+    entities::MovementType,
+    terrain::{TerrainDefinition, TerrainDefinitions, TerrainTable},
+};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+
+use crate::{
+    core::{components::Description, pathfinding},
+    gameplay::world::components::TerrainType,
+};
+
+/// Global terrain registry, seeded with the built-in terrain
+static TERRAIN_REGISTRY: Lazy<RwLock<TerrainRegistry>> =
+    Lazy::new(|| RwLock::new(TerrainRegistry::default()));
+
+/// Lookup table from [`TerrainType`] ids to their definitions
+#[derive(Debug, Clone)]
+pub struct TerrainRegistry {
+    definitions: Vec<TerrainDefinition>,
+    ids: HashMap<String, u16>,
+}
+
+impl Default for TerrainRegistry {
+    fn default() -> Self {
+        let mut registry = Self { definitions: Vec::new(), ids: HashMap::new() };
+        for definition in builtin_definitions() {
+            registry.insert(definition);
+        }
+        registry
+    }
+}
+
+impl TerrainRegistry {
+    /// Get the definition for a terrain id (unknown ids resolve to wall)
+    pub fn get(&self, terrain: TerrainType) -> &TerrainDefinition {
+        self.definitions.get(terrain.0 as usize).unwrap_or(&self.definitions[TerrainType::WALL.0 as usize])
+    }
+
+    /// Find a terrain id by name
+    pub fn id_of(&self, name: &str) -> Option<TerrainType> { self.ids.get(name).copied().map(TerrainType) }
+
+    /// Number of registered terrain types
+    pub fn len(&self) -> usize { self.definitions.len() }
+
+    /// Whether the registry has no terrain (never true once built-ins are seeded)
+    pub fn is_empty(&self) -> bool { self.definitions.is_empty() }
+
+    /// All terrain ids flagged as ore
+    pub fn ores(&self) -> Vec<TerrainType> { self.filter(|definition| definition.ore) }
+
+    /// All terrain ids that emit light
+    pub fn emissive(&self) -> Vec<TerrainType> { self.filter(|definition| definition.light.is_some()) }
+
+    /// Merge a loaded table into the registry, replacing definitions with matching names
+    pub fn install(&mut self, table: &TerrainTable) {
+        for definition in &table.terrain {
+            self.insert(definition.clone());
+        }
+    }
+
+    fn insert(&mut self, definition: TerrainDefinition) {
+        match self.ids.get(&definition.name) {
+            Some(&id) => self.definitions[id as usize] = definition,
+            None => {
+                let id = self.definitions.len() as u16;
+                self.ids.insert(definition.name.clone(), id);
+                self.definitions.push(definition);
+            }
+        }
+    }
+
+    fn filter(&self, predicate: impl Fn(&TerrainDefinition) -> bool) -> Vec<TerrainType> {
+        self.definitions
+            .iter()
+            .enumerate()
+            .filter(|(_, definition)| predicate(definition))
+            .map(|(id, _)| TerrainType(id as u16))
+            .collect()
+    }
+}
+
+/// Run a closure with read access to the global terrain registry
+pub fn with_terrain_registry<R>(f: impl FnOnce(&TerrainRegistry) -> R) -> R { f(&TERRAIN_REGISTRY.read()) }
+
+/// Merge a terrain table into the global registry
+pub fn install_terrain_table(table: &TerrainTable) {
+    TERRAIN_REGISTRY.write().install(table);
+
+    // Costs and passability may have changed
+    pathfinding::utils::clear_pathfinding_cache();
+}
+
+/// Names of the built-in terrain, in id order. These must match the [`TerrainType`] constants.
+const BUILTIN_TERRAIN: [&str; 9] = [
+    "floor",
+    "wall",
+    "stairs_up",
+    "stairs_down",
+    "rubble",
+    "shallow_water",
+    "deep_water",
+    "chasm",
+    "ore_vein",
+];
+
+/// Built-in terrain, in [`BUILTIN_TERRAIN`] order. Used until the terrain table is installed.
+fn builtin_definitions() -> Vec<TerrainDefinition> {
+    use MovementType::*;
+
+    vec![
+        TerrainDefinition::new("floor", '.', 515, "Floor"),
+        TerrainDefinition::new("wall", '#', 206, "Wall").solid(),
+        TerrainDefinition::new("stairs_up", '<', 127, "Stairs leading up"),
+        TerrainDefinition::new("stairs_down", '>', 126, "Stairs leading down"),
+        TerrainDefinition::new("rubble", ':', 517, "Rubble")
+            .with_move_cost(2)
+            .with_override(Flying, Some(1))
+            .with_override(Swimming, Some(3))
+            .with_override(Burrowing, Some(1))
+            .with_mineable(false),
+        TerrainDefinition::new("shallow_water", '~', 417, "Shallow water")
+            .with_move_cost(2)
+            .with_override(Flying, Some(1))
+            .with_override(Swimming, Some(1))
+            .with_override(Burrowing, Some(3)),
+        TerrainDefinition::new("deep_water", '=', 418, "Deep water")
+            .blocking()
+            .with_override(Flying, Some(1))
+            .with_override(Swimming, Some(1)),
+        TerrainDefinition::new("chasm", ' ', 0, "A bottomless chasm")
+            .blocking()
+            .with_override(Flying, Some(1)),
+        TerrainDefinition::new("ore_vein", '%', 210, "Loose ore vein")
+            .with_move_cost(3)
+            .with_override(Flying, Some(1))
+            .with_override(Burrowing, Some(1))
+            .with_mineable(true),
+    ]
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Install the loaded terrain table before the map is generated
+pub fn apply_terrain_definitions(
+    terrain_definitions: Option<Res<TerrainDefinitions>>,
+    tables: Res<Assets<TerrainTable>>,
+) {
+    let Some(table) = terrain_definitions.and_then(|definitions| tables.get(&definitions.table)) else {
+        warn!("Terrain table not loaded, using built-in terrain");
+        return;
+    };
+
+    for name in BUILTIN_TERRAIN {
+        if !table.terrain.iter().any(|definition| definition.name == name) {
+            error!("Terrain table is missing built-in terrain '{name}'");
+        }
+    }
+
+    if let Err(errors) = table.validate() {
+        for error in errors {
+            warn!("Terrain table: {error}");
+        }
+    }
+
+    install_terrain_table(table);
+    info!("Loaded {} terrain definitions", with_terrain_registry(|registry| registry.len()));
+}
+
+/// Re-install the terrain table when it changes on disk and refresh existing tiles
+pub fn reload_terrain_definitions(
+    mut events: EventReader<AssetEvent<TerrainTable>>,
+    tables: Res<Assets<TerrainTable>>,
+    mut q_tiles: Query<(&TerrainType, &mut TileTextureIndex, &mut Description)>,
+) {
+    let mut reloaded = false;
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event
+            && let Some(table) = tables.get(*id)
+        {
+            install_terrain_table(table);
+            reloaded = true;
+        }
+    }
+
+    if reloaded {
+        for (terrain, mut texture_index, mut description) in &mut q_tiles {
+            texture_index.0 = terrain.texture_index();
+            description.0 = terrain.description();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_ids_match_constants() {
+        let registry = TerrainRegistry::default();
+
+        assert_eq!(registry.id_of("floor"), Some(TerrainType::FLOOR));
+        assert_eq!(registry.id_of("wall"), Some(TerrainType::WALL));
+        assert_eq!(registry.id_of("stairs_up"), Some(TerrainType::STAIRS_UP));
+        assert_eq!(registry.id_of("stairs_down"), Some(TerrainType::STAIRS_DOWN));
+        assert_eq!(registry.id_of("rubble"), Some(TerrainType::RUBBLE));
+        assert_eq!(registry.id_of("shallow_water"), Some(TerrainType::SHALLOW_WATER));
+        assert_eq!(registry.id_of("deep_water"), Some(TerrainType::DEEP_WATER));
+        assert_eq!(registry.id_of("chasm"), Some(TerrainType::CHASM));
+        assert_eq!(registry.id_of("ore_vein"), Some(TerrainType::ORE_VEIN));
+    }
+
+    #[test]
+    fn test_builtin_definitions_follow_builtin_names() {
+        let names: Vec<String> =
+            builtin_definitions().into_iter().map(|definition| definition.name).collect();
+        assert_eq!(names, BUILTIN_TERRAIN);
+    }
+
+    #[test]
+    fn test_install_overrides_and_extends() {
+        let mut registry = TerrainRegistry::default();
+        let builtin_count = registry.len();
+
+        let table = TerrainTable {
+            terrain: vec![
+                TerrainDefinition::new("floor", ',', 600, "Dusty floor"),
+                TerrainDefinition::new("glimmerstone", '*', 211, "Glimmerstone").solid().with_mineable(true),
+            ],
+        };
+        registry.install(&table);
+
+        assert_eq!(registry.get(TerrainType::FLOOR).atlas_index, 600);
+        assert_eq!(registry.len(), builtin_count + 1);
+
+        let glimmerstone = registry.id_of("glimmerstone").unwrap();
+        assert!(registry.get(glimmerstone).opaque);
+        assert!(registry.ores().contains(&glimmerstone));
+    }
+}
@@ -26,6 +26,10 @@ impl TileSprite {
     }
 }
 
+/// Ghost sprite drawing an actor's silhouette where an echolocation ping caught it
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EchoGhost(pub Entity);
+
 impl From<TileSpriteData> for TileSprite {
     fn from(data: TileSpriteData) -> Self {
         let tile_size = data
@@ -17,6 +17,28 @@ impl RenderingConstants {
     /// Number of rows in the tilemap
     pub const TILEMAP_ROWS: usize = 49;
 
+    /// Draw depth of actors
+    pub const ACTOR_Z: f32 = 1.0;
+
+    /// Draw depth of corpses and items, beneath anyone standing on them
+    pub const FLOOR_OBJECT_Z: f32 = 0.5;
+
+    /// Tilemap coordinates of the sprite for items lying on the floor
+    pub const FLOOR_ITEM_SPRITE: (u32, u32) = (28, 25);
+
+    /// Tilemap coordinates of the map feature sprites
+    pub const DOOR_CLOSED_SPRITE: (u32, u32) = (12, 11);
+    pub const DOOR_OPEN_SPRITE: (u32, u32) = (13, 11);
+    pub const TRAP_SPRITE: (u32, u32) = (20, 11);
+    pub const ALTAR_SPRITE: (u32, u32) = (31, 13);
+    pub const STAIRS_UP_SPRITE: (u32, u32) = (8, 11);
+    pub const STAIRS_DOWN_SPRITE: (u32, u32) = (9, 11);
+
+    /// Tilemap coordinates of the floor decoration sprites
+    pub const BLOOD_SPRITE: (u32, u32) = (14, 43);
+    pub const RUBBLE_SPRITE: (u32, u32) = (2, 43);
+    pub const SCORCH_SPRITE: (u32, u32) = (15, 43);
+
     /// Debug colors for FOV visualization
     pub const DEBUG_VISIBLE_COLOR: (f32, f32, f32, f32) = (0.8, 1.0, 0.8, 1.0);
     pub const DEBUG_REVEALED_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 1.0, 0.6);
@@ -0,0 +1,174 @@
+//! Character creation screen: pick a class and difficulty, name the character and reroll stats
+
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+    ui::Val::*,
+};
+use brtk::random::Random;
+use echos_assets::classes::{ClassDefinition, ClassDefinitions};
+
+use super::ScreenState;
+use crate::{
+    core::{components::StatType, resources::GameDifficulty},
+    gameplay::player::character::NewCharacter,
+    ui::utils::{button, header, label, ui_root},
+};
+
+/// Character creation plugin, shown between loading and gameplay
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(ScreenState::CharacterCreation), setup_character_creation)
+        .add_systems(
+            Update,
+            (
+                type_character_name,
+                update_character_preview
+                    .run_if(resource_changed::<NewCharacter>.or(resource_changed::<GameDifficulty>)),
+            )
+                .chain()
+                .run_if(in_state(ScreenState::CharacterCreation)),
+        )
+        .add_systems(OnExit(ScreenState::CharacterCreation), cleanup_character_creation);
+}
+
+/// Marker component for character creation screen entities
+#[derive(Component)]
+struct CharacterCreationScreen;
+
+/// Text showing part of the character being made
+#[derive(Component, Clone, Copy)]
+enum CharacterPreview {
+    Name,
+    Class,
+    Stats,
+    Difficulty,
+}
+
+/// A class button's choice, applied when it's clicked
+fn class_button(class: ClassDefinition) -> impl Bundle {
+    let name = class.name.clone();
+    button(name, move |_: Trigger<Pointer<Click>>, mut character: ResMut<NewCharacter>| {
+        character.choose_class(&class);
+    })
+}
+
+fn reroll(_: Trigger<Pointer<Click>>, mut character: ResMut<NewCharacter>, mut random: ResMut<Random>) {
+    character.reroll(&mut random);
+}
+
+fn cycle_difficulty(_: Trigger<Pointer<Click>>, mut difficulty: ResMut<GameDifficulty>) {
+    difficulty.0 = difficulty.next();
+}
+
+fn begin(_: Trigger<Pointer<Click>>, mut next_screen: ResMut<NextState<ScreenState>>) {
+    next_screen.set(ScreenState::Gameplay);
+}
+
+/// Set up the character creation UI, starting on the first class
+fn setup_character_creation(
+    mut commands: Commands,
+    class_definitions: Res<ClassDefinitions>,
+    class_assets: Res<Assets<ClassDefinition>>,
+    mut character: ResMut<NewCharacter>,
+) {
+    let classes: Vec<ClassDefinition> =
+        class_definitions.loaded(&class_assets).into_iter().cloned().collect();
+    if classes.is_empty() {
+        warn!("No character classes loaded, the player definition will be used as it is");
+    }
+    for class in &classes {
+        if let Err(errors) = class.validate() {
+            for error in errors {
+                warn!("Class '{}': {error}", class.name);
+            }
+        }
+    }
+    if character.class.is_none()
+        && let Some(class) = classes.first()
+    {
+        character.choose_class(class);
+    }
+
+    commands.spawn((Camera2d, CharacterCreationScreen));
+
+    commands.spawn((
+        ui_root("Character Creation"),
+        CharacterCreationScreen,
+        BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+        children![
+            header("Create your character"),
+            label("Type a name, Backspace to erase"),
+            (label(""), CharacterPreview::Name),
+            (
+                Name::new("Classes"),
+                Node { column_gap: Px(20.0), ..default() },
+                Children::spawn(SpawnIter(classes.into_iter().map(class_button))),
+            ),
+            (label(""), CharacterPreview::Class),
+            (label(""), CharacterPreview::Stats),
+            (label(""), CharacterPreview::Difficulty),
+            (
+                Name::new("Actions"),
+                Node { column_gap: Px(20.0), ..default() },
+                children![
+                    button("Reroll", reroll),
+                    button("Difficulty", cycle_difficulty),
+                    button("Begin", begin)
+                ],
+            ),
+        ],
+    ));
+}
+
+/// Type the character's name; Enter starts the game
+fn type_character_name(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut character: ResMut<NewCharacter>,
+    mut next_screen: ResMut<NextState<ScreenState>>,
+) {
+    for event in keyboard_events.read().filter(|event| event.state.is_pressed()) {
+        match &event.logical_key {
+            Key::Character(text) => character.push_to_name(text),
+            Key::Space => character.push_to_name(" "),
+            Key::Backspace => character.pop_from_name(),
+            Key::Enter => next_screen.set(ScreenState::Gameplay),
+            _ => {}
+        }
+    }
+}
+
+/// Show the name, class, stats and difficulty as they change
+fn update_character_preview(
+    character: Res<NewCharacter>,
+    difficulty: Res<GameDifficulty>,
+    mut texts: Query<(&mut Text, &CharacterPreview)>,
+) {
+    for (mut text, preview) in &mut texts {
+        text.0 = match preview {
+            CharacterPreview::Name => {
+                character.description().unwrap_or_else(|| "An unnamed adventurer".to_string())
+            }
+            CharacterPreview::Class => {
+                character.class.as_ref().map_or(String::new(), |class| class.description.clone())
+            }
+            CharacterPreview::Stats => character.stats().map_or(String::new(), |stats| {
+                StatType::all()
+                    .iter()
+                    .map(|stat| format!("{} {}", stat.abbreviation(), stats.get_stat(*stat)))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+            }),
+            CharacterPreview::Difficulty => format!("Difficulty: {:?}", difficulty.0),
+        };
+    }
+}
+
+/// Clean up character creation entities
+fn cleanup_character_creation(
+    mut commands: Commands,
+    screen_entities: Query<Entity, With<CharacterCreationScreen>>,
+) {
+    for entity in &screen_entities {
+        commands.entity(entity).despawn();
+    }
+}
@@ -6,7 +6,8 @@ use super::ScreenState;
 use crate::{
     core::states::GameState,
     rendering::systems::{
-        add_sprite_to_entities, position_to_transform, update_sprite_visibility, update_tilemap_visibility,
+        add_sprite_to_entities, position_to_transform, update_echo_ghosts, update_sprite_visibility,
+        update_tilemap_visibility,
     },
 };
 
@@ -40,7 +41,7 @@ pub fn plugin(app: &mut App) {
     // Rendering updates after turn processing
     app.add_systems(
         PostUpdate,
-        (update_tilemap_visibility, update_sprite_visibility)
+        (update_tilemap_visibility, update_sprite_visibility, update_echo_ghosts)
             .chain()
             .in_set(RenderingSystems::Rendering)
             .run_if(in_state(GameState::ProcessTurns))
@@ -5,16 +5,26 @@ use bevy_asset_loader::prelude::*;
 
 use super::ScreenState;
 use crate::rendering::resources::TextureAssets;
-use echos_assets::entities::EntityDefinitions;
+use echos_assets::{
+    classes::ClassDefinitions, entities::EntityDefinitions, factions::FactionDefinitions,
+    progression::ProgressionDefinitions, scaling::ScalingDefinitions, survival::SurvivalDefinitions,
+    terrain::TerrainDefinitions,
+};
 
 /// Loading screen plugin that handles asset loading
 pub fn plugin(app: &mut App) {
     // Configure asset loading
     app.add_loading_state(
         LoadingState::new(ScreenState::Loading)
-            .continue_to_state(ScreenState::Gameplay)
+            .continue_to_state(ScreenState::CharacterCreation)
             .load_collection::<TextureAssets>()
             .load_collection::<EntityDefinitions>()
+            .load_collection::<ClassDefinitions>()
+            .load_collection::<TerrainDefinitions>()
+            .load_collection::<FactionDefinitions>()
+            .load_collection::<ScalingDefinitions>()
+            .load_collection::<ProgressionDefinitions>()
+            .load_collection::<SurvivalDefinitions>()
             .with_dynamic_assets_file::<StandardDynamicAssetCollection>("entities.assets.ron"),
     );
 
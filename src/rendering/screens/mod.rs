@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+pub mod character_creation;
 pub mod gameplay;
 pub mod loading;
 
@@ -9,6 +10,7 @@ pub enum ScreenState {
     #[default]
     Loading,
     MainMenu,
+    CharacterCreation,
     Gameplay,
     Paused,
 }
@@ -16,5 +18,5 @@ pub enum ScreenState {
 /// Rendering screens plugin that manages different game screens
 pub fn plugin(app: &mut App) {
     app.init_state::<ScreenState>();
-    app.add_plugins((loading::plugin, gameplay::plugin));
+    app.add_plugins((loading::plugin, character_creation::plugin, gameplay::plugin));
 }
@@ -3,9 +3,9 @@ use bevy::prelude::*;
 use crate::{
     core::{
         components::{PlayerTag, Position},
-        constants::ModelConstants,
+        resources::CurrentMap,
     },
-    rendering::RenderingConstants,
+    rendering::{RenderingConstants, systems::world_from_position},
 };
 
 // ============================================================================
@@ -15,6 +15,7 @@ use crate::{
 /// Camera system that follows the player with smooth interpolation and handles zoom controls.
 pub fn camera_movement(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    current_map: Option<Res<CurrentMap>>,
     player_query: Query<&Position, With<PlayerTag>>,
     mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera>>,
 ) {
@@ -40,15 +41,36 @@ pub fn camera_movement(
         if let Ok(player_position) = player_query.single() {
             // Convert tile position to world coordinates (same calculation as
             // position_to_transform)
-            let target_x = player_position.x() as f32 * RenderingConstants::TILE_SIZE
-                - (ModelConstants::MAP_WIDTH as f32 * RenderingConstants::HALF_TILE_SIZE);
-            let target_y = player_position.y() as f32 * RenderingConstants::TILE_SIZE
-                - (ModelConstants::MAP_HEIGHT as f32 * RenderingConstants::HALF_TILE_SIZE);
+            let map_size = current_map.as_ref().map_or((0, 0), |map| map.size);
+            let target = world_from_position(player_position, map_size);
 
-            let target_position = Vec3::new(target_x, target_y, camera_transform.translation.z);
+            // Keep the view inside the map rather than showing the void past its edges
+            let target = clamp_to_map_bounds(target, ortho.area.half_size(), map_size);
 
             // Snap camera directly to the player position
-            camera_transform.translation = target_position;
+            camera_transform.translation = target.extend(camera_transform.translation.z);
         }
     }
 }
+
+/// Clamp a camera center so a view of `half_view` extents stays within the map. Axes where the map
+/// is smaller than the view are centered instead.
+fn clamp_to_map_bounds(target: Vec2, half_view: Vec2, map_size: (u32, u32)) -> Vec2 {
+    let half_tile = Vec2::splat(RenderingConstants::HALF_TILE_SIZE);
+    let min = world_from_position(&Position::new(0, 0), map_size) - half_tile;
+    let max = world_from_position(&Position::new(map_size.0 as i32 - 1, map_size.1 as i32 - 1), map_size)
+        + half_tile;
+
+    let clamp_axis = |target: f32, half_view: f32, min: f32, max: f32| {
+        if max - min <= half_view * 2.0 {
+            (min + max) / 2.0
+        } else {
+            target.clamp(min + half_view, max - half_view)
+        }
+    };
+
+    Vec2::new(
+        clamp_axis(target.x, half_view.x, min.x, max.x),
+        clamp_axis(target.y, half_view.y, min.y, max.y),
+    )
+}
@@ -1,7 +1,11 @@
 use bevy::prelude::*;
 
 use crate::{
-    core::{components::Position, constants::ModelConstants},
+    core::{
+        components::{DeadTag, FloorItem, Position},
+        resources::CurrentMap,
+    },
+    gameplay::world::components::{Decoration, Feature},
     rendering::{RenderingConstants, components::TileSprite, resources::TileMap},
 };
 
@@ -31,20 +35,44 @@ pub fn add_sprite_to_entities(
 }
 
 /// System that converts position components to transform components
-/// Only updates transforms when positions have actually changed
-pub fn position_to_transform(mut q_objects: Query<(&Position, &mut Transform), Changed<Position>>) {
-    for (position, mut transform) in &mut q_objects {
-        *transform = transform_from_position(position);
+/// Only updates transforms when positions have actually changed, or when a new level resized the
+/// map
+pub fn position_to_transform(
+    current_map: Res<CurrentMap>,
+    mut last_map_size: Local<(u32, u32)>,
+    mut q_objects: Query<(
+        Ref<Position>,
+        &mut Transform,
+        Has<DeadTag>,
+        Has<FloorItem>,
+        Has<Feature>,
+        Has<Decoration>,
+    )>,
+) {
+    let resized = *last_map_size != current_map.size;
+    *last_map_size = current_map.size;
+
+    for (position, mut transform, dead, floor_item, feature, decoration) in &mut q_objects {
+        if resized || position.is_changed() {
+            *transform = transform_from_position(&position, current_map.size);
+            if dead || floor_item || feature || decoration {
+                transform.translation.z = RenderingConstants::FLOOR_OBJECT_Z;
+            }
+        }
     }
 }
 
-/// Helper function to convert a position to a transform
-pub fn transform_from_position(position: &Position) -> Transform {
-    Transform::from_xyz(
+/// Helper function to convert a position to a transform on a map of the given size
+pub fn transform_from_position(position: &Position, map_size: (u32, u32)) -> Transform {
+    Transform::from_translation(world_from_position(position, map_size).extend(RenderingConstants::ACTOR_Z))
+}
+
+/// World-space center of a tile, with the map centered on the origin
+pub fn world_from_position(position: &Position, map_size: (u32, u32)) -> Vec2 {
+    Vec2::new(
         position.x() as f32 * RenderingConstants::TILE_SIZE
-            - (ModelConstants::MAP_WIDTH as f32 * RenderingConstants::HALF_TILE_SIZE),
+            - (map_size.0 as f32 * RenderingConstants::HALF_TILE_SIZE),
         position.y() as f32 * RenderingConstants::TILE_SIZE
-            - (ModelConstants::MAP_HEIGHT as f32 * RenderingConstants::HALF_TILE_SIZE),
-        1.0,
+            - (map_size.1 as f32 * RenderingConstants::HALF_TILE_SIZE),
     )
 }
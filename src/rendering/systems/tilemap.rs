@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::tiles::{TileColor, TilePos};
 
 use crate::core::{
+    actions::{PING_ECHO_TURNS, PING_SILHOUETTE_TURNS},
     components::Position,
     resources::{FovMap, LightMap},
 };
@@ -10,6 +11,14 @@ use crate::core::{
 // TILEMAP SYSTEMS
 // ============================================================================
 
+/// Tint for a tile picked out by echolocation, brighter while the echo is fresh
+pub fn echo_color(echo: u8) -> Color { echo_tint(echo as f32 / PING_ECHO_TURNS as f32) }
+
+/// Tint for an actor silhouette caught by echolocation, brighter while it is fresh
+pub fn silhouette_color(turns: u8) -> Color { echo_tint(turns as f32 / PING_SILHOUETTE_TURNS as f32) }
+
+fn echo_tint(strength: f32) -> Color { Color::srgba(0.3, 0.5, 0.9, 0.35 + 0.5 * strength.min(1.0)) }
+
 /// System that updates tilemap visibility and lighting based on FOV and LightMap
 pub fn update_tilemap_visibility(
     fov_map: Res<FovMap>,
@@ -39,6 +48,9 @@ pub fn update_tilemap_visibility(
             );
 
             tile_color.0 = final_color;
+        } else if fov_map.is_echoed(position) {
+            // Echolocation outlines: a cold blue that fades with the echo
+            tile_color.0 = echo_color(fov_map.echo_at(position));
         } else if fov_map.is_revealed(position) {
             // Revealed areas are dimmed and desaturated (fog of war)
             tile_color.0 = Color::srgba(0.4, 0.4, 0.4, 1.0);
@@ -1,24 +1,39 @@
 use bevy::prelude::*;
+use std::collections::HashSet;
 
-use crate::core::{
-    components::{AITag, PlayerTag, Position},
-    resources::{FovMap, LightMap},
+use crate::{
+    core::{
+        components::{AITag, PlayerTag, Position},
+        resources::{CurrentMap, FovMap, LightMap},
+    },
+    rendering::{
+        components::{EchoGhost, TileSprite},
+        resources::TileMap,
+        systems::{echo_color, silhouette_color, transform_from_position},
+    },
 };
 
 // ============================================================================
 // VISIBILITY SYSTEMS
 // ============================================================================
 
-/// Apply lighting to a sprite with minimum visibility threshold
-fn apply_lighting_to_sprite(sprite: &mut Sprite, light_map: &LightMap, position: &Position) {
+/// Apply lighting to a sprite with minimum visibility threshold, on top of its own tint
+fn apply_lighting_to_sprite(
+    sprite: &mut Sprite,
+    light_map: &LightMap,
+    position: &Position,
+    tile_sprite: Option<&TileSprite>,
+) {
     let light_color = light_map.get_light((position.x, position.y));
     let light_linear = light_color.to_linear();
+    let tint = tile_sprite.and_then(|tile_sprite| tile_sprite.tint).unwrap_or(Color::WHITE).to_linear();
 
     let min_light = 0.3;
-    let lit_color = Color::linear_rgb(
-        (light_linear.red + min_light).min(1.0),
-        (light_linear.green + min_light).min(1.0),
-        (light_linear.blue + min_light).min(1.0),
+    let lit_color = Color::linear_rgba(
+        (light_linear.red + min_light).min(1.0) * tint.red,
+        (light_linear.green + min_light).min(1.0) * tint.green,
+        (light_linear.blue + min_light).min(1.0) * tint.blue,
+        tint.alpha,
     );
 
     sprite.color = lit_color;
@@ -32,6 +47,8 @@ fn apply_lighting_to_sprite(sprite: &mut Sprite, light_map: &LightMap, position:
 /// # Visibility Logic
 /// - **Player**: Always visible (never hidden)
 /// - **Visible entities**: Affected by lighting (color modulated by light)
+/// - **Echoed but not visible**: Drawn flat in the echo tint (actors are drawn by their
+///   [`EchoGhost`] instead, see [`update_echo_ghosts`])
 /// - **Revealed but not visible**: Semi-transparent (alpha = 0.4)
 /// - **Unexplored entities**: Hidden (Visibility::Hidden)
 ///
@@ -42,19 +59,25 @@ fn apply_lighting_to_sprite(sprite: &mut Sprite, light_map: &LightMap, position:
 pub fn update_sprite_visibility(
     fov_map: Res<FovMap>,
     light_map: Res<LightMap>,
-    mut q_sprites: Query<(&Position, &mut Visibility, &mut Sprite), (With<Sprite>, Without<PlayerTag>)>,
+    mut q_sprites: Query<
+        (&Position, &mut Visibility, &mut Sprite, Option<&TileSprite>),
+        (With<Sprite>, Without<PlayerTag>),
+    >,
 ) {
     // Only update when FOV or lighting changes to avoid unnecessary work
     if !fov_map.is_changed() && !light_map.is_changed() {
         return;
     }
 
-    for (position, mut visibility, mut sprite) in &mut q_sprites {
+    for (position, mut visibility, mut sprite, tile_sprite) in &mut q_sprites {
         if fov_map.is_visible(*position) {
             *visibility = Visibility::Visible;
 
             // Apply lighting to the sprite
-            apply_lighting_to_sprite(&mut sprite, &light_map, position);
+            apply_lighting_to_sprite(&mut sprite, &light_map, position, tile_sprite);
+        } else if fov_map.is_echoed(*position) {
+            *visibility = Visibility::Visible;
+            sprite.color = echo_color(fov_map.echo_at(*position));
         }
         // else if fov_map.is_revealed(*position) {
         //     *visibility = Visibility::Visible;
@@ -96,8 +119,9 @@ pub fn update_typed_visibility(
             *visibility = Visibility::Visible;
 
             // Apply lighting to living entities
-            apply_lighting_to_sprite(&mut sprite, &light_map, position);
+            apply_lighting_to_sprite(&mut sprite, &light_map, position, None);
         } else {
+            // Out of sight, only an echo ghost shows where it was
             *visibility = Visibility::Hidden;
         }
     }
@@ -108,7 +132,7 @@ pub fn update_typed_visibility(
             *visibility = Visibility::Visible;
 
             // Apply full lighting to visible static entities
-            apply_lighting_to_sprite(&mut sprite, &light_map, position);
+            apply_lighting_to_sprite(&mut sprite, &light_map, position, None);
         } else if fov_map.is_revealed(*position) {
             *visibility = Visibility::Visible;
             // Revealed static entities are dimmed (fog of war effect)
@@ -118,3 +142,61 @@ pub fn update_typed_visibility(
         }
     }
 }
+
+/// System that draws the actor silhouettes caught by echolocation as ghost sprites
+///
+/// A ghost stays where the ping caught its actor, wherever the actor has gone since, and fades on
+/// its own clock. It is hidden while its tile is in plain sight, where the truth shows instead.
+pub fn update_echo_ghosts(
+    mut commands: Commands,
+    fov_map: Res<FovMap>,
+    current_map: Res<CurrentMap>,
+    tilemap: Option<Res<TileMap>>,
+    q_actors: Query<&TileSprite>,
+    mut q_ghosts: Query<(Entity, &EchoGhost, &mut Transform, &mut Sprite, &mut Visibility)>,
+) {
+    if !fov_map.is_changed() {
+        return;
+    }
+
+    let Some(tilemap) = tilemap else {
+        return;
+    };
+
+    let ghost_visibility = |position: Position| {
+        if fov_map.is_visible(position) { Visibility::Hidden } else { Visibility::Visible }
+    };
+
+    let mut drawn = HashSet::new();
+    for (ghost, &EchoGhost(actor), mut transform, mut sprite, mut visibility) in &mut q_ghosts {
+        let Some(silhouette) = fov_map.silhouette(actor) else {
+            commands.entity(ghost).despawn();
+            continue;
+        };
+
+        drawn.insert(actor);
+        *transform = transform_from_position(&silhouette.position, current_map.size);
+        sprite.color = silhouette_color(silhouette.turns);
+        *visibility = ghost_visibility(silhouette.position);
+    }
+
+    for (actor, silhouette) in fov_map.silhouettes() {
+        if drawn.contains(&actor) {
+            continue;
+        }
+
+        let Ok(tile_sprite) = q_actors.get(actor) else {
+            continue;
+        };
+
+        let mut sprite = tilemap.generate_sprite_for_tile(tile_sprite.tile_coords);
+        sprite.color = silhouette_color(silhouette.turns);
+        commands.spawn((
+            Name::new("Echo ghost"),
+            EchoGhost(actor),
+            sprite,
+            transform_from_position(&silhouette.position, current_map.size),
+            ghost_visibility(silhouette.position),
+        ));
+    }
+}
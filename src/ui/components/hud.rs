@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::core::components::StatType;
+
+/// Root of the in-game HUD
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Hud;
+
+/// Text line with the player's level, health and experience
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct HudStatus;
+
+/// Text listing what the player saw when they last looked around
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct ExamineText;
+
+/// Text warning how weighed down the player is, empty while they aren't
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct EncumbranceIndicator;
+
+/// The filled part of the experience bar
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct ExperienceBarFill;
+
+/// Panel for spending stat points, shown while there are points to spend
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct StatAllocationPanel;
+
+/// Text showing how many stat points are left to spend
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct UnspentPointsLabel;
+
+/// Text showing the player's value for one stat
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct StatLabel(pub StatType);
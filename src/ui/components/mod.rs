@@ -3,3 +3,6 @@ pub use self::game_camera::*;
 
 mod interaction;
 pub use self::interaction::*;
+
+mod hud;
+pub use self::hud::*;
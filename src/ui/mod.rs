@@ -7,9 +7,14 @@ pub mod utils;
 mod ui_constants;
 
 pub use self::ui_constants::*;
+use crate::rendering::screens::ScreenState;
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<components::GameCamera>();
     app.register_type::<components::InteractionPalette>();
     app.add_systems(Update, (systems::apply_interaction_palette,));
+
+    // In-game HUD
+    app.add_systems(OnEnter(ScreenState::Gameplay), systems::spawn_hud);
+    app.add_systems(Update, systems::update_hud.run_if(in_state(ScreenState::Gameplay)));
 }
@@ -0,0 +1,167 @@
+use bevy::{prelude::*, ui::Val::*};
+
+use crate::{
+    core::components::{Encumbrance, Experience, Fuel, Health, PlayerTag, Satiety, StatType, Stats},
+    gameplay::player::{components::ExamineReport, progression::Progression},
+    ui::{
+        Palette,
+        components::{
+            EncumbranceIndicator, ExamineText, ExperienceBarFill, Hud, HudStatus, StatAllocationPanel,
+            StatLabel, UnspentPointsLabel,
+        },
+        utils::button_small,
+    },
+};
+
+const HUD_FONT_SIZE: f32 = 16.0;
+const EXPERIENCE_BAR_WIDTH: f32 = 200.0;
+const EXPERIENCE_BAR_BACKGROUND: Color = Color::srgb(0.15, 0.15, 0.2);
+const EXPERIENCE_BAR_FILL: Color = Color::srgb(0.55, 0.45, 0.95);
+const PANEL_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.08, 0.85);
+const BURDENED_COLOR: Color = Color::srgb(0.95, 0.85, 0.3);
+const STRESSED_COLOR: Color = Color::srgb(0.95, 0.55, 0.2);
+const OVERLOADED_COLOR: Color = Color::srgb(0.95, 0.25, 0.2);
+
+/// The player's levelling state, as spent from the stat panel
+type PlayerProgress<'w> = Single<'w, (&'static mut Experience, &'static mut Stats), With<PlayerTag>>;
+
+fn hud_text(text: impl Into<String>) -> impl Bundle {
+    (Text(text.into()), TextFont::from_font_size(HUD_FONT_SIZE), TextColor(Palette::LABEL_TEXT))
+}
+
+/// One row of the stat panel: the stat's value and a button spending a point on it
+fn stat_row(stat: StatType) -> impl Bundle {
+    (
+        Name::new(stat.display_name()),
+        Node { align_items: AlignItems::Center, column_gap: Px(8.0), ..default() },
+        Children::spawn((
+            Spawn((hud_text(stat.display_name()), StatLabel(stat), Node { width: Px(140.0), ..default() })),
+            Spawn(button_small("+", move |_: Trigger<Pointer<Click>>, player: PlayerProgress| {
+                let (mut experience, mut stats) = player.into_inner();
+                if experience.spend_point(stat, &mut stats) {
+                    info!("Raised {} to {}", stat.display_name(), stats.get_stat(stat));
+                }
+            })),
+        )),
+    )
+}
+
+/// Spawn the HUD: a status line and experience bar, plus what the player last looked at and the
+/// stat panel for level-ups
+pub fn spawn_hud(mut commands: Commands) {
+    commands.spawn((
+        Name::new("HUD"),
+        Hud,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Px(12.0),
+            bottom: Px(12.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Px(6.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        children![
+            (Name::new("Examine"), hud_text(""), ExamineText),
+            (
+                Name::new("Stat Allocation"),
+                StatAllocationPanel,
+                Node {
+                    display: Display::None,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(4.0),
+                    padding: UiRect::all(Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(PANEL_BACKGROUND),
+                Children::spawn((
+                    Spawn((hud_text(""), UnspentPointsLabel)),
+                    SpawnIter(StatType::all().into_iter().map(stat_row)),
+                )),
+            ),
+            (Name::new("Status"), hud_text(""), HudStatus),
+            (Name::new("Encumbrance"), hud_text(""), EncumbranceIndicator),
+            (
+                Name::new("Experience Bar"),
+                Node { width: Px(EXPERIENCE_BAR_WIDTH), height: Px(6.0), ..default() },
+                BackgroundColor(EXPERIENCE_BAR_BACKGROUND),
+                children![(
+                    ExperienceBarFill,
+                    Node { width: Percent(0.0), height: Percent(100.0), ..default() },
+                    BackgroundColor(EXPERIENCE_BAR_FILL),
+                )],
+            ),
+        ],
+    ));
+}
+
+/// The player's state as the HUD shows it
+type PlayerStatus<'w> = Single<
+    'w,
+    (
+        &'static Experience,
+        Option<&'static Health>,
+        Option<&'static Stats>,
+        Option<&'static Encumbrance>,
+        Option<&'static Satiety>,
+        Option<&'static Fuel>,
+    ),
+    With<PlayerTag>,
+>;
+
+/// Keep the HUD in step with the player's level, health, hunger, torch, experience, stats and load,
+/// and with what they last looked at
+pub fn update_hud(
+    progression: Res<Progression>,
+    examine_report: Res<ExamineReport>,
+    player: PlayerStatus,
+    mut texts: Query<
+        (&mut Text, Option<&StatLabel>, Has<HudStatus>, Has<UnspentPointsLabel>, Has<ExamineText>),
+        Without<EncumbranceIndicator>,
+    >,
+    encumbrance_indicator: Single<(&mut Text, &mut TextColor), With<EncumbranceIndicator>>,
+    mut fill: Single<&mut Node, (With<ExperienceBarFill>, Without<StatAllocationPanel>)>,
+    mut panel: Single<&mut Node, With<StatAllocationPanel>>,
+) {
+    let (experience, health, stats, encumbrance, satiety, fuel) = player.into_inner();
+    let progress = experience.progress(&progression);
+
+    for (mut text, stat_label, is_status, is_unspent, is_examine) in &mut texts {
+        if is_examine {
+            if examine_report.is_changed() {
+                text.0 = examine_report.lines.join("\n");
+            }
+        } else if is_status {
+            let health =
+                health.map_or(String::new(), |health| format!("HP {}/{}  ", health.current, health.max));
+            let experience_text = match progress {
+                Some((earned, needed)) => format!("XP {earned}/{needed}"),
+                None => "XP max".to_string(),
+            };
+            let hunger = satiety
+                .map(|satiety| satiety.stage.label())
+                .filter(|label| !label.is_empty())
+                .map_or(String::new(), |label| format!("  {label}"));
+            let torch = fuel.map_or("  No light".to_string(), |fuel| format!("  Torch {}", fuel.turns_left));
+            text.0 = format!("Level {}  {health}{experience_text}{hunger}{torch}", experience.level);
+        } else if is_unspent {
+            text.0 = format!("Level up! {} stat points to spend", experience.unspent_points);
+        } else if let (Some(StatLabel(stat)), Some(stats)) = (stat_label, stats) {
+            text.0 = format!("{} {}", stat.display_name(), stats.get_stat(*stat));
+        }
+    }
+
+    let fraction = progress.map_or(1.0, |(earned, needed)| earned as f32 / needed.max(1) as f32);
+    fill.width = Percent(fraction.clamp(0.0, 1.0) * 100.0);
+
+    panel.display = if experience.unspent_points > 0 { Display::Flex } else { Display::None };
+
+    let encumbrance = encumbrance.copied().unwrap_or_default();
+    let (mut text, mut color) = encumbrance_indicator.into_inner();
+    text.0 = encumbrance.label().to_string();
+    color.0 = match encumbrance {
+        Encumbrance::Unencumbered | Encumbrance::Burdened => BURDENED_COLOR,
+        Encumbrance::Stressed => STRESSED_COLOR,
+        Encumbrance::Overloaded => OVERLOADED_COLOR,
+    };
+}
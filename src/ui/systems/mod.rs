@@ -3,3 +3,6 @@ pub use self::spawn_camera::*;
 
 mod apply_interaction;
 pub use self::apply_interaction::*;
+
+mod hud;
+pub use self::hud::*;
@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use echos_in_the_dark::{
+    core::{
+        actions::{MoveAction, TeleportAction},
+        components::{OccupiesTile, Position},
+        resources::{CurrentMap, Map},
+        types::GameAction,
+    },
+    gameplay::{factions::FactionRegistry, world::components::TerrainType},
+    prelude::Direction,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(CurrentMap(Map::new_with_terrain((10, 10), TerrainType::FLOOR)));
+        world.init_resource::<FactionRegistry>();
+        world
+    }
+
+    fn assert_index_consistent(world: &mut World) {
+        let actors: Vec<(Entity, Position)> = world
+            .query_filtered::<(Entity, &Position), With<OccupiesTile>>()
+            .iter(world)
+            .map(|(entity, pos)| (entity, *pos))
+            .collect();
+
+        let mismatches = world.resource::<CurrentMap>().actor_index_mismatches(actors);
+        assert!(mismatches.is_empty(), "Actor index out of sync: {mismatches:?}");
+    }
+
+    #[test]
+    fn test_spawn_and_despawn_update_actor_index() {
+        let mut world = create_world();
+
+        let actor = world.spawn((Position::new(2, 2), OccupiesTile)).id();
+        assert_eq!(world.resource::<CurrentMap>().get_actor(Position::new(2, 2)), Some(actor));
+        assert_index_consistent(&mut world);
+
+        world.despawn(actor);
+        assert_eq!(world.resource::<CurrentMap>().get_actor(Position::new(2, 2)), None);
+        assert_eq!(world.resource::<CurrentMap>().get_actor_position(actor), None);
+        assert_index_consistent(&mut world);
+    }
+
+    #[test]
+    fn test_position_changes_move_actor() {
+        let mut world = create_world();
+        let actor = world.spawn((Position::new(2, 2), OccupiesTile)).id();
+
+        // Replacing the component directly
+        world.entity_mut(actor).insert(Position::new(5, 5));
+        assert_eq!(world.resource::<CurrentMap>().get_actor(Position::new(2, 2)), None);
+        assert_eq!(world.resource::<CurrentMap>().get_actor(Position::new(5, 5)), Some(actor));
+
+        // Through actions
+        MoveAction::new(actor, Direction::EAST).execute(&mut world).unwrap();
+        assert_eq!(world.resource::<CurrentMap>().get_actor_position(actor), Some(Position::new(6, 5)));
+
+        TeleportAction::new(actor, Position::new(1, 1)).execute(&mut world).unwrap();
+        assert_eq!(world.resource::<CurrentMap>().get_actor_position(actor), Some(Position::new(1, 1)));
+        assert_index_consistent(&mut world);
+    }
+
+    #[test]
+    fn test_removing_marker_clears_actor() {
+        let mut world = create_world();
+        let actor = world.spawn((Position::new(3, 3), OccupiesTile)).id();
+
+        world.entity_mut(actor).remove::<OccupiesTile>();
+        assert_eq!(world.resource::<CurrentMap>().get_actor(Position::new(3, 3)), None);
+
+        // Plain positioned entities are not indexed
+        world.spawn(Position::new(4, 4));
+        assert_eq!(world.resource::<CurrentMap>().get_actor(Position::new(4, 4)), None);
+        assert_index_consistent(&mut world);
+    }
+}
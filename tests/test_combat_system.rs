@@ -42,7 +42,7 @@ mod tests {
 
         // Set up walkable terrain and place entities on the map
         let mut map = world.resource_mut::<CurrentMap>();
-        map.tiles = Grid::new_fill(map.size, Tile { terrain: TerrainType::Floor, ..Default::default() });
+        map.tiles = Grid::new_fill(map.size, Tile { terrain: TerrainType::FLOOR, ..Default::default() });
         map.place_actor(Position::new(0, 0), attacker).unwrap();
         map.place_actor(Position::new(1, 0), target).unwrap();
 
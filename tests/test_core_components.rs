@@ -120,7 +120,7 @@ fn test_inventory_with_stats() {
         "A very heavy sword".to_string(),
     );
 
-    let result = inventory.add_item(heavy_item);
+    let result = inventory.add_item(heavy_item, None);
     assert!(result.is_ok());
     assert_eq!(inventory.current_weight, 15.0);
 }
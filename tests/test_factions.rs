@@ -0,0 +1,94 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use echos_assets::factions::{FactionRelation, FactionTable, Stance};
+use echos_in_the_dark::{
+    core::{
+        actions::MoveAction,
+        components::{AITag, Faction, OccupiesTile, PlayerTag, Position},
+        events::NoiseEvent,
+        resources::{CurrentMap, Map, TurnQueue},
+        types::{GameAction, GameError},
+    },
+    gameplay::{
+        enemies::{
+            components::{AIBehavior, AITarget},
+            systems::select_target_system,
+        },
+        factions::FactionRegistry,
+        world::components::TerrainType,
+    },
+    prelude::Direction,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(CurrentMap(Map::new_with_terrain((20, 20), TerrainType::FLOOR)));
+        world.init_resource::<Events<NoiseEvent>>();
+        world.init_resource::<TurnQueue>();
+        world.insert_resource(faction_registry());
+        world
+    }
+
+    /// The shipped relations between the built-in factions
+    fn faction_registry() -> FactionRegistry {
+        let mut registry = FactionRegistry::default();
+        registry.install(&FactionTable {
+            factions: vec!["player".into(), "monsters".into(), "critters".into(), "wanderers".into()],
+            relations: vec![
+                FactionRelation::new("monsters", "player", Stance::Hostile),
+                FactionRelation::new("critters", "player", Stance::Hostile),
+                FactionRelation::new("critters", "monsters", Stance::Hostile),
+            ],
+            default_stance: Stance::Neutral,
+        });
+        registry
+    }
+
+    fn spawn_ai(world: &mut World, position: Position, faction: Faction) -> Entity {
+        world
+            .spawn((position, OccupiesTile, AITag, faction, AIBehavior::hostile(8), AITarget::default()))
+            .id()
+    }
+
+    #[test]
+    fn test_targets_nearest_visible_hostile() {
+        let mut world = create_world();
+        let monster = spawn_ai(&mut world, Position::new(5, 5), Faction::MONSTERS);
+        let player = world.spawn((Position::new(12, 5), OccupiesTile, PlayerTag, Faction::PLAYER)).id();
+        let critter = spawn_ai(&mut world, Position::new(8, 5), Faction::CRITTERS);
+        spawn_ai(&mut world, Position::new(6, 5), Faction::WANDERERS);
+
+        world.run_system_once(select_target_system).unwrap();
+
+        // The wanderer is closer but neutral; the critter is the nearest hostile
+        let target = world.get::<AITarget>(monster).unwrap();
+        assert_eq!(target.entity, Some(critter));
+        assert!(!target.is_player);
+
+        // The critter sees the player and the monster, and the monster is closer
+        assert_eq!(world.get::<AITarget>(critter).unwrap().entity, Some(monster));
+
+        // With no hostile in view, a monster still has the player to hunt
+        world.entity_mut(critter).despawn();
+        world.entity_mut(player).insert(Position::new(19, 19));
+        world.run_system_once(select_target_system).unwrap();
+
+        let target = world.get::<AITarget>(monster).unwrap();
+        assert_eq!(target.entity, Some(player));
+        assert!(target.is_player);
+    }
+
+    #[test]
+    fn test_allies_do_not_bump_attack() {
+        let mut world = create_world();
+        let first = spawn_ai(&mut world, Position::new(5, 5), Faction::MONSTERS);
+        spawn_ai(&mut world, Position::new(6, 5), Faction::MONSTERS);
+
+        let result = MoveAction::new(first, Direction::EAST).execute(&mut world);
+        assert!(matches!(result, Err(GameError::MovementBlocked { .. })));
+        assert_eq!(world.get::<Position>(first), Some(&Position::new(5, 5)));
+    }
+}
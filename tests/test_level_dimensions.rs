@@ -0,0 +1,31 @@
+use echos_in_the_dark::{
+    core::{components::Position, resources::FovMap},
+    gameplay::world::generation::GenConfig,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deeper_levels_are_larger() {
+        let shallow = GenConfig::for_depth(1);
+        let deep = GenConfig::for_depth(15);
+
+        assert!(deep.width > shallow.width);
+        assert!(deep.height > shallow.height);
+    }
+
+    #[test]
+    fn test_generated_grid_matches_config_size() {
+        let mut rng = fastrand::Rng::with_seed(7);
+        let mut config = GenConfig::for_depth(8);
+        let grid = config.generate(&mut rng);
+
+        assert_eq!(grid.size(), (config.width, config.height));
+
+        // Map-sized resources built from the same config cover the whole level
+        let fov_map = FovMap::new(config.width as usize, config.height as usize);
+        assert!(!fov_map.is_revealed(Position::new(config.width as i32 - 1, config.height as i32 - 1)));
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use echos_in_the_dark::{
+    core::{
+        actions::MoveAction,
+        components::{Movement, OccupiesTile, Position},
+        pathfinding::utils::find_path_uncached_for,
+        resources::{CurrentMap, Map},
+        types::GameAction,
+    },
+    gameplay::{factions::FactionRegistry, world::components::TerrainType},
+    prelude::{Direction, assets::MovementType},
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create a floor map split in two by a chasm running down column 10
+    fn create_chasm_map() -> CurrentMap {
+        let mut map = Map::new_with_terrain((20, 20), TerrainType::FLOOR);
+        for y in 0..20 {
+            map.set_terrain(Position::new(10, y), TerrainType::CHASM);
+        }
+        CurrentMap(map)
+    }
+
+    #[test]
+    fn test_terrain_costs_per_movement_type() {
+        assert_eq!(TerrainType::FLOOR.movement_cost(MovementType::Walking), Some(1));
+        assert_eq!(TerrainType::RUBBLE.movement_cost(MovementType::Walking), Some(2));
+        assert_eq!(TerrainType::RUBBLE.movement_cost(MovementType::Burrowing), Some(1));
+        assert_eq!(TerrainType::DEEP_WATER.movement_cost(MovementType::Walking), None);
+        assert_eq!(TerrainType::DEEP_WATER.movement_cost(MovementType::Swimming), Some(1));
+        assert_eq!(TerrainType::CHASM.movement_cost(MovementType::Flying), Some(1));
+        assert_eq!(TerrainType::WALL.movement_cost(MovementType::Flying), None);
+
+        assert!(!TerrainType::CHASM.is_walkable());
+        assert!(TerrainType::CHASM.is_traversable());
+        assert!(!TerrainType::WALL.is_traversable());
+    }
+
+    #[test]
+    fn test_flyer_paths_over_chasm_walker_cannot() {
+        let mut map = create_chasm_map();
+        let origin = Position::new(2, 10);
+        let destination = Position::new(17, 10);
+
+        let walking = find_path_uncached_for(origin, destination, &mut map, MovementType::Walking, false);
+        assert!(walking.is_none(), "Walkers should not cross the chasm");
+
+        let flying = find_path_uncached_for(origin, destination, &mut map, MovementType::Flying, false)
+            .expect("Flyers should path over the chasm");
+        assert!(flying.iter().any(|pos| pos.x == 10));
+        assert_eq!(flying.last(), Some(&destination));
+    }
+
+    #[test]
+    fn test_difficult_terrain_slows_movement() {
+        let mut world = World::new();
+
+        let mut map = Map::new_with_terrain((10, 10), TerrainType::FLOOR);
+        map.set_terrain(Position::new(3, 2), TerrainType::RUBBLE);
+        world.insert_resource(CurrentMap(map));
+        world.init_resource::<FactionRegistry>();
+
+        let walker =
+            world.spawn((Position::new(2, 2), Movement::new(MovementType::Walking), OccupiesTile)).id();
+
+        let time = MoveAction::new(walker, Direction::EAST).execute(&mut world).unwrap();
+        assert_eq!(time, 2000);
+        assert_eq!(*world.get::<Position>(walker).unwrap(), Position::new(3, 2));
+        assert_eq!(world.resource::<CurrentMap>().get_actor(Position::new(3, 2)), Some(walker));
+
+        // Walkers refuse to step into a chasm
+        world.resource_mut::<CurrentMap>().set_terrain(Position::new(4, 2), TerrainType::CHASM);
+        assert!(MoveAction::new(walker, Direction::EAST).execute(&mut world).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use echos_in_the_dark::{
+    core::{
+        actions::{DigAction, MoveAction, PingAction},
+        components::{Movement, OccupiesTile, PlayerTag, Position},
+        events::{NoiseEvent, NoiseKind},
+        resources::{CurrentMap, FovMap, Map, NoiseMap},
+        types::GameAction,
+    },
+    gameplay::{factions::FactionRegistry, world::components::TerrainType},
+    prelude::{Direction, assets::MovementType},
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(CurrentMap(Map::new_with_terrain((20, 20), TerrainType::FLOOR)));
+        world.init_resource::<Events<NoiseEvent>>();
+        world.init_resource::<FactionRegistry>();
+        world
+    }
+
+    fn drain_noises(world: &mut World) -> Vec<NoiseEvent> {
+        world.resource_mut::<Events<NoiseEvent>>().drain().collect()
+    }
+
+    #[test]
+    fn test_walking_is_heard_but_flying_is_silent() {
+        let mut world = create_world();
+        let walker = world.spawn((Position::new(5, 5), OccupiesTile)).id();
+        let bat = world.spawn((Position::new(5, 8), OccupiesTile, Movement::new(MovementType::Flying))).id();
+
+        MoveAction::new(walker, Direction::EAST).execute(&mut world).unwrap();
+        MoveAction::new(bat, Direction::EAST).execute(&mut world).unwrap();
+
+        let noises = drain_noises(&mut world);
+        assert_eq!(noises.len(), 1);
+        assert_eq!(noises[0].source, walker);
+        assert_eq!(noises[0].kind, NoiseKind::Footstep);
+
+        // A guard two tiles away hears the footstep
+        let mut noise_map = NoiseMap::new();
+        noise_map.propagate(world.resource::<CurrentMap>(), &noises[0], 0);
+        assert!(noise_map.noise_at(Position::new(8, 5)).is_some());
+    }
+
+    #[test]
+    fn test_digging_clears_ore_and_makes_noise() {
+        let mut world = create_world();
+        world.resource_mut::<CurrentMap>().set_terrain(Position::new(6, 5), TerrainType::ORE_VEIN);
+        let miner = world.spawn((Position::new(5, 5), OccupiesTile)).id();
+
+        DigAction::new(miner, Position::new(6, 5)).execute(&mut world).unwrap();
+        assert_eq!(world.resource::<CurrentMap>().get_terrain(Position::new(6, 5)), Some(TerrainType::FLOOR));
+
+        let noises = drain_noises(&mut world);
+        assert_eq!(noises.len(), 1);
+        assert_eq!(noises[0].kind, NoiseKind::Digging);
+
+        // Plain floor and distant tiles cannot be dug
+        assert!(DigAction::new(miner, Position::new(4, 5)).execute(&mut world).is_err());
+        assert!(DigAction::new(miner, Position::new(9, 5)).execute(&mut world).is_err());
+    }
+
+    #[test]
+    fn test_player_ping_outlines_and_is_heard() {
+        let mut world = create_world();
+        world.insert_resource(FovMap::new(20, 20));
+        let player = world.spawn((Position::new(5, 5), OccupiesTile, PlayerTag)).id();
+        let lurker = world.spawn((Position::new(9, 5), OccupiesTile)).id();
+
+        PingAction::new(player).execute(&mut world).unwrap();
+
+        // The lurker's silhouette shows up where it stood, without marking the open floor
+        let fov_map = world.resource::<FovMap>();
+        assert_eq!(
+            fov_map.silhouette(lurker).map(|silhouette| silhouette.position),
+            Some(Position::new(9, 5))
+        );
+        assert!(!fov_map.is_echoed(Position::new(9, 5)));
+        assert!(!fov_map.is_echoed(Position::new(7, 5)));
+
+        // ...and anything nearby hears it
+        let noises = drain_noises(&mut world);
+        assert_eq!(noises.len(), 1);
+        assert_eq!(noises[0].kind, NoiseKind::Ping);
+
+        let mut noise_map = NoiseMap::new();
+        noise_map.propagate(world.resource::<CurrentMap>(), &noises[0], 0);
+        let heard = noise_map.noise_at(Position::new(9, 5)).unwrap();
+        assert_eq!(heard.source, player);
+    }
+}
@@ -20,16 +20,16 @@ mod tests {
         // Fill with walkable floor tiles
         for x in 0..20 {
             for y in 0..20 {
-                map.set_terrain(Position::new(x, y), TerrainType::Floor);
+                map.set_terrain(Position::new(x, y), TerrainType::FLOOR);
             }
         }
 
         // Add some walls to create interesting pathfinding scenarios
         for i in 5..15 {
-            map.set_terrain(Position::new(i, 10), TerrainType::Wall); // Horizontal wall
+            map.set_terrain(Position::new(i, 10), TerrainType::WALL); // Horizontal wall
         }
-        map.set_terrain(Position::new(10, 9), TerrainType::Wall); // Block one path
-        map.set_terrain(Position::new(10, 11), TerrainType::Wall); // Force pathfinding around
+        map.set_terrain(Position::new(10, 9), TerrainType::WALL); // Block one path
+        map.set_terrain(Position::new(10, 11), TerrainType::WALL); // Force pathfinding around
 
         CurrentMap(map)
     }
@@ -0,0 +1,68 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use echos_in_the_dark::{
+    core::components::Position,
+    gameplay::{
+        enemies::{components::PatrolRoute, systems::assign_patrol_routes_system},
+        world::generation::{GenConfig, PatrolGraph},
+    },
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_levels_have_a_patrol_graph() {
+        let mut rng = fastrand::Rng::with_seed(11);
+        let mut config = GenConfig::for_depth(4);
+        let grid = config.generate(&mut rng);
+
+        let graph = &config.patrol_graph;
+        assert_eq!(graph.nodes.len(), config.rooms.len());
+        assert_eq!(graph.edges.len(), config.rooms.len().saturating_sub(1));
+        assert!(
+            graph
+                .nodes
+                .iter()
+                .all(|node| grid.get((*node).into()).is_some_and(|terrain| terrain.is_walkable()))
+        );
+    }
+
+    #[test]
+    fn test_patrollers_get_a_route_at_spawn() {
+        let mut world = World::new();
+        let nodes = vec![Position::new(2, 2), Position::new(10, 2), Position::new(10, 10)];
+        world.insert_resource(GenConfig {
+            patrol_graph: PatrolGraph::new(nodes, vec![(0, 1), (1, 2)]),
+            ..Default::default()
+        });
+
+        let guard = world.spawn((Position::new(3, 3), PatrolRoute::default(), Name::new("Guard"))).id();
+        world.run_system_once(assign_patrol_routes_system).unwrap();
+
+        let route = world.get::<PatrolRoute>(guard).unwrap();
+        assert_eq!(
+            route.waypoints,
+            vec![Position::new(2, 2), Position::new(10, 2), Position::new(10, 10), Position::new(10, 2)]
+        );
+    }
+
+    #[test]
+    fn test_route_resumes_from_nearest_waypoint() {
+        let mut route = PatrolRoute::new(vec![
+            Position::new(2, 2),
+            Position::new(10, 2),
+            Position::new(10, 10),
+            Position::new(10, 2),
+        ]);
+
+        // Dragged off chasing someone, the guard rejoins at the closest stop
+        route.resume(Position::new(11, 9));
+        assert_eq!(route.next_waypoint(Position::new(11, 9)), Some(Position::new(10, 10)));
+
+        // Reaching it moves it on, back the way it came
+        assert_eq!(route.next_waypoint(Position::new(10, 10)), Some(Position::new(10, 2)));
+        route.resume(Position::new(10, 2));
+        assert_eq!(route.next_waypoint(Position::new(10, 2)), Some(Position::new(2, 2)));
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use echos_in_the_dark::{
+    core::{
+        components::{AITag, Description, Faction, OccupiesTile, PlayerTag, Position},
+        events::DamageDealtEvent,
+        resources::{CurrentMap, Map, TurnQueue},
+    },
+    gameplay::{
+        enemies::{
+            components::{AIBehavior, AITarget, Grudges},
+            systems::{decay_grudges_system, record_grudges_system, select_target_system},
+        },
+        factions::FactionRegistry,
+        player::systems::examine_text,
+        world::components::TerrainType,
+    },
+    prelude::assets::AIBehaviorType,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(CurrentMap(Map::new_with_terrain((30, 30), TerrainType::FLOOR)));
+        world.init_resource::<TurnQueue>();
+        world.init_resource::<FactionRegistry>();
+        world.init_resource::<Events<DamageDealtEvent>>();
+        world
+    }
+
+    fn spawn_wanderer(world: &mut World, position: Position, defends_allies: bool) -> Entity {
+        world
+            .spawn((
+                Name::new("Wanderer"),
+                position,
+                OccupiesTile,
+                AITag,
+                Faction::WANDERERS,
+                AIBehavior::neutral(8),
+                AITarget::default(),
+                Grudges::new(defends_allies),
+            ))
+            .id()
+    }
+
+    fn attack(world: &mut World, attacker: Entity, target: Entity) {
+        let position = *world.get::<Position>(target).unwrap();
+        world.send_event(DamageDealtEvent { attacker, target, damage: 5, position });
+        world.run_system_once(record_grudges_system).unwrap();
+    }
+
+    fn advance_time(world: &mut World, to: u64) {
+        let mut turn_queue = world.resource_mut::<TurnQueue>();
+        turn_queue.schedule_turn(Entity::PLACEHOLDER, to);
+        turn_queue.get_next_actor();
+    }
+
+    fn behavior_type(world: &World, entity: Entity) -> AIBehaviorType {
+        world.get::<AIBehavior>(entity).unwrap().behavior_type
+    }
+
+    #[test]
+    fn test_victim_and_witnesses_hold_grudges() {
+        let mut world = create_world();
+        let player = world.spawn((Position::new(5, 5), OccupiesTile, PlayerTag, Faction::PLAYER)).id();
+        let victim = spawn_wanderer(&mut world, Position::new(6, 5), false);
+        let defender = spawn_wanderer(&mut world, Position::new(9, 5), true);
+        let bystander = spawn_wanderer(&mut world, Position::new(9, 6), false);
+        let distant = spawn_wanderer(&mut world, Position::new(28, 28), true);
+
+        attack(&mut world, player, victim);
+
+        let now = world.resource::<TurnQueue>().current_time();
+        for entity in [victim, defender] {
+            assert!(world.get::<Grudges>(entity).unwrap().holds_against(player, now));
+            assert_eq!(behavior_type(&world, entity), AIBehaviorType::Hostile);
+        }
+
+        // Only allies that defend their own and saw the attack join in
+        for entity in [bystander, distant] {
+            assert!(!world.get::<Grudges>(entity).unwrap().is_provoked(now));
+            assert_eq!(behavior_type(&world, entity), AIBehaviorType::Neutral);
+        }
+
+        // The provoked wanderer now targets the player despite its faction being neutral
+        world.run_system_once(select_target_system).unwrap();
+        let target = world.get::<AITarget>(victim).unwrap();
+        assert_eq!(target.entity, Some(player));
+        assert!(target.is_player);
+    }
+
+    #[test]
+    fn test_grudges_decay_back_to_calm() {
+        let mut world = create_world();
+        let player = world.spawn((Position::new(5, 5), OccupiesTile, PlayerTag, Faction::PLAYER)).id();
+        let victim = spawn_wanderer(&mut world, Position::new(6, 5), false);
+
+        attack(&mut world, player, victim);
+        world.run_system_once(decay_grudges_system).unwrap();
+        assert_eq!(behavior_type(&world, victim), AIBehaviorType::Hostile);
+
+        advance_time(&mut world, 100_000);
+        world.run_system_once(decay_grudges_system).unwrap();
+
+        let now = world.resource::<TurnQueue>().current_time();
+        assert!(!world.get::<Grudges>(victim).unwrap().holds_against(player, now));
+        assert_eq!(behavior_type(&world, victim), AIBehaviorType::Neutral);
+    }
+
+    #[test]
+    fn test_examine_shows_provocation() {
+        let mut world = create_world();
+        let player = world.spawn((Position::new(5, 5), OccupiesTile, PlayerTag, Faction::PLAYER)).id();
+        let victim = spawn_wanderer(&mut world, Position::new(6, 5), false);
+        let description = Description::new("A traveller.");
+
+        let describe = |world: &World| {
+            let now = world.resource::<TurnQueue>().current_time();
+            examine_text(
+                "Wanderer",
+                Some(&description),
+                world.get::<AIBehavior>(victim).unwrap(),
+                world.get::<Grudges>(victim),
+                player,
+                now,
+            )
+        };
+
+        assert_eq!(describe(&world), "Wanderer (indifferent): A traveller.");
+
+        attack(&mut world, player, victim);
+        assert_eq!(describe(&world), "Wanderer (enraged at you): A traveller.");
+    }
+}
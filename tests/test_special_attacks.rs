@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use echos_in_the_dark::{
+    core::{
+        actions::SpecialAttackAction,
+        components::{Faction, Health, OccupiesTile, Position, SpecialAttacks, Stats},
+        events::CombatEvent,
+        resources::{CurrentMap, Map},
+        types::{GameAction, GameError},
+    },
+    gameplay::{factions::FactionRegistry, world::components::TerrainType},
+    prelude::assets::{AttackShape, SpecialAttackData},
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(CurrentMap(Map::new_with_terrain((20, 20), TerrainType::FLOOR)));
+        world.init_resource::<Events<CombatEvent>>();
+        world.init_resource::<FactionRegistry>();
+        world
+    }
+
+    /// A fighter on the player's side, hostile to the monster attackers
+    fn spawn_fighter(world: &mut World, position: Position) -> Entity {
+        world.spawn((position, OccupiesTile, Faction::PLAYER, Stats::balanced(10), Health::new(500))).id()
+    }
+
+    fn spawn_attacker(world: &mut World, position: Position, attack: SpecialAttackData) -> Entity {
+        let attacker = spawn_fighter(world, position);
+        world.entity_mut(attacker).insert((Faction::MONSTERS, SpecialAttacks::new(vec![attack])));
+        attacker
+    }
+
+    /// Everyone the attacker swung at, hit or miss
+    fn struck(world: &World) -> Vec<Entity> {
+        let events = world.resource::<Events<CombatEvent>>();
+        events
+            .get_cursor()
+            .read(events)
+            .map(|event| match *event {
+                CombatEvent::AttackHit { target, .. }
+                | CombatEvent::AttackMissed { target, .. }
+                | CombatEvent::CriticalHit { target, .. } => target,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_swipe_hits_everything_adjacent() {
+        let mut world = create_world();
+        let berserker = spawn_attacker(
+            &mut world,
+            Position::new(5, 5),
+            SpecialAttackData::new("Swipe", AttackShape::Swipe, 2),
+        );
+
+        let adjacent = [Position::new(6, 5), Position::new(4, 4), Position::new(5, 6)]
+            .map(|position| spawn_fighter(&mut world, position));
+        let distant = spawn_fighter(&mut world, Position::new(8, 5));
+
+        SpecialAttackAction::new(berserker, 0, Position::new(6, 5)).execute(&mut world).unwrap();
+
+        let struck = struck(&world);
+        assert!(adjacent.iter().all(|entity| struck.contains(entity)));
+        assert!(!struck.contains(&distant));
+    }
+
+    #[test]
+    fn test_charge_rushes_in_and_strikes() {
+        let mut world = create_world();
+        let guard = spawn_attacker(
+            &mut world,
+            Position::new(2, 5),
+            SpecialAttackData::new("Shield Charge", AttackShape::Charge { length: 4 }, 6),
+        );
+        let target = spawn_fighter(&mut world, Position::new(6, 5));
+
+        SpecialAttackAction::new(guard, 0, Position::new(6, 5)).execute(&mut world).unwrap();
+
+        assert_eq!(*world.get::<Position>(guard).unwrap(), Position::new(5, 5));
+        assert_eq!(world.resource::<CurrentMap>().get_actor(Position::new(5, 5)), Some(guard));
+        assert_eq!(struck(&world), vec![target]);
+    }
+
+    #[test]
+    fn test_special_attack_respects_cooldown() {
+        let mut world = create_world();
+        let berserker = spawn_attacker(
+            &mut world,
+            Position::new(5, 5),
+            SpecialAttackData::new("Swipe", AttackShape::Swipe, 2),
+        );
+        spawn_fighter(&mut world, Position::new(6, 5));
+
+        let mut swipe = SpecialAttackAction::new(berserker, 0, Position::new(6, 5));
+        swipe.execute(&mut world).unwrap();
+
+        assert!(matches!(swipe.execute(&mut world), Err(GameError::OnCooldown { remaining_time: 2 })));
+
+        // Two more turns and it's ready again
+        let mut special_attacks = world.get_mut::<SpecialAttacks>(berserker).unwrap();
+        special_attacks.tick();
+        special_attacks.tick();
+        assert!(swipe.execute(&mut world).is_ok());
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use echos_in_the_dark::{
+    core::{
+        components::{PlayerTag, Position},
+        resources::{CurrentMap, Map, TurnQueue},
+    },
+    gameplay::{
+        enemies::{
+            components::{AIBehavior, AITarget, SquadOrders, SquadRole, chebyshev_distance},
+            systems::{assign_squad_orders_system, is_corridor},
+        },
+        world::components::TerrainType,
+    },
+    prelude::assets::MovementType,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_member(world: &mut World, position: Position, player: Entity, role: SquadRole) -> Entity {
+        let player_pos = *world.get::<Position>(player).unwrap();
+        world
+            .spawn((
+                position,
+                AIBehavior::hostile(8),
+                AITarget::new(player, player_pos, true),
+                role,
+                SquadOrders::default(),
+            ))
+            .id()
+    }
+
+    fn orders(world: &World, entity: Entity) -> SquadOrders { *world.get::<SquadOrders>(entity).unwrap() }
+
+    #[test]
+    fn test_members_spread_around_target() {
+        let mut world = World::new();
+        world.insert_resource(CurrentMap(Map::new_with_terrain((20, 20), TerrainType::FLOOR)));
+        world.init_resource::<TurnQueue>();
+        let player_pos = Position::new(10, 10);
+        let player = world.spawn((player_pos, PlayerTag)).id();
+
+        let melee: Vec<Entity> = (2..5)
+            .map(|x| spawn_member(&mut world, Position::new(x, 10), player, SquadRole::Melee))
+            .collect();
+        let archer = spawn_member(&mut world, Position::new(10, 2), player, SquadRole::Ranged { range: 4 });
+
+        world.run_system_once(assign_squad_orders_system).unwrap();
+
+        let mut tiles = Vec::new();
+        for entity in melee {
+            let SquadOrders::Attack(tile) = orders(&world, entity) else {
+                panic!("melee member should be sent in to attack");
+            };
+            assert_eq!(chebyshev_distance(tile, player_pos), 1);
+            assert!(!tiles.contains(&tile), "two members were sent to {tile:?}");
+            tiles.push(tile);
+        }
+
+        let SquadOrders::Keep(tile) = orders(&world, archer) else {
+            panic!("ranged member should keep its distance");
+        };
+        assert_eq!(chebyshev_distance(tile, player_pos), 4);
+    }
+
+    #[test]
+    fn test_extra_melee_hold_at_corridor_mouth() {
+        // A room to the west joined by a corridor to a dead end where the player stands
+        let mut map = Map::new((20, 10));
+        for y in 1..=6 {
+            for x in 1..=6 {
+                map.set_terrain(Position::new(x, y), TerrainType::FLOOR);
+            }
+        }
+        for x in 7..=12 {
+            map.set_terrain(Position::new(x, 3), TerrainType::FLOOR);
+        }
+        assert!(is_corridor(&map, Position::new(9, 3), MovementType::Walking));
+
+        let mut world = World::new();
+        world.insert_resource(CurrentMap(map));
+        world.init_resource::<TurnQueue>();
+        let player = world.spawn((Position::new(12, 3), PlayerTag)).id();
+
+        let front = spawn_member(&mut world, Position::new(4, 3), player, SquadRole::Melee);
+        let second = spawn_member(&mut world, Position::new(2, 3), player, SquadRole::Melee);
+        let third = spawn_member(&mut world, Position::new(2, 5), player, SquadRole::Melee);
+
+        world.run_system_once(assign_squad_orders_system).unwrap();
+
+        // Only one tile touches the player, so only one member goes in
+        assert_eq!(orders(&world, front), SquadOrders::Attack(Position::new(11, 3)));
+
+        let SquadOrders::Hold(mouth) = orders(&world, second) else {
+            panic!("second member should hold");
+        };
+        assert_eq!(mouth.x(), 6, "second member should wait at the corridor mouth");
+
+        let SquadOrders::Hold(spot) = orders(&world, third) else {
+            panic!("third member should hold");
+        };
+        assert_ne!(spot, mouth);
+    }
+
+    #[test]
+    fn test_orders_follow_the_target_while_time_stands_still() {
+        let mut world = World::new();
+        world.insert_resource(CurrentMap(Map::new_with_terrain((20, 20), TerrainType::FLOOR)));
+        world.init_resource::<TurnQueue>();
+        let player = world.spawn((Position::new(10, 10), PlayerTag)).id();
+
+        let members: Vec<Entity> = (2..4)
+            .map(|x| spawn_member(&mut world, Position::new(x, 10), player, SquadRole::Melee))
+            .collect();
+
+        let plan = world.register_system(assign_squad_orders_system);
+        world.run_system(plan).unwrap();
+
+        // The player slips away before any game time passes, and the squad sees it
+        let moved = Position::new(15, 15);
+        world.entity_mut(player).insert(moved);
+        for &member in &members {
+            world.get_mut::<AITarget>(member).unwrap().set_if_neq(AITarget::new(player, moved, true));
+        }
+        world.run_system(plan).unwrap();
+
+        for member in members {
+            let SquadOrders::Attack(tile) = orders(&world, member) else {
+                panic!("melee member should be sent in to attack");
+            };
+            assert_eq!(chebyshev_distance(tile, moved), 1);
+        }
+    }
+}